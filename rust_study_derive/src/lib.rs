@@ -0,0 +1,270 @@
+//! rust_study 的过程宏
+//!
+//! 提供 `#[derive(Describe)]`，为结构体和枚举实现 `rust_study::advanced::traits::Describe`：
+//! 生成的 `describe` 列出类型名（枚举是 `类型::变体`）和每个字段的 `Debug` 输出，
+//! 带 `#[describe(skip)]` 的字段不会显示，只计入隐藏字段的个数。
+//!
+//! 为了不引入依赖，这里没有使用 syn 和 quote，而是直接遍历 `proc_macro::TokenStream`
+//! 解析出类型名和字段，再把生成的代码拼成字符串，解析回 `TokenStream`。
+
+use std::iter::Peekable;
+
+use proc_macro::{token_stream, Delimiter, Spacing, TokenStream, TokenTree};
+
+// 派生宏的入口：输入是整个类型定义，输出是追加在它后面的 impl 块
+#[proc_macro_derive(Describe, attributes(describe))]
+pub fn derive_describe(input: TokenStream) -> TokenStream {
+    match parse_item(input) {
+        Ok(item) => item.expand().parse().expect("生成的代码无法解析"),
+        Err(message) => compile_error(&message),
+    }
+}
+
+// 把错误变成 compile_error!，编译器会在 derive 的位置报告它
+fn compile_error(message: &str) -> TokenStream {
+    format!("::core::compile_error!({:?});", message).parse().unwrap()
+}
+
+type Tokens = Peekable<token_stream::IntoIter>;
+
+struct Item {
+    name: String,
+    kind: Kind,
+}
+
+enum Kind {
+    Struct(Fields),
+    Enum(Vec<Variant>),
+}
+
+struct Variant {
+    name: String,
+    fields: Fields,
+}
+
+enum Fields {
+    Named(Vec<Field>),
+    Unnamed(Vec<Field>),
+    Unit,
+}
+
+struct Field {
+    // 命名字段是字段名，元组字段是下标
+    name: String,
+    skip: bool,
+}
+
+fn parse_item(input: TokenStream) -> Result<Item, String> {
+    let mut tokens = input.into_iter().peekable();
+    if parse_attributes(&mut tokens)? {
+        return Err(String::from("#[describe(skip)] 只能用在字段上"));
+    }
+    skip_visibility(&mut tokens);
+    let keyword = expect_ident(&mut tokens)?;
+    let name = expect_ident(&mut tokens)?;
+    if matches!(tokens.peek(), Some(TokenTree::Punct(punct)) if punct.as_char() == '<') {
+        return Err(format!("Describe 暂不支持带泛型参数的类型 {}", name));
+    }
+
+    let kind = match keyword.as_str() {
+        "struct" => Kind::Struct(parse_fields(tokens.next())?),
+        "enum" => match tokens.next() {
+            Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Brace => {
+                Kind::Enum(parse_variants(group.stream())?)
+            }
+            _ => return Err(format!("无法解析枚举 {} 的变体", name)),
+        },
+        other => return Err(format!("Describe 只能用于结构体和枚举，不能用于 {}", other)),
+    };
+    Ok(Item { name, kind })
+}
+
+// 跳过 #[...] 属性（文档注释也是属性），返回其中是否有 #[describe(skip)]
+fn parse_attributes(tokens: &mut Tokens) -> Result<bool, String> {
+    let mut skip = false;
+    while matches!(tokens.peek(), Some(TokenTree::Punct(punct)) if punct.as_char() == '#') {
+        tokens.next();
+        let Some(TokenTree::Group(group)) = tokens.next() else {
+            return Err(String::from("无法解析属性"));
+        };
+        let mut inner = group.stream().into_iter();
+        if !matches!(inner.next(), Some(TokenTree::Ident(ident)) if ident.to_string() == "describe") {
+            continue;
+        }
+        let Some(TokenTree::Group(arguments)) = inner.next() else {
+            return Err(String::from("describe 属性的写法是 #[describe(skip)]"));
+        };
+        for argument in arguments.stream() {
+            match argument {
+                TokenTree::Ident(ident) if ident.to_string() == "skip" => skip = true,
+                other => return Err(format!("未知的 describe 参数: {}", other)),
+            }
+        }
+    }
+    Ok(skip)
+}
+
+// pub、pub(crate)、pub(super) 等可见性对生成的代码没有影响
+fn skip_visibility(tokens: &mut Tokens) {
+    if matches!(tokens.peek(), Some(TokenTree::Ident(ident)) if ident.to_string() == "pub") {
+        tokens.next();
+        if matches!(tokens.peek(), Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Parenthesis) {
+            tokens.next();
+        }
+    }
+}
+
+fn expect_ident(tokens: &mut Tokens) -> Result<String, String> {
+    match tokens.next() {
+        Some(TokenTree::Ident(ident)) => Ok(ident.to_string()),
+        Some(other) => Err(format!("这里需要一个标识符，而不是 {}", other)),
+        None => Err(String::from("类型定义不完整")),
+    }
+}
+
+// 类型名（或变体名）后面的部分：{ 命名字段 }、( 元组字段 )，或者什么都没有
+fn parse_fields(token: Option<TokenTree>) -> Result<Fields, String> {
+    match token {
+        Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Brace => {
+            let fields = split_top_level(group.stream())
+                .into_iter()
+                .map(|segment| {
+                    let mut tokens = segment.into_iter().collect::<TokenStream>().into_iter().peekable();
+                    let skip = parse_attributes(&mut tokens)?;
+                    skip_visibility(&mut tokens);
+                    Ok(Field { name: expect_ident(&mut tokens)?, skip })
+                })
+                .collect::<Result<_, String>>()?;
+            Ok(Fields::Named(fields))
+        }
+        Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Parenthesis => {
+            let fields = split_top_level(group.stream())
+                .into_iter()
+                .enumerate()
+                .map(|(index, segment)| {
+                    let mut tokens = segment.into_iter().collect::<TokenStream>().into_iter().peekable();
+                    Ok(Field { name: index.to_string(), skip: parse_attributes(&mut tokens)? })
+                })
+                .collect::<Result<_, String>>()?;
+            Ok(Fields::Unnamed(fields))
+        }
+        // 单元结构体后面是分号；单元变体后面可能是 = 判别值，这里都不需要
+        _ => Ok(Fields::Unit),
+    }
+}
+
+fn parse_variants(stream: TokenStream) -> Result<Vec<Variant>, String> {
+    split_top_level(stream)
+        .into_iter()
+        .map(|segment| {
+            let mut tokens = segment.into_iter().collect::<TokenStream>().into_iter().peekable();
+            if parse_attributes(&mut tokens)? {
+                return Err(String::from("#[describe(skip)] 只能用在字段上"));
+            }
+            let name = expect_ident(&mut tokens)?;
+            let fields = match tokens.peek() {
+                Some(TokenTree::Group(_)) => parse_fields(tokens.next())?,
+                _ => Fields::Unit,
+            };
+            Ok(Variant { name, fields })
+        })
+        .collect()
+}
+
+// 按最外层的逗号切分。尖括号不是 Group，类型中的 HashMap<K, V> 要靠计数跳过，
+// 同时不能把函数指针类型里的 -> 当成右尖括号
+fn split_top_level(stream: TokenStream) -> Vec<Vec<TokenTree>> {
+    let mut segments = Vec::new();
+    let mut current = Vec::new();
+    let mut depth = 0usize;
+    let mut after_dash = false;
+    for token in stream {
+        if let TokenTree::Punct(punct) = &token {
+            match punct.as_char() {
+                ',' if depth == 0 => {
+                    segments.push(std::mem::take(&mut current));
+                    after_dash = false;
+                    continue;
+                }
+                '<' => depth += 1,
+                '>' if !after_dash => depth = depth.saturating_sub(1),
+                _ => {}
+            }
+            after_dash = punct.as_char() == '-' && punct.spacing() == Spacing::Joint;
+        } else {
+            after_dash = false;
+        }
+        current.push(token);
+    }
+    if !current.is_empty() {
+        segments.push(current);
+    }
+    segments
+}
+
+impl Item {
+    // 生成的 impl 通过 ::rust_study 引用 trait 和辅助函数，在 rust_study 内部和外部都能使用
+    fn expand(&self) -> String {
+        let body = match &self.kind {
+            Kind::Struct(fields) => format!("match self {{ {} }}", arm(&self.name, fields)),
+            // 没有变体的枚举不可能有值，match *self {} 就是穷尽的
+            Kind::Enum(variants) if variants.is_empty() => String::from("match *self {}"),
+            Kind::Enum(variants) => {
+                let arms: String = variants
+                    .iter()
+                    .map(|variant| arm(&format!("{}::{}", self.name, variant.name), &variant.fields))
+                    .collect();
+                format!("match self {{ {} }}", arms)
+            }
+        };
+        format!(
+            "impl ::rust_study::advanced::traits::Describe for {} {{\n\
+             \x20   fn describe(&self, ctx: &mut ::rust_study::context::Ctx) -> ::std::io::Result<()> {{\n\
+             \x20       {}\n\
+             \x20   }}\n\
+             }}",
+            self.name, body
+        )
+    }
+}
+
+// 一个 match 分支：绑定没有跳过的字段，交给 describe_fields 输出。
+// 绑定名用 __0、__1……，避免和字段名 ctx 之类的标识符冲突
+fn arm(path: &str, fields: &Fields) -> String {
+    let list = |fields: &[Field]| -> String {
+        fields
+            .iter()
+            .enumerate()
+            .filter(|(_, field)| !field.skip)
+            .map(|(index, field)| {
+                format!("({:?}, __{} as &dyn ::std::fmt::Debug), ", field.name.trim_start_matches("r#"), index)
+            })
+            .collect()
+    };
+    let hidden = |fields: &[Field]| fields.iter().filter(|field| field.skip).count();
+
+    let (pattern, list, hidden) = match fields {
+        Fields::Named(fields) => {
+            let bindings: String = fields
+                .iter()
+                .enumerate()
+                .filter(|(_, field)| !field.skip)
+                .map(|(index, field)| format!("{}: __{}, ", field.name, index))
+                .collect();
+            (format!(" {{ {}.. }}", bindings), list(fields), hidden(fields))
+        }
+        Fields::Unnamed(fields) => {
+            let bindings: Vec<String> = fields
+                .iter()
+                .enumerate()
+                .map(|(index, field)| if field.skip { String::from("_") } else { format!("__{}", index) })
+                .collect();
+            (format!("({})", bindings.join(", ")), list(fields), hidden(fields))
+        }
+        Fields::Unit => (String::new(), String::new(), 0),
+    };
+    format!(
+        "{}{} => ::rust_study::macros::derive::describe_fields(ctx, {:?}, &[{}], {}),\n",
+        path, pattern, path, list, hidden
+    )
+}
@@ -0,0 +1,141 @@
+//! 高级特性练习
+//!
+//! 把下面函数和方法中的 `todo!()` 换成实现，然后运行 `rust_study check <练习>` 检查，
+//! 例如 `rust_study check advanced/largest`。
+
+use crate::check::{expect_eq, Case, Exercise};
+
+// 练习 1：返回切片中最大的元素，空切片返回 None（参考 generics 课中的 largest）
+pub fn largest<T: PartialOrd + Copy>(list: &[T]) -> Option<T> {
+    todo!()
+}
+
+// 练习 2：返回两个字符串切片中较长的一个，一样长时返回第一个（参考 lifetimes 课中的 longest）
+pub fn longest<'a>(x: &'a str, y: &'a str) -> &'a str {
+    todo!()
+}
+
+pub trait Shape {
+    fn area(&self) -> f64;
+
+    // 默认实现：描述形状的面积，保留两位小数
+    fn describe(&self) -> String {
+        format!("面积为 {:.2}", self.area())
+    }
+}
+
+pub struct Square {
+    pub side: f64,
+}
+
+pub struct Circle {
+    pub radius: f64,
+}
+
+// 练习 3：为 Square 和 Circle 实现 Shape（参考 traits 课）
+impl Shape for Square {
+    fn area(&self) -> f64 {
+        todo!()
+    }
+}
+
+impl Shape for Circle {
+    fn area(&self) -> f64 {
+        todo!()
+    }
+}
+
+// 所有形状的面积之和
+pub fn total_area(shapes: &[Box<dyn Shape>]) -> f64 {
+    todo!()
+}
+
+pub static EXERCISES: &[Exercise] = &[
+    Exercise {
+        id: "largest",
+        topic: "advanced",
+        lesson: "generics",
+        title: "泛型函数",
+        title_en: "Generic functions",
+        cases: &[
+            Case {
+                name: "整数",
+                name_en: "Integers",
+                hint: "let mut largest = list[0]; 再遍历 list 比较，T: PartialOrd 才能用 >",
+                hint_en: "let mut largest = list[0]; then walk list comparing; > needs T: PartialOrd",
+                run: || expect_eq(largest(&[34, 50, 25, 100, 65]), Some(100)),
+            },
+            Case {
+                name: "字符",
+                name_en: "Characters",
+                hint: "同一个泛型函数适用于所有满足约束的类型",
+                hint_en: "One generic function works for every type that meets the bounds",
+                run: || expect_eq(largest(&['y', 'm', 'a', 'q']), Some('y')),
+            },
+            Case {
+                name: "空切片",
+                name_en: "Empty slice",
+                hint: "先用 list.first()? 或 if list.is_empty() 处理空切片，否则 list[0] 会越界",
+                hint_en: "Handle the empty slice first with list.first()? or if list.is_empty(), otherwise list[0] is out of bounds",
+                run: || expect_eq(largest::<f64>(&[]), None),
+            },
+        ],
+    },
+    Exercise {
+        id: "longest",
+        topic: "advanced",
+        lesson: "lifetimes",
+        title: "生命周期标注",
+        title_en: "Lifetime annotations",
+        cases: &[
+            Case {
+                name: "第二个更长",
+                name_en: "Second is longer",
+                hint: "比较 x.len() 和 y.len()，返回值的生命周期 'a 与两个参数相同",
+                hint_en: "Compare x.len() and y.len(); the return value has the same lifetime 'a as both arguments",
+                run: || expect_eq(longest("abc", "abcd"), "abcd"),
+            },
+            Case {
+                name: "一样长",
+                name_en: "Same length",
+                hint: "用 >= 而不是 >，长度相同时返回 x",
+                hint_en: "Use >= instead of > so that x is returned when the lengths are equal",
+                run: || expect_eq(longest("left", "righ"), "left"),
+            },
+        ],
+    },
+    Exercise {
+        id: "shapes",
+        topic: "advanced",
+        lesson: "traits",
+        title: "实现 Trait",
+        title_en: "Implementing traits",
+        cases: &[
+            Case {
+                name: "正方形",
+                name_en: "Square",
+                hint: "正方形面积是 self.side * self.side",
+                hint_en: "The area of a square is self.side * self.side",
+                run: || expect_eq(Square { side: 3.0 }.area(), 9.0),
+            },
+            Case {
+                name: "圆形和默认方法",
+                name_en: "Circle and the default method",
+                hint: "圆面积是 std::f64::consts::PI * r * r；describe 使用默认实现，不需要重写",
+                hint_en: "The area of a circle is std::f64::consts::PI * r * r; describe uses the default implementation and needs no override",
+                run: || expect_eq(Circle { radius: 1.0 }.describe(), String::from("面积为 3.14")),
+            },
+            Case {
+                name: "trait 对象",
+                name_en: "Trait objects",
+                hint: "shapes.iter().map(|shape| shape.area()).sum()",
+                hint_en: "shapes.iter().map(|shape| shape.area()).sum()",
+                run: || {
+                    let shapes: Vec<Box<dyn Shape>> =
+                        vec![Box::new(Square { side: 2.0 }), Box::new(Square { side: 1.0 })];
+                    expect_eq(total_area(&shapes), 5.0)
+                },
+            },
+        ],
+    },
+];
@@ -4,82 +4,88 @@
 //! Rust 在编译时进行泛型代码的单态化（monomorphization），
 //! 为每个具体类型生成特定代码，保证运行时零成本抽象。
 
-pub fn main() {
-    println!("=== 泛型基础 ===");
+use std::io::{self, Write};
 
-    // 1. 泛型函数
+use crate::context::Ctx;
+
+pub fn main(ctx: &mut Ctx) -> io::Result<()> {
+    writeln!(ctx, "=== {} ===", ctx.text("lesson.generics"))?;
+
+    ctx.section(1, "泛型函数", "Generic functions")?;
     let number_list = vec![34, 50, 25, 100, 65];
     let result = largest(&number_list);
-    println!("最大的数字是 {}", result);
+    writeln!(ctx, "最大的数字是 {}", result)?;
 
     let char_list = vec!['y', 'm', 'a', 'q'];
     let result = largest(&char_list);
-    println!("最大的字符是 {}", result);
+    writeln!(ctx, "最大的字符是 {}", result)?;
 
-    // 2. 泛型结构体
+    ctx.section(2, "泛型结构体", "Generic structs")?;
     let integer_point = Point { x: 5, y: 10 };
     let float_point = Point { x: 1.0, y: 4.0 };
-    println!("整数点: {:?}", integer_point);
-    println!("浮点数点: {:?}", float_point);
+    writeln!(ctx, "整数点: {:?}", integer_point)?;
+    writeln!(ctx, "浮点数点: {:?}", float_point)?;
 
-    // 3. 多个泛型参数
+    ctx.section(3, "多个泛型参数", "Multiple generic parameters")?;
     let point = Point2 { x: 5, y: 10.4 };
-    println!("混合类型点: {:?}", point);
+    writeln!(ctx, "混合类型点: {:?}", point)?;
 
-    // 4. 泛型枚举（标准库中的 Option 和 Result）
+    ctx.section(4, "泛型枚举（标准库中的 Option 和 Result）", "Generic enums (Option and Result in std)")?;
     let some_number: Option<i32> = Some(5);
     let some_string: Option<&str> = Some("hello");
-    println!("Option 示例: {:?}, {:?}", some_number, some_string);
+    writeln!(ctx, "Option 示例: {:?}, {:?}", some_number, some_string)?;
 
-    // 5. 泛型方法
+    ctx.section(5, "泛型方法", "Generic methods")?;
     let p = Point { x: 5, y: 10 };
-    println!("x 坐标: {}", p.x());
+    writeln!(ctx, "x 坐标: {}", p.x())?;
     // println!("到原点的距离: {}", p.distance_from_origin()); // 只有 f32 Point 有这个方法
 
     let p_float = Point { x: 5.0, y: 10.0 };
-    println!("浮点数点到原点的距离: {}", p_float.distance_from_origin());
+    writeln!(ctx, "浮点数点到原点的距离: {}", p_float.distance_from_origin())?;
 
-    // 6. 使用 trait bounds 约束泛型
+    ctx.section(6, "使用 trait bounds 约束泛型", "Constraining generics with trait bounds")?;
     let tweet = Tweet {
         username: String::from("horse_ebooks"),
         content: String::from("of course, as you probably already know, people"),
         reply: false,
         retweet: false,
     };
-    println!("1 条新推文: {}", summarize(&tweet));
+    writeln!(ctx, "1 条新推文: {}", summarize(&tweet))?;
 
-    // 7. 使用 where 子句简化 trait bounds
+    ctx.section(7, "使用 where 子句简化 trait bounds", "Simplifying trait bounds with where clauses")?;
     let result = compare_and_print(&5, &10);
-    println!("比较结果: {}", result);
+    writeln!(ctx, "比较结果: {}", result)?;
 
-    // 8. 泛型与生命周期
+    ctx.section(8, "泛型与生命周期", "Generics and lifetimes")?;
     let string1 = String::from("abcd");
     let string2 = "xyz";
     let result = longest(string1.as_str(), string2);
-    println!("最长的字符串是 {}", result);
+    writeln!(ctx, "最长的字符串是 {}", result)?;
 
-    // 9. 泛型性能
+    ctx.section(9, "泛型性能", "Performance of generics")?;
     // Rust 在编译时进行单态化，为每个具体类型生成特定代码
     // 这意味着泛型没有运行时开销
 
-    // 10. 泛型常量表达式（Rust 1.51+）
+    ctx.section(10, "泛型常量表达式（Rust 1.51+）", "Const generic expressions (Rust 1.51+)")?;
     let arr: [i32; 3] = [1, 2, 3];
     let first = first_element(&arr);
-    println!("数组第一个元素: {}", first);
+    writeln!(ctx, "数组第一个元素: {}", first)?;
 
-    // 11. 泛型关联类型（GATs）
+    ctx.section(11, "泛型关联类型（GATs）", "Generic associated types (GATs)")?;
     let container = Container { value: 42 };
     let doubled = container.map(|x| x * 2);
-    println!("容器值加倍: {}", doubled.value);
+    writeln!(ctx, "容器值加倍: {}", doubled.value)?;
 
-    // 12. 泛型中的常量泛型参数
+    ctx.section(12, "泛型中的常量泛型参数", "Const generic parameters")?;
     let buffer: Buffer<32> = Buffer::new();
-    println!("缓冲区容量: {}", buffer.capacity());
+    writeln!(ctx, "缓冲区容量: {}", buffer.capacity())?;
 
-    // 13. 泛型代码组织
+    ctx.section(13, "泛型代码组织", "Organizing generic code")?;
     let numbers = vec![1, 2, 3, 4, 5];
     let stats = calculate_statistics(&numbers);
-    println!("统计信息: {:?}", stats);
+    writeln!(ctx, "统计信息: {:?}", stats)?;
+
+    Ok(())
 }
 
 // 泛型函数
@@ -160,8 +166,8 @@ fn summarize<T: Summary>(item: &T) -> String {
 }
 
 // 多个 trait bounds
-fn notify<T: Summary + std::fmt::Display>(item: &T) {
-    println!("通知: {}", item.summarize());
+fn notify<T: Summary + std::fmt::Display>(ctx: &mut Ctx, item: &T) -> io::Result<()> {
+    writeln!(ctx, "通知: {}", item.summarize())
 }
 
 // 使用 where 子句简化
@@ -236,14 +242,14 @@ impl<const SIZE: usize> Buffer<SIZE> {
 
 // 泛型代码组织示例
 #[derive(Debug)]
-struct Statistics {
-    min: f64,
-    max: f64,
-    mean: f64,
-    median: f64,
+pub struct Statistics {
+    pub min: f64,
+    pub max: f64,
+    pub mean: f64,
+    pub median: f64,
 }
 
-fn calculate_statistics<T>(numbers: &[T]) -> Statistics
+pub fn calculate_statistics<T>(numbers: &[T]) -> Statistics
 where
     T: Into<f64> + Copy + PartialOrd,
 {
@@ -280,19 +286,19 @@ where
 }
 
 // 泛型的高级用法：类型状态模式
-struct RequestBuilder<State> {
+pub struct RequestBuilder<State> {
     url: String,
     method: String,
     state: std::marker::PhantomData<State>,
 }
 
-struct NoBody;
-struct WithBody {
+pub struct NoBody;
+pub struct WithBody {
     body: String,
 }
 
 impl RequestBuilder<NoBody> {
-    fn new(url: String) -> Self {
+    pub fn new(url: String) -> Self {
         RequestBuilder {
             url,
             method: "GET".to_string(),
@@ -300,7 +306,7 @@ impl RequestBuilder<NoBody> {
         }
     }
 
-    fn with_body(self, body: String) -> RequestBuilder<WithBody> {
+    pub fn with_body(self, body: String) -> RequestBuilder<WithBody> {
         RequestBuilder {
             url: self.url,
             method: self.method,
@@ -310,16 +316,16 @@ impl RequestBuilder<NoBody> {
 }
 
 impl RequestBuilder<WithBody> {
-    fn send(self) {
-        println!("发送 {} 请求到 {}，包含正文", self.method, self.url);
+    pub fn send(self, ctx: &mut Ctx) -> io::Result<()> {
+        writeln!(ctx, "发送 {} 请求到 {}，包含正文", self.method, self.url)
     }
 }
 
 // 使用示例
-fn use_request_builder() {
+fn use_request_builder(ctx: &mut Ctx) -> io::Result<()> {
     let request = RequestBuilder::new("https://example.com".to_string())
         .with_body("Hello, World!".to_string());
-    request.send();
+    request.send(ctx)
 }
 
 // 泛型约束的另一种写法：使用 impl Trait
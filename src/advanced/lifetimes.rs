@@ -4,73 +4,84 @@
 //! 生命周期注解描述了多个引用的生命周期如何相互关联。
 //! 生命周期的主要目标是避免悬垂引用。
 
-pub fn main() {
-    println!("=== 生命周期基础 ===");
+use std::io::{self, Write};
 
-    // 1. 基本生命周期示例
+use crate::context::Ctx;
+
+pub fn main(ctx: &mut Ctx) -> io::Result<()> {
+    writeln!(ctx, "=== {} ===", ctx.text("lesson.lifetimes"))?;
+
+    ctx.section(1, "基本生命周期示例", "A basic lifetime example")?;
     let string1 = String::from("abcd");
     let string2 = "xyz";
     let result = longest(string1.as_str(), string2);
-    println!("最长的字符串是 {}", result);
+    writeln!(ctx, "最长的字符串是 {}", result)?;
 
-    // 2. 结构体中的生命周期
+    ctx.section(2, "结构体中的生命周期", "Lifetimes in structs")?;
     let novel = String::from("从前有座山。山里有座庙...");
     let first_sentence = novel.split('。').next().expect("找不到句号。");
     let i = ImportantExcerpt {
         part: first_sentence,
     };
-    println!("重要摘录: {}", i.part);
+    writeln!(ctx, "重要摘录: {}", i.part)?;
 
-    // 3. 生命周期省略规则
+    ctx.section(3, "生命周期省略规则", "Lifetime elision rules")?;
     let s = String::from("hello");
     let len = first_word(&s);
-    println!("第一个单词长度: {}", len);
+    writeln!(ctx, "第一个单词长度: {}", len)?;
 
-    // 4. 方法中的生命周期
+    ctx.section(4, "方法中的生命周期", "Lifetimes in methods")?;
     let excerpt = ImportantExcerpt { part: "hello" };
-    let announcement = excerpt.announce_and_return_part("重要通知");
-    println!("通知: {}", announcement);
+    let announcement = excerpt.announce_and_return_part(ctx, "重要通知")?;
+    writeln!(ctx, "通知: {}", announcement)?;
 
-    // 5. 静态生命周期
+    ctx.section(5, "静态生命周期", "The static lifetime")?;
     let s: &'static str = "我是一个静态字符串";
-    println!("静态字符串: {}", s);
+    writeln!(ctx, "静态字符串: {}", s)?;
 
-    // 6. 生命周期和泛型结合
+    ctx.section(6, "生命周期和泛型结合", "Combining lifetimes and generics")?;
     let string1 = String::from("abcd");
     let string2 = "xyz";
     let result = longest_with_an_announcement(
+        ctx,
         string1.as_str(),
         string2,
         "今天是个好日子!"
-    );
-    println!("带通知的最长字符串: {}", result);
+    )?;
+    writeln!(ctx, "带通知的最长字符串: {}", result)?;
 
-    // 7. 生命周期在 trait 对象中
+    ctx.section(7, "生命周期在 trait 对象中", "Lifetimes in trait objects")?;
     let trait_object: Box<dyn Printable> = Box::new(String::from("hello"));
-    trait_object.print();
+    trait_object.print(ctx)?;
 
-    // 8. 生命周期约束
+    ctx.section(8, "生命周期约束", "Lifetime bounds")?;
     let wrapper = Wrapper { value: "hello" };
-    println!("包装器值: {}", wrapper.value);
+    writeln!(ctx, "包装器值: {}", wrapper.value)?;
 
-    // 9. 高阶 trait 边界（HRTB）
+    ctx.section(9, "高阶 trait 边界（HRTB）", "Higher-ranked trait bounds (HRTB)")?;
     let numbers = vec![1, 2, 3];
     let sum = sum_refs_simple(&numbers);
-    println!("引用之和: {}", sum);
+    writeln!(ctx, "引用之和: {}", sum)?;
 
-    // 10. 生命周期子类型
+    ctx.section(10, "生命周期子类型", "Lifetime subtyping")?;
     let static_str = "静态字符串";
     let longer;
     {
         let dynamic_str = String::from("动态字符串");
         longer = longest_lifetime(static_str, dynamic_str.as_str());
-        println!("更长的生命周期: {}", longer); // 在 dynamic_str 作用域内使用
+        writeln!(ctx, "更长的生命周期: {}", longer)?; // 在 dynamic_str 作用域内使用
     }
     // println!("更长的生命周期: {}", longer); // 错误：longer 的生命周期不够长
+
+    ctx.section(11, "生命周期和线程", "Lifetimes and threads")?;
+    // 新线程可能比当前函数活得更久，闭包中不能借用局部变量（详见 concurrency 主题）
+    spawn_thread(ctx)?;
+
+    Ok(())
 }
 
 // 生命周期注解
-fn longest<'a>(x: &'a str, y: &'a str) -> &'a str {
+pub fn longest<'a>(x: &'a str, y: &'a str) -> &'a str {
     if x.len() > y.len() {
         x
     } else {
@@ -107,9 +118,9 @@ impl<'a> ImportantExcerpt<'a> {
     }
 
     // 显式生命周期注解
-    fn announce_and_return_part(&self, announcement: &str) -> &str {
-        println!("注意! {}", announcement);
-        self.part
+    fn announce_and_return_part(&self, ctx: &mut Ctx, announcement: &str) -> io::Result<&str> {
+        writeln!(ctx, "注意! {}", announcement)?;
+        Ok(self.part)
     }
 }
 
@@ -117,29 +128,30 @@ impl<'a> ImportantExcerpt<'a> {
 use std::fmt::Display;
 
 fn longest_with_an_announcement<'a, T>(
+    ctx: &mut Ctx,
     x: &'a str,
     y: &'a str,
     ann: T,
-) -> &'a str
+) -> io::Result<&'a str>
 where
     T: Display,
 {
-    println!("公告: {}", ann);
+    writeln!(ctx, "公告: {}", ann)?;
     if x.len() > y.len() {
-        x
+        Ok(x)
     } else {
-        y
+        Ok(y)
     }
 }
 
 // trait 对象中的生命周期
 trait Printable {
-    fn print(&self);
+    fn print(&self, ctx: &mut Ctx) -> io::Result<()>;
 }
 
 impl Printable for String {
-    fn print(&self) {
-        println!("字符串: {}", self);
+    fn print(&self, ctx: &mut Ctx) -> io::Result<()> {
+        writeln!(ctx, "字符串: {}", self)
     }
 }
 
@@ -186,7 +198,7 @@ impl<'a, 'c: 'a> Parser<'a, 'c> {
     }
 }
 
-fn parse_context(context: Context) -> Result<(), &str> {
+fn parse_context(context: Context<'_>) -> Result<(), &str> {
     Parser { context: &context }.parse()
 }
 
@@ -196,11 +208,17 @@ fn make_adder<'a>(x: &'a i32) -> Box<dyn Fn(i32) -> i32 + 'a> {
 }
 
 // 生命周期和迭代器
-struct Iter<'a, T> {
+pub struct Iter<'a, T> {
     data: &'a [T],
     index: usize,
 }
 
+impl<'a, T> Iter<'a, T> {
+    pub fn new(data: &'a [T]) -> Iter<'a, T> {
+        Iter { data, index: 0 }
+    }
+}
+
 impl<'a, T> Iterator for Iter<'a, T> {
     type Item = &'a T;
 
@@ -213,8 +231,28 @@ impl<'a, T> Iterator for Iter<'a, T> {
             None
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.data.len() - self.index;
+        (remaining, Some(remaining))
+    }
 }
 
+// 从尾部取元素时缩短切片；返回的引用仍然带着原切片的生命周期 'a
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.index < self.data.len() {
+            let (last, rest) = self.data.split_last()?;
+            self.data = rest;
+            Some(last)
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Iter<'a, T> {}
+
 // 生命周期在关联类型中
 trait Process {
     type Item<'a> where Self: 'a;
@@ -263,18 +301,19 @@ impl<'a, T> Marker<'a, T> {
 // 生命周期和并发
 use std::thread;
 
-fn spawn_thread() {
+fn spawn_thread(ctx: &mut Ctx) -> io::Result<()> {
     let s = String::from("hello");
 
-    // 错误：s 的生命周期不够长
+    // 错误：s 的生命周期不够长（见 tests/compile_fail/thread_borrow.rs）
     // thread::spawn(|| {
     //     println!("{}", s);
     // });
 
-    // 正确：移动所有权
-    thread::spawn(move || {
-        println!("{}", s);
-    });
+    // 正确：移动所有权。join 等待线程结束并取回它的返回值，
+    // 不 join 的话线程可能还没运行完，输出就丢了
+    let handle = thread::spawn(move || format!("线程中的字符串: {}", s));
+    let message = handle.join().expect("线程 panic 了");
+    writeln!(ctx, "{}", message)
 }
 
 // 生命周期诊断技巧
@@ -316,7 +355,7 @@ fn pattern_matching_lifetime<'a>(s: &'a str) -> &'a str {
 //
 //     // 测试 Iter
 //     let data = vec![1, 2, 3, 4, 5];
-//     let iter = Iter { data: &data, index: 0 };
+//     let iter = Iter::new(&data);
 //     for item in iter {
 //         println!("迭代器项: {}", item);
 //     }
@@ -6,14 +6,5 @@
 pub mod generics;
 pub mod traits;
 pub mod lifetimes;
-
-pub fn run_all() {
-    println!("\n--- 泛型示例 ---");
-    generics::main();
-
-    println!("\n--- Trait 示例 ---");
-    traits::main();
-
-    println!("\n--- 生命周期示例 ---");
-    lifetimes::main();
-}
\ No newline at end of file
+pub mod exercises;
+pub mod quiz;
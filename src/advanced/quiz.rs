@@ -0,0 +1,86 @@
+//! 高级特性小测验
+//!
+//! 预测输出题调用 traits 和 lifetimes 课中的函数，答案以实际运行结果为准。
+
+use std::io::{self, Write};
+
+use crate::context::Ctx;
+use crate::quiz::{Answer, Question};
+
+use super::lifetimes::longest;
+use super::traits::{Human, Pilot};
+
+pub static QUESTIONS: &[Question] = &[
+    Question {
+        topic: "advanced",
+        lesson: "traits",
+        prompt: "Human 自己有 fly 方法，同时实现了 Pilot 和 Wizard 的 fly。下面的代码输出什么？",
+        prompt_en: "Human has its own fly method and also implements fly from Pilot and Wizard. What does this code print?",
+        code: "let person = Human;\nPilot::fly(&person);\nperson.fly();",
+        options: &[
+            "这是你的机长在说话\n挥动手臂",
+            "挥动手臂\n挥动手臂",
+            "这是你的机长在说话\n起飞!",
+            "编译错误：fly 有歧义",
+        ],
+        options_en: &[
+            "这是你的机长在说话\n挥动手臂",
+            "挥动手臂\n挥动手臂",
+            "这是你的机长在说话\n起飞!",
+            "Compile error: fly is ambiguous",
+        ],
+        answer: Answer::Output(fully_qualified),
+        explanation: "person.fly() 优先调用类型自己的方法；要调用 trait 中的同名方法，写 Pilot::fly(&person)。",
+        explanation_en: "person.fly() calls the type's own method first; to call the trait method of the same name, write Pilot::fly(&person).",
+    },
+    Question {
+        topic: "advanced",
+        lesson: "lifetimes",
+        prompt: "下面的代码输出什么？",
+        prompt_en: "What does this code print?",
+        code: "println!(\"{}\", longest(\"abc\", \"xyz\"));",
+        options: &["abc", "xyz", "abcxyz"],
+        options_en: &["abc", "xyz", "abcxyz"],
+        answer: Answer::Output(|ctx| writeln!(ctx, "{}", longest("abc", "xyz"))),
+        explanation: "longest 只在 x 严格更长时返回 x，长度相同时走 else 分支返回 y。",
+        explanation_en: "longest returns x only when x is strictly longer; with equal lengths it takes the else branch and returns y.",
+    },
+    Question {
+        topic: "advanced",
+        lesson: "lifetimes",
+        prompt: "下面的函数签名为什么不能编译？",
+        prompt_en: "Why doesn't this function signature compile?",
+        code: "fn longest(x: &str, y: &str) -> &str {\n    if x.len() > y.len() { x } else { y }\n}",
+        options: &[
+            "&str 不能作为返回类型",
+            "缺少生命周期标注：编译器不知道返回的引用来自 x 还是 y",
+            "if 表达式不能返回引用",
+        ],
+        options_en: &[
+            "&str can't be a return type",
+            "A lifetime annotation is missing: the compiler doesn't know whether the returned reference comes from x or y",
+            "An if expression can't return a reference",
+        ],
+        answer: Answer::Choice(1),
+        explanation: "有两个引用参数时省略规则无法推断返回值的生命周期（E0106），需要写成 fn longest<'a>(x: &'a str, y: &'a str) -> &'a str。",
+        explanation_en: "With two reference parameters the elision rules can't infer the lifetime of the return value (E0106); write fn longest<'a>(x: &'a str, y: &'a str) -> &'a str.",
+    },
+    Question {
+        topic: "advanced",
+        lesson: "generics",
+        prompt: "泛型函数 `fn largest<T>(list: &[T]) -> &T` 中比较 `item > largest` 时，T 至少需要什么约束？",
+        prompt_en: "In the generic function `fn largest<T>(list: &[T]) -> &T`, what bound does T need at least to compare `item > largest`?",
+        code: "",
+        options: &["T: Copy", "T: PartialOrd", "T: Display", "不需要约束"],
+        options_en: &["T: Copy", "T: PartialOrd", "T: Display", "No bound is needed"],
+        answer: Answer::Choice(1),
+        explanation: "> 运算符来自 PartialOrd trait，没有这个约束编译器无法确定 T 能否比较（E0369）。",
+        explanation_en: "The > operator comes from the PartialOrd trait; without that bound the compiler can't tell whether T can be compared (E0369).",
+    },
+];
+
+fn fully_qualified(ctx: &mut Ctx) -> io::Result<()> {
+    let person = Human;
+    Pilot::fly(&person, ctx)?;
+    person.fly(ctx)
+}
@@ -6,41 +6,49 @@
 //! 2. 作为泛型约束（trait bounds）
 //! 3. 实现多态
 
+use std::cell::RefCell;
 use std::fmt;
+use std::io::{self, Write};
+use std::rc::Rc;
 
-pub fn main() {
-    println!("=== Trait 基础 ===");
+use crate::context::Ctx;
 
-    // 1. 基本 Trait 使用
+// 派生宏和 trait 同名但在不同的命名空间，use Describe 会同时导入两者（见 macros 主题）
+pub use rust_study_derive::Describe;
+
+pub fn main(ctx: &mut Ctx) -> io::Result<()> {
+    writeln!(ctx, "=== {} ===", ctx.text("lesson.traits"))?;
+
+    ctx.section(1, "基本 Trait 使用", "Basic trait usage")?;
     let tweet = Tweet {
         username: String::from("horse_ebooks"),
         content: String::from("of course, as you probably already know, people"),
         reply: false,
         retweet: false,
     };
-    println!("1 条新推文: {}", tweet.summarize());
-    println!("默认摘要: {}", tweet.default_summary());
+    writeln!(ctx, "1 条新推文: {}", tweet.summarize())?;
+    writeln!(ctx, "默认摘要: {}", tweet.default_summary())?;
 
-    // 2. Trait 作为参数
-    notify(&tweet);
-    notify_with_impl(&tweet);
+    ctx.section(2, "Trait 作为参数", "Traits as parameters")?;
+    notify(ctx, &tweet)?;
+    notify_with_impl(ctx, &tweet)?;
 
-    // 3. Trait 作为返回值
+    ctx.section(3, "Trait 作为返回值", "Traits as return types")?;
     let article = returns_summarizable();
-    println!("返回的文章: {}", article.summarize());
+    writeln!(ctx, "返回的文章: {}", article.summarize())?;
 
-    // 4. 使用 Trait 实现运算符重载
+    ctx.section(4, "使用 Trait 实现运算符重载", "Operator overloading with traits")?;
     let p1 = Point { x: 1, y: 0 };
     let p2 = Point { x: 2, y: 3 };
     let p3 = p1 + p2;
-    println!("点相加: {:?}", p3);
+    writeln!(ctx, "点相加: {:?}", p3)?;
 
-    // 5. 使用 Trait 实现显示格式化
+    ctx.section(5, "使用 Trait 实现显示格式化", "Display formatting with traits")?;
     let point = Point { x: 5, y: 10 };
-    println!("点显示: {}", point);
-    println!("点调试: {:?}", point);
+    writeln!(ctx, "点显示: {}", point)?;
+    writeln!(ctx, "点调试: {:?}", point)?;
 
-    // 6. 派生 Trait
+    ctx.section(6, "派生 Trait", "Derived traits")?;
     let rect1 = Rectangle {
         width: 30,
         height: 50,
@@ -53,10 +61,10 @@ pub fn main() {
         width: 60,
         height: 45,
     };
-    println!("rect1 能容纳 rect2 吗? {}", rect1.can_hold(&rect2));
-    println!("rect1 能容纳 rect3 吗? {}", rect1.can_hold(&rect3));
+    writeln!(ctx, "rect1 能容纳 rect2 吗? {}", rect1.can_hold(&rect2))?;
+    writeln!(ctx, "rect1 能容纳 rect3 吗? {}", rect1.can_hold(&rect3))?;
 
-    // 7. Trait 对象（动态分发）
+    ctx.section(7, "Trait 对象（动态分发）", "Trait objects (dynamic dispatch)")?;
     let screen = Screen {
         components: vec![
             Box::new(Button {
@@ -71,40 +79,55 @@ pub fn main() {
             }),
         ],
     };
-    screen.run();
+    screen.run(ctx)?;
 
-    // 8. Trait 继承
+    ctx.section(8, "Trait 继承", "Trait inheritance")?;
     let person = Person {
         name: String::from("Alice"),
         age: 30,
     };
-    person.greet();
-    person.describe();
+    person.greet(ctx)?;
+    person.describe(ctx)?;
 
-    // 9. 关联类型
+    ctx.section(9, "关联类型", "Associated types")?;
     let counter = Counter { value: 0 };
     let doubled = counter.map(|x| x * 2);
-    println!("计数器加倍: {}", doubled.value);
+    writeln!(ctx, "计数器加倍: {}", doubled.value)?;
 
-    // 10. 默认泛型类型参数
+    ctx.section(10, "默认泛型类型参数", "Default generic type parameters")?;
     let meters1 = Meters(5.0);
     let meters2 = Meters(3.0);
     let total = meters1 + meters2;
-    println!("总距离: {:?}", total);
+    writeln!(ctx, "总距离: {:?}", total)?;
 
-    // 11. 完全限定语法
+    ctx.section(11, "完全限定语法", "Fully qualified syntax")?;
     let person = Human;
-    person.fly(); // 调用 Human 的 fly
-    Pilot::fly(&person); // 调用 Pilot 的 fly
-    Wizard::fly(&person); // 调用 Wizard 的 fly
+    person.fly(ctx)?; // 调用 Human 的 fly
+    Pilot::fly(&person, ctx)?; // 调用 Pilot 的 fly
+    Wizard::fly(&person, ctx)?; // 调用 Wizard 的 fly
 
-    // 12. 父 Trait
+    ctx.section(12, "父 Trait", "Supertraits")?;
     let outline_point = OutlinePoint { x: 1, y: 3 };
-    println!("轮廓点: {}", outline_point);
+    writeln!(ctx, "轮廓点: {}", outline_point)?;
 
-    // 13. newtype 模式
+    ctx.section(13, "newtype 模式", "The newtype pattern")?;
     let w = Wrapper(vec![String::from("hello"), String::from("world")]);
-    println!("包装器: {}", w);
+    writeln!(ctx, "包装器: {}", w)?;
+
+    ctx.section(14, "Deref 和 Drop", "Deref and Drop")?;
+    let mut y = MyBox::new(5);
+    *y += 1; // DerefMut 让 MyBox 可以像 &mut i32 一样修改
+    writeln!(ctx, "MyBox 解引用: {}", *y)?;
+
+    // Drop 中拿不到 ctx，释放的消息先记到日志里，离开作用域后再输出
+    let log = Rc::new(RefCell::new(Vec::new()));
+    {
+        let _resource = Resource::new("文件句柄", &log);
+        writeln!(ctx, "资源在作用域内，日志: {:?}", log.borrow())?;
+    }
+    writeln!(ctx, "资源离开作用域后，日志: {:?}", log.borrow())?;
+
+    Ok(())
 }
 
 // 基本 Trait 定义
@@ -145,13 +168,13 @@ impl Summary for Tweet {
 }
 
 // Trait 作为参数
-pub fn notify(item: &impl Summary) {
-    println!("突发新闻! {}", item.summarize());
+pub fn notify(ctx: &mut Ctx, item: &impl Summary) -> io::Result<()> {
+    writeln!(ctx, "突发新闻! {}", item.summarize())
 }
 
 // Trait bound 语法
-pub fn notify_with_impl<T: Summary>(item: &T) {
-    println!("通知: {}", item.summarize());
+pub fn notify_with_impl<T: Summary>(ctx: &mut Ctx, item: &T) -> io::Result<()> {
+    writeln!(ctx, "通知: {}", item.summarize())
 }
 
 // 返回实现 Trait 的类型
@@ -204,7 +227,7 @@ impl Rectangle {
 
 // Trait 对象（动态分发）
 pub trait Draw {
-    fn draw(&self);
+    fn draw(&self, ctx: &mut Ctx) -> io::Result<()>;
 }
 
 pub struct Screen {
@@ -212,10 +235,11 @@ pub struct Screen {
 }
 
 impl Screen {
-    pub fn run(&self) {
+    pub fn run(&self, ctx: &mut Ctx) -> io::Result<()> {
         for component in self.components.iter() {
-            component.draw();
+            component.draw(ctx)?;
         }
+        Ok(())
     }
 }
 
@@ -226,8 +250,8 @@ pub struct Button {
 }
 
 impl Draw for Button {
-    fn draw(&self) {
-        println!("绘制按钮: {}x{}, 标签: {}", self.width, self.height, self.label);
+    fn draw(&self, ctx: &mut Ctx) -> io::Result<()> {
+        writeln!(ctx, "绘制按钮: {}x{}, 标签: {}", self.width, self.height, self.label)
     }
 }
 
@@ -238,18 +262,18 @@ pub struct TextField {
 }
 
 impl Draw for TextField {
-    fn draw(&self) {
-        println!("绘制文本框: {}x{}, 占位符: {}", self.width, self.height, self.placeholder);
+    fn draw(&self, ctx: &mut Ctx) -> io::Result<()> {
+        writeln!(ctx, "绘制文本框: {}x{}, 占位符: {}", self.width, self.height, self.placeholder)
     }
 }
 
 // Trait 继承
-trait Greet {
-    fn greet(&self);
+pub trait Greet {
+    fn greet(&self, ctx: &mut Ctx) -> io::Result<()>;
 }
 
-trait Describe: Greet {
-    fn describe(&self);
+pub trait Describe: Greet {
+    fn describe(&self, ctx: &mut Ctx) -> io::Result<()>;
 }
 
 struct Person {
@@ -258,14 +282,14 @@ struct Person {
 }
 
 impl Greet for Person {
-    fn greet(&self) {
-        println!("你好，我是{}!", self.name);
+    fn greet(&self, ctx: &mut Ctx) -> io::Result<()> {
+        writeln!(ctx, "你好，我是{}!", self.name)
     }
 }
 
 impl Describe for Person {
-    fn describe(&self) {
-        println!("我叫{}，今年{}岁。", self.name, self.age);
+    fn describe(&self, ctx: &mut Ctx) -> io::Result<()> {
+        writeln!(ctx, "我叫{}，今年{}岁。", self.name, self.age)
     }
 }
 
@@ -307,42 +331,42 @@ impl std::ops::Add for Meters {
 }
 
 // 完全限定语法
-trait Pilot {
-    fn fly(&self);
+pub trait Pilot {
+    fn fly(&self, ctx: &mut Ctx) -> io::Result<()>;
 }
 
 trait Wizard {
-    fn fly(&self);
+    fn fly(&self, ctx: &mut Ctx) -> io::Result<()>;
 }
 
-struct Human;
+pub struct Human;
 
 impl Human {
-    fn fly(&self) {
-        println!("挥动手臂");
+    pub fn fly(&self, ctx: &mut Ctx) -> io::Result<()> {
+        writeln!(ctx, "挥动手臂")
     }
 }
 
 impl Pilot for Human {
-    fn fly(&self) {
-        println!("这是你的机长在说话");
+    fn fly(&self, ctx: &mut Ctx) -> io::Result<()> {
+        writeln!(ctx, "这是你的机长在说话")
     }
 }
 
 impl Wizard for Human {
-    fn fly(&self) {
-        println!("起飞!");
+    fn fly(&self, ctx: &mut Ctx) -> io::Result<()> {
+        writeln!(ctx, "起飞!")
     }
 }
 
 // 父 Trait
 trait OutlinePrint: fmt::Display {
-    fn outline_print(&self) {
+    fn outline_print(&self, ctx: &mut Ctx) -> io::Result<()> {
         let output = self.to_string();
         let len = output.len();
-        println!("{}", "*".repeat(len + 4));
-        println!("* {} *", output);
-        println!("{}", "*".repeat(len + 4));
+        writeln!(ctx, "{}", "*".repeat(len + 4))?;
+        writeln!(ctx, "* {} *", output)?;
+        writeln!(ctx, "{}", "*".repeat(len + 4))
     }
 }
 
@@ -428,7 +452,14 @@ impl TryFrom<i32> for PositiveNumber {
 // Deref 和 DerefMut
 use std::ops::{Deref, DerefMut};
 
-struct MyBox<T>(T);
+// 自己实现的简化版 Box：只有 Deref，数据仍然在栈上（smart_pointers 主题中与 Box 对比）
+pub struct MyBox<T>(pub T);
+
+impl<T> MyBox<T> {
+    pub fn new(x: T) -> MyBox<T> {
+        MyBox(x)
+    }
+}
 
 impl<T> Deref for MyBox<T> {
     type Target = T;
@@ -444,20 +475,30 @@ impl<T> DerefMut for MyBox<T> {
     }
 }
 
-// Drop
-struct Resource {
-    name: String,
+// Drop：释放时把消息记到共享的日志里
+pub struct Resource {
+    pub name: String,
+    log: Rc<RefCell<Vec<String>>>,
+}
+
+impl Resource {
+    pub fn new(name: &str, log: &Rc<RefCell<Vec<String>>>) -> Resource {
+        Resource {
+            name: name.to_string(),
+            log: Rc::clone(log),
+        }
+    }
 }
 
 impl Drop for Resource {
     fn drop(&mut self) {
-        println!("释放资源: {}", self.name);
+        self.log.borrow_mut().push(format!("释放资源: {}", self.name));
     }
 }
 
 // Iterator
-struct Countdown {
-    count: i32,
+pub struct Countdown {
+    pub count: i32,
 }
 
 impl Iterator for Countdown {
@@ -472,8 +513,16 @@ impl Iterator for Countdown {
             None
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.count.max(0) as usize;
+        (remaining, Some(remaining))
+    }
 }
 
+// 剩余的元素个数是确定的，len() 可以直接算出来
+impl ExactSizeIterator for Countdown {}
+
 // 在 main 中测试
 // fn main() {
 //     // 测试 From/Into
@@ -494,13 +543,12 @@ impl Iterator for Countdown {
 //     assert_eq!(5, *y);
 //
 //     // 测试 Drop
+//     let log = Rc::new(RefCell::new(Vec::new()));
 //     {
-//         let _resource = Resource {
-//             name: "文件句柄".to_string(),
-//         };
+//         let _resource = Resource::new("文件句柄", &log);
 //         println!("资源在作用域内");
 //     }
-//     println!("资源已离开作用域");
+//     println!("资源已离开作用域: {:?}", log.borrow());
 //
 //     // 测试 Iterator
 //     let countdown = Countdown { count: 3 };
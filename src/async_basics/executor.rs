@@ -0,0 +1,303 @@
+//! 一个最小的执行器
+//!
+//! 执行器（executor）负责反复 poll 任务，直到它们完成。本课的执行器只有几十行：
+//! - 每个任务（`Task`）保存一个装箱的 future，以及把自己放回就绪队列的方法
+//! - 任务实现 `Wake`，它的 `Waker` 被调用时把任务重新送进队列
+//! - `run` 不断从队列中取出任务 poll 一次；队列为空并且所有任务都结束时退出
+//!
+//! 所有任务都在调用 `run` 的线程上运行，它们之间是协作式的：
+//! 任务只有在 `.await` 返回 `Pending` 时才会让出执行权。
+//! `Waker` 要求 `Send + Sync`，所以任务内部用 `Arc` 和 `Mutex`，放进来的 future 也要是 `Send` 的。
+
+use std::future::Future;
+use std::io::{self, Write};
+use std::pin::Pin;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Wake, Waker};
+
+use crate::async_basics::futures::YieldNow;
+use crate::context::Ctx;
+
+pub fn main(ctx: &mut Ctx) -> io::Result<()> {
+    writeln!(ctx, "=== {} ===", ctx.text("lesson.executor"))?;
+
+    ctx.section(1, "spawn 任务并运行", "Spawning and running tasks")?;
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let (executor, spawner) = new_executor();
+    for name in ["a", "b", "c"] {
+        let log = Arc::clone(&log);
+        spawner.spawn(async move {
+            log.lock().unwrap().push(format!("任务 {} 完成", name));
+        });
+    }
+    // 执行器在所有 Spawner 都被丢弃后才知道不会再有新任务
+    drop(spawner);
+    let polls = executor.run();
+    writeln!(ctx, "{:?}，共 poll {} 次", log.lock().unwrap(), polls)?;
+
+    ctx.section(2, "协作式调度：在 .await 处让出", "Cooperative scheduling: yielding at .await")?;
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let (executor, spawner) = new_executor();
+    for name in ["甲", "乙"] {
+        let log = Arc::clone(&log);
+        spawner.spawn(async move {
+            for step in 1..=3 {
+                log.lock().unwrap().push(format!("{}{}", name, step));
+                // 让出一次，执行器先去运行队列中的其他任务
+                YieldNow::new().await;
+            }
+        });
+    }
+    drop(spawner);
+    let polls = executor.run();
+    writeln!(ctx, "交替执行: {}，共 poll {} 次", log.lock().unwrap().join(" "), polls)?;
+
+    ctx.section(3, "不让出的任务会独占执行器", "A task that never yields hogs the executor")?;
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let (executor, spawner) = new_executor();
+    {
+        let log = Arc::clone(&log);
+        spawner.spawn(async move {
+            // 没有 .await，整个循环在一次 poll 中跑完
+            for step in 1..=3 {
+                log.lock().unwrap().push(format!("忙{}", step));
+            }
+        });
+    }
+    {
+        let log = Arc::clone(&log);
+        spawner.spawn(async move {
+            log.lock().unwrap().push(String::from("闲"));
+        });
+    }
+    drop(spawner);
+    executor.run();
+    writeln!(ctx, "执行顺序: {}", log.lock().unwrap().join(" "))?;
+
+    ctx.section(4, "在任务中 spawn 新任务", "Spawning new tasks from a task")?;
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let (executor, spawner) = new_executor();
+    {
+        let log = Arc::clone(&log);
+        let inner = spawner.clone();
+        spawner.spawn(async move {
+            log.lock().unwrap().push(String::from("父任务开始"));
+            let child_log = Arc::clone(&log);
+            inner.spawn(async move {
+                child_log.lock().unwrap().push(String::from("子任务运行"));
+            });
+            YieldNow::new().await;
+            log.lock().unwrap().push(String::from("父任务结束"));
+        });
+    }
+    drop(spawner);
+    executor.run();
+    writeln!(ctx, "{:?}", log.lock().unwrap())?;
+
+    ctx.section(5, "Waker：等待另一个任务的消息", "Waker: waiting for a message from another task")?;
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let (executor, spawner) = new_executor();
+    let (sender, receiver) = oneshot::<String>();
+    {
+        let log = Arc::clone(&log);
+        spawner.spawn(async move {
+            log.lock().unwrap().push(String::from("接收方开始等待"));
+            // 还没有值时 poll 返回 Pending，并保存 Waker；发送方没有发送就被丢弃时得到 None
+            if let Some(message) = receiver.await {
+                log.lock().unwrap().push(format!("接收方收到: {}", message));
+            }
+        });
+    }
+    {
+        let log = Arc::clone(&log);
+        spawner.spawn(async move {
+            log.lock().unwrap().push(String::from("发送方发送"));
+            // send 调用保存的 Waker，接收任务被重新放回队列
+            sender.send(String::from("你好"));
+        });
+    }
+    drop(spawner);
+    let polls = executor.run();
+    for line in log.lock().unwrap().iter() {
+        writeln!(ctx, "  {}", line)?;
+    }
+    writeln!(ctx, "共 poll {} 次（接收任务 2 次，发送任务 1 次）", polls)?;
+
+    ctx.section(6, "JoinHandle：取回任务的结果", "JoinHandle: getting a task's result")?;
+    let (executor, spawner) = new_executor();
+    let squares: Vec<JoinHandle<u64>> = (1..=4)
+        .map(|n| {
+            spawner.spawn_with_handle(async move {
+                YieldNow::new().await;
+                n * n
+            })
+        })
+        .collect();
+    let results = Arc::new(Mutex::new(Vec::new()));
+    {
+        let results = Arc::clone(&results);
+        spawner.spawn(async move {
+            for handle in squares {
+                let value = handle.await;
+                results.lock().unwrap().push(value);
+            }
+        });
+    }
+    drop(spawner);
+    executor.run();
+    writeln!(ctx, "各任务的结果: {:?}", results.lock().unwrap())?;
+
+    Ok(())
+}
+
+type BoxFuture = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+// 任务：future 放在 Mutex 里，完成后变成 None
+struct Task {
+    future: Mutex<Option<BoxFuture>>,
+    ready: Sender<Arc<Task>>,
+}
+
+// 唤醒任务就是把它重新放进就绪队列
+impl Wake for Task {
+    fn wake(self: Arc<Self>) {
+        let ready = self.ready.clone();
+        // 执行器已经退出时发送会失败，这时忽略即可
+        let _ = ready.send(self);
+    }
+}
+
+pub struct Executor {
+    ready: Receiver<Arc<Task>>,
+}
+
+#[derive(Clone)]
+pub struct Spawner {
+    ready: Sender<Arc<Task>>,
+}
+
+pub fn new_executor() -> (Executor, Spawner) {
+    let (sender, receiver) = mpsc::channel();
+    (Executor { ready: receiver }, Spawner { ready: sender })
+}
+
+impl Spawner {
+    pub fn spawn(&self, future: impl Future<Output = ()> + Send + 'static) {
+        let task = Arc::new(Task { future: Mutex::new(Some(Box::pin(future))), ready: self.ready.clone() });
+        // 新任务直接进入就绪队列
+        let _ = self.ready.send(task);
+    }
+
+    // 把 future 的结果通过 oneshot 送回，返回等待结果的句柄
+    pub fn spawn_with_handle<T: Send + 'static>(&self, future: impl Future<Output = T> + Send + 'static) -> JoinHandle<T> {
+        let (sender, receiver) = oneshot();
+        self.spawn(async move {
+            sender.send(future.await);
+        });
+        JoinHandle { receiver }
+    }
+}
+
+impl Executor {
+    // 运行到所有任务完成，返回 poll 的总次数
+    pub fn run(self) -> usize {
+        let mut polls = 0;
+        // 每个任务都持有一个 Sender；所有任务都结束、所有 Spawner 都被丢弃后 recv 返回 Err
+        while let Ok(task) = self.ready.recv() {
+            let mut slot = task.future.lock().unwrap();
+            if let Some(mut future) = slot.take() {
+                let waker = Waker::from(Arc::clone(&task));
+                let mut cx = Context::from_waker(&waker);
+                polls += 1;
+                if future.as_mut().poll(&mut cx).is_pending() {
+                    // 还没完成，放回去等下次被唤醒
+                    *slot = Some(future);
+                }
+            }
+        }
+        polls
+    }
+}
+
+// 只能发送一次的通道：接收端是一个 future
+struct Shared<T> {
+    value: Option<T>,
+    waker: Option<Waker>,
+    // 发送端已经被丢弃：之后不会再有值
+    closed: bool,
+}
+
+pub struct OneshotSender<T> {
+    shared: Arc<Mutex<Shared<T>>>,
+}
+
+pub struct OneshotReceiver<T> {
+    shared: Arc<Mutex<Shared<T>>>,
+}
+
+pub fn oneshot<T>() -> (OneshotSender<T>, OneshotReceiver<T>) {
+    let shared = Arc::new(Mutex::new(Shared { value: None, waker: None, closed: false }));
+    (OneshotSender { shared: Arc::clone(&shared) }, OneshotReceiver { shared })
+}
+
+impl<T> OneshotSender<T> {
+    pub fn send(self, value: T) {
+        let waker = {
+            let mut shared = self.shared.lock().unwrap();
+            shared.value = Some(value);
+            shared.waker.take()
+        };
+        // 先释放锁再唤醒，被唤醒的任务可能马上就要加锁
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+// 发送端没有发送就被丢弃时也要唤醒接收方，否则等待它的任务永远 Pending，
+// 任务里的 Sender 让就绪队列一直开着，run 永远不会返回
+impl<T> Drop for OneshotSender<T> {
+    fn drop(&mut self) {
+        let waker = {
+            let mut shared = self.shared.lock().unwrap();
+            shared.closed = true;
+            shared.waker.take()
+        };
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+// 收到值时返回 Some，发送端没有发送就被丢弃时返回 None
+impl<T> Future for OneshotReceiver<T> {
+    type Output = Option<T>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        let mut shared = self.shared.lock().unwrap();
+        match shared.value.take() {
+            Some(value) => Poll::Ready(Some(value)),
+            None if shared.closed => Poll::Ready(None),
+            None => {
+                // 保存最新的 Waker，值到达时用它唤醒当前任务
+                shared.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+// 等待 spawn_with_handle 启动的任务的结果
+pub struct JoinHandle<T> {
+    receiver: OneshotReceiver<T>,
+}
+
+impl<T> Future for JoinHandle<T> {
+    type Output = T;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<T> {
+        // 发送端在任务的 future 里，只有任务没完成就被丢弃（例如它 panic 了）时才收不到值
+        Pin::new(&mut self.receiver).poll(cx).map(|value| value.expect("任务没有完成就被丢弃了"))
+    }
+}
@@ -0,0 +1,110 @@
+//! 异步编程练习
+//!
+//! 把下面函数中的 `todo!()` 换成实现，然后运行 `rust_study check <练习>` 检查，
+//! 例如 `rust_study check async_basics/sum_after_yields`。
+
+use crate::async_basics::futures::{block_on, poll_to_end};
+use crate::check::{expect_eq, Case, Exercise};
+
+// 练习 1：依次累加 values 中的元素，每加一个之前先 YieldNow::new().await 让出一次
+// （参考 futures 课中的 add_after_yields）
+pub async fn sum_after_yields(values: Vec<i32>) -> i32 {
+    todo!()
+}
+
+// 练习 2：用 executor 课中的 new_executor，为每个名字 spawn 一个任务；
+// 任务循环 steps 次，每次把 "名字+步数"（例如 "a1"）推入共享的日志后让出一次。
+// 运行执行器，返回日志
+pub fn interleave_tasks(names: &[&'static str], steps: u32) -> Vec<String> {
+    todo!()
+}
+
+// 练习 3：为每个延迟（毫秒）创建一个 async 块：等待对应的 TimerFuture 后返回这个延迟；
+// 用 timers 课中的 join_all 同时等待它们，返回结果
+pub fn wait_all(delays: Vec<u64>) -> Vec<u64> {
+    todo!()
+}
+
+pub static EXERCISES: &[Exercise] = &[
+    Exercise {
+        id: "sum_after_yields",
+        topic: "async_basics",
+        lesson: "futures",
+        title: "在 async fn 中让出",
+        title_en: "Yielding inside an async fn",
+        cases: &[
+            Case {
+                name: "空向量",
+                name_en: "Empty vector",
+                hint: "没有元素时不需要让出，第一次 poll 就返回 Ready(0)",
+                hint_en: "With no elements there is nothing to yield; the first poll returns Ready(0)",
+                run: || expect_eq(poll_to_end(sum_after_yields(Vec::new())), (0, 1)),
+            },
+            Case {
+                name: "求和",
+                name_en: "Sum",
+                hint: "for value in values { YieldNow::new().await; total += value; }",
+                hint_en: "for value in values { YieldNow::new().await; total += value; }",
+                run: || expect_eq(block_on(sum_after_yields(vec![1, 2, 3])), 6),
+            },
+            Case {
+                name: "每个元素让出一次",
+                name_en: "Yields once per element",
+                hint: "三个元素让出三次，加上最后完成的那次，一共 poll 4 次",
+                hint_en: "Three elements yield three times; with the final poll that completes, that is 4 polls",
+                run: || expect_eq(poll_to_end(sum_after_yields(vec![4, 5, 6])), (15, 4)),
+            },
+        ],
+    },
+    Exercise {
+        id: "interleave_tasks",
+        topic: "async_basics",
+        lesson: "executor",
+        title: "交替执行的任务",
+        title_en: "Interleaving tasks",
+        cases: &[
+            Case {
+                name: "一个任务",
+                name_en: "One task",
+                hint: "let (executor, spawner) = new_executor(); spawn 之后要 drop(spawner) 再 executor.run()",
+                hint_en: "let (executor, spawner) = new_executor(); after spawning, drop(spawner) before executor.run()",
+                run: || expect_eq(interleave_tasks(&["a"], 2), vec![String::from("a1"), String::from("a2")]),
+            },
+            Case {
+                name: "两个任务交替",
+                name_en: "Two tasks take turns",
+                hint: "日志用 Arc<Mutex<Vec<String>>>，每个任务 Arc::clone 一份；让出后执行器先运行另一个任务",
+                hint_en: "Keep the log in an Arc<Mutex<Vec<String>>> and Arc::clone it for each task; after a yield the executor runs the other task first",
+                run: || {
+                    expect_eq(
+                        interleave_tasks(&["a", "b"], 2),
+                        ["a1", "b1", "a2", "b2"].map(String::from).to_vec(),
+                    )
+                },
+            },
+        ],
+    },
+    Exercise {
+        id: "wait_all",
+        topic: "async_basics",
+        lesson: "timers",
+        title: "等待一组定时器",
+        title_en: "Waiting for a group of timers",
+        cases: &[
+            Case {
+                name: "没有定时器",
+                name_en: "No timers",
+                hint: "join_all(Vec::new()) 第一次 poll 就完成，返回空向量",
+                hint_en: "join_all(Vec::new()) completes on the first poll and returns an empty vector",
+                run: || expect_eq(wait_all(Vec::new()), Vec::new()),
+            },
+            Case {
+                name: "结果按参数顺序排列",
+                name_en: "Results follow the argument order",
+                hint: "delays.into_iter().map(|ms| async move { TimerFuture::new(Duration::from_millis(ms)).await; ms })",
+                hint_en: "delays.into_iter().map(|ms| async move { TimerFuture::new(Duration::from_millis(ms)).await; ms })",
+                run: || expect_eq(wait_all(vec![30, 10, 20]), vec![30, 10, 20]),
+            },
+        ],
+    },
+];
@@ -0,0 +1,274 @@
+//! Future 与 async/await
+//!
+//! `async fn` 和 `async` 块不会立即执行，而是返回一个实现了 `Future` trait 的值。
+//! `Future` 只有一个方法 `poll`：
+//! - 返回 `Poll::Ready(value)` 表示已经完成
+//! - 返回 `Poll::Pending` 表示还没完成，并且保证在可以继续时调用 `Context` 中的 `Waker`
+//!
+//! 编译器把 `async fn` 展开成一个状态机，每个 `.await` 是一个可能暂停的位置。
+//! 标准库只提供这些 trait 和类型，不提供运行 future 的执行器（executor）；
+//! 本课用线程的 park/unpark 写一个最小的 `block_on`。
+
+use std::cell::RefCell;
+use std::future::{self, Future};
+use std::io::{self, Write};
+use std::pin::{pin, Pin};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
+use std::thread::{self, Thread};
+
+use crate::context::Ctx;
+
+pub fn main(ctx: &mut Ctx) -> io::Result<()> {
+    writeln!(ctx, "=== {} ===", ctx.text("lesson.futures"))?;
+
+    ctx.section(1, "Future trait 和 Poll", "The Future trait and Poll")?;
+    // Waker::noop 什么也不做，手动 poll 时够用了
+    let mut cx = Context::from_waker(Waker::noop());
+    let mut ready = pin!(future::ready(5));
+    writeln!(ctx, "poll ready(5): {:?}", ready.as_mut().poll(&mut cx))?;
+    let mut pending = pin!(future::pending::<i32>());
+    writeln!(ctx, "poll pending(): {:?}", pending.as_mut().poll(&mut cx))?;
+
+    ctx.section(2, "async fn 是惰性的", "async fns are lazy")?;
+    let log = RefCell::new(Vec::new());
+    let greeting = greet(&log, "Rust");
+    log.borrow_mut().push(String::from("future 已创建"));
+    // 只有被 poll 时函数体才开始执行
+    let mut greeting = pin!(greeting);
+    let result = greeting.as_mut().poll(&mut cx);
+    writeln!(ctx, "poll 结果: {:?}", result)?;
+    writeln!(ctx, "执行顺序: {:?}", log.borrow())?;
+
+    ctx.section(3, "手写一个 Future", "Writing a Future by hand")?;
+    // YieldNow 第一次 poll 返回 Pending，并立即唤醒自己，第二次返回 Ready
+    let mut yield_now = pin!(YieldNow::new());
+    writeln!(ctx, "第一次 poll: {:?}", yield_now.as_mut().poll(&mut cx))?;
+    writeln!(ctx, "第二次 poll: {:?}", yield_now.as_mut().poll(&mut cx))?;
+    let (value, polls) = poll_to_end(Countdown::new(3));
+    writeln!(ctx, "Countdown(3) 完成: {}，共 poll 了 {} 次", value, polls)?;
+
+    ctx.section(4, "async fn 展开成状态机", "An async fn becomes a state machine")?;
+    let (value, polls) = poll_to_end(add_after_yields(2, 3));
+    writeln!(ctx, "async fn: 结果 {}，poll {} 次", value, polls)?;
+    // 下面的 AddAfterYields 就是编译器为上面的 async fn 生成的代码的手写版本
+    let (value, polls) = poll_to_end(AddAfterYields::Start { a: 2, b: 3 });
+    writeln!(ctx, "手写状态机: 结果 {}，poll {} 次", value, polls)?;
+
+    ctx.section(5, "block_on：用 Waker 唤醒等待的线程", "block_on: waking the waiting thread with a Waker")?;
+    // block_on 在 Pending 时 park 当前线程，Waker 被调用时 unpark 它
+    writeln!(ctx, "block_on(Countdown(5)) = {}", block_on(Countdown::new(5)))?;
+    let wakes = Arc::new(CountingWaker::default());
+    let waker = Waker::from(Arc::clone(&wakes));
+    let mut cx = Context::from_waker(&waker);
+    let mut countdown = pin!(Countdown::new(4));
+    while countdown.as_mut().poll(&mut cx).is_pending() {}
+    writeln!(ctx, "Countdown(4) 调用了 {} 次 wake", wakes.count())?;
+
+    ctx.section(6, ".await 组合多个 async fn", "Composing async fns with .await")?;
+    let total = block_on(total_length(&["future", "poll", "waker"]));
+    writeln!(ctx, "所有单词的总长度: {}", total)?;
+
+    ctx.section(7, "async 块和 move", "async blocks and move")?;
+    let name = String::from("async 块");
+    // async move 块拿走 name 的所有权，得到的 future 可以离开当前作用域
+    let future = async move {
+        YieldNow::new().await;
+        format!("{} 完成", name)
+    };
+    writeln!(ctx, "{}", block_on(future))?;
+    let futures: Vec<Pin<Box<dyn Future<Output = i32>>>> = vec![Box::pin(async { 1 }), Box::pin(add_after_yields(10, 20))];
+    let results: Vec<i32> = futures.into_iter().map(block_on).collect();
+    writeln!(ctx, "装箱成 Pin<Box<dyn Future>>: {:?}", results)?;
+
+    ctx.section(8, "为什么需要 Pin", "Why Pin is needed")?;
+    // 这个 future 在 .await 前后都持有指向自己局部变量的引用，是自引用结构；
+    // poll 之后再移动它，引用就会失效，所以 poll 要求 Pin<&mut Self>
+    let self_referential = async {
+        let data = [1, 2, 3];
+        let first = &data[0];
+        YieldNow::new().await;
+        *first + data.len() as i32
+    };
+    // pin! 把 future 固定在栈上，Box::pin 把它固定在堆上
+    let mut pinned = pin!(self_referential);
+    let mut cx = Context::from_waker(Waker::noop());
+    writeln!(ctx, "第一次 poll: {:?}", pinned.as_mut().poll(&mut cx))?;
+    writeln!(ctx, "第二次 poll: {:?}", pinned.as_mut().poll(&mut cx))?;
+
+    Ok(())
+}
+
+async fn greet(log: &RefCell<Vec<String>>, name: &str) -> String {
+    log.borrow_mut().push(String::from("函数体开始执行"));
+    format!("你好，{}", name)
+}
+
+// 让出一次：第一次 poll 返回 Pending，第二次返回 Ready
+pub struct YieldNow {
+    yielded: bool,
+}
+
+impl YieldNow {
+    pub fn new() -> YieldNow {
+        YieldNow { yielded: false }
+    }
+}
+
+impl Future for YieldNow {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.yielded {
+            return Poll::Ready(());
+        }
+        self.yielded = true;
+        // 返回 Pending 之前必须安排好唤醒，否则执行器不知道什么时候再 poll
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    }
+}
+
+// 需要 poll remaining + 1 次才完成，完成时返回最初的 remaining
+pub struct Countdown {
+    start: u32,
+    remaining: u32,
+}
+
+impl Countdown {
+    pub fn new(remaining: u32) -> Countdown {
+        Countdown { start: remaining, remaining }
+    }
+}
+
+impl Future for Countdown {
+    type Output = u32;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<u32> {
+        if self.remaining == 0 {
+            return Poll::Ready(self.start);
+        }
+        self.remaining -= 1;
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    }
+}
+
+async fn add_after_yields(a: i32, b: i32) -> i32 {
+    YieldNow::new().await;
+    let sum = a + b;
+    YieldNow::new().await;
+    sum
+}
+
+// 手写的状态机：每个 .await 对应一个状态，跨越 .await 的局部变量保存在状态里
+enum AddAfterYields {
+    Start { a: i32, b: i32 },
+    FirstYield { a: i32, b: i32, yield_now: YieldNow },
+    SecondYield { sum: i32, yield_now: YieldNow },
+    Done,
+}
+
+impl Future for AddAfterYields {
+    type Output = i32;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<i32> {
+        // 所有字段都是 Unpin 的，可以直接拿到 &mut Self
+        let this = &mut *self;
+        loop {
+            match this {
+                AddAfterYields::Start { a, b } => {
+                    *this = AddAfterYields::FirstYield { a: *a, b: *b, yield_now: YieldNow::new() };
+                }
+                AddAfterYields::FirstYield { a, b, yield_now } => {
+                    if Pin::new(yield_now).poll(cx).is_pending() {
+                        return Poll::Pending;
+                    }
+                    *this = AddAfterYields::SecondYield { sum: *a + *b, yield_now: YieldNow::new() };
+                }
+                AddAfterYields::SecondYield { sum, yield_now } => {
+                    if Pin::new(yield_now).poll(cx).is_pending() {
+                        return Poll::Pending;
+                    }
+                    let sum = *sum;
+                    *this = AddAfterYields::Done;
+                    return Poll::Ready(sum);
+                }
+                AddAfterYields::Done => panic!("future 完成后又被 poll"),
+            }
+        }
+    }
+}
+
+// 用空的 Waker 反复 poll，返回结果和 poll 的次数
+pub fn poll_to_end<F: Future>(future: F) -> (F::Output, u32) {
+    let mut future = pin!(future);
+    let mut cx = Context::from_waker(Waker::noop());
+    let mut polls = 0;
+    loop {
+        polls += 1;
+        if let Poll::Ready(value) = future.as_mut().poll(&mut cx) {
+            return (value, polls);
+        }
+    }
+}
+
+// 唤醒时 unpark 等待的线程
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+// 在当前线程上运行 future 直到完成
+pub fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut cx = Context::from_waker(&waker);
+    loop {
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(value) => return value,
+            // 如果 unpark 已经先发生，park 会立即返回
+            Poll::Pending => thread::park(),
+        }
+    }
+}
+
+// 记录被唤醒了多少次
+#[derive(Default)]
+struct CountingWaker {
+    wakes: AtomicUsize,
+}
+
+impl CountingWaker {
+    fn count(&self) -> usize {
+        self.wakes.load(Ordering::SeqCst)
+    }
+}
+
+impl Wake for CountingWaker {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.wakes.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+async fn word_length(word: &str) -> usize {
+    YieldNow::new().await;
+    word.chars().count()
+}
+
+async fn total_length(words: &[&str]) -> usize {
+    let mut total = 0;
+    for word in words {
+        // 每个 .await 都可能暂停，恢复后从这里继续
+        total += word_length(word).await;
+    }
+    total
+}
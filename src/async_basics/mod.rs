@@ -0,0 +1,10 @@
+//! 异步编程模块
+//!
+//! 包含 Future、执行器、Waker、定时器和 join 等异步示例，只使用标准库。
+
+// 声明子模块
+pub mod futures;
+pub mod executor;
+pub mod timers;
+pub mod exercises;
+pub mod quiz;
@@ -0,0 +1,81 @@
+//! 异步编程小测验
+//!
+//! 预测输出题运行与 futures 和 timers 课相同的代码，答案以实际运行结果为准。
+
+use std::cell::RefCell;
+use std::io::{self, Write};
+
+use crate::async_basics::futures::{block_on, YieldNow};
+use crate::async_basics::timers::join;
+use crate::context::Ctx;
+use crate::quiz::{Answer, Question};
+
+pub static QUESTIONS: &[Question] = &[
+    Question {
+        topic: "async_basics",
+        lesson: "futures",
+        prompt: "下面的代码输出什么？",
+        prompt_en: "What does this code print?",
+        code: "let log = RefCell::new(Vec::new());\nlet future = async { log.borrow_mut().push(\"执行\") };\nlog.borrow_mut().push(\"创建\");\nblock_on(future);\nprintln!(\"{:?}\", log.borrow());",
+        options: &["[\"执行\", \"创建\"]", "[\"创建\", \"执行\"]", "[\"创建\"]", "编译错误：async 块不能借用 log"],
+        options_en: &["[\"执行\", \"创建\"]", "[\"创建\", \"执行\"]", "[\"创建\"]", "Compile error: an async block can't borrow log"],
+        answer: Answer::Output(lazy_future),
+        explanation: "async 块只是创建了一个 future，直到 block_on 第一次 poll 它，块中的代码才开始执行。",
+        explanation_en: "An async block only creates a future; its code starts running when block_on polls it for the first time.",
+    },
+    Question {
+        topic: "async_basics",
+        lesson: "futures",
+        prompt: "手写的 Future 在 poll 中返回 Poll::Pending 之前必须做什么？",
+        prompt_en: "What must a hand-written Future do before returning Poll::Pending from poll?",
+        code: "",
+        options: &[
+            "调用 thread::sleep 等待一会儿",
+            "保证以后会调用 cx.waker()（保存它，或者立即 wake_by_ref）",
+            "什么都不用做，执行器会不断重试",
+            "返回之前再 poll 自己一次",
+        ],
+        options_en: &[
+            "Call thread::sleep and wait a little",
+            "Make sure cx.waker() gets called later (store it, or wake_by_ref right away)",
+            "Nothing, the executor keeps retrying",
+            "Poll itself once more before returning",
+        ],
+        answer: Answer::Choice(1),
+        explanation: "执行器只在 Waker 被调用后才会再次 poll 任务；如果没人调用 Waker，这个 future 就永远不会完成。",
+        explanation_en: "An executor polls a task again only after its Waker is called; if nobody calls the Waker, the future never completes.",
+    },
+    Question {
+        topic: "async_basics",
+        lesson: "timers",
+        prompt: "下面的代码输出什么？",
+        prompt_en: "What does this code print?",
+        code: "async fn steps(log: &RefCell<Vec<String>>, name: &str, n: u32) {\n    for i in 1..=n {\n        log.borrow_mut().push(format!(\"{}{}\", name, i));\n        YieldNow::new().await;\n    }\n}\n\nlet log = RefCell::new(Vec::new());\nblock_on(join(steps(&log, \"a\", 2), steps(&log, \"b\", 2)));\nprintln!(\"{}\", log.borrow().join(\" \"));",
+        options: &["a1 a2 b1 b2", "a1 b1 a2 b2", "b1 b2 a1 a2", "顺序不确定"],
+        options_en: &["a1 a2 b1 b2", "a1 b1 a2 b2", "b1 b2 a1 a2", "Nondeterministic"],
+        answer: Answer::Output(join_order),
+        explanation: "join 每次被 poll 时依次 poll 两个 future；两者都在每步之后让出，所以执行交替进行，而且顺序是确定的。",
+        explanation_en: "Each time join is polled it polls both futures in turn; both yield after every step, so they alternate, and the order is deterministic.",
+    },
+];
+
+fn lazy_future(ctx: &mut Ctx) -> io::Result<()> {
+    let log = RefCell::new(Vec::new());
+    let future = async { log.borrow_mut().push("执行") };
+    log.borrow_mut().push("创建");
+    block_on(future);
+    writeln!(ctx, "{:?}", log.borrow())
+}
+
+async fn steps(log: &RefCell<Vec<String>>, name: &str, n: u32) {
+    for i in 1..=n {
+        log.borrow_mut().push(format!("{}{}", name, i));
+        YieldNow::new().await;
+    }
+}
+
+fn join_order(ctx: &mut Ctx) -> io::Result<()> {
+    let log = RefCell::new(Vec::new());
+    block_on(join(steps(&log, "a", 2), steps(&log, "b", 2)));
+    writeln!(ctx, "{}", log.borrow().join(" "))
+}
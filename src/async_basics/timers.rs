@@ -0,0 +1,247 @@
+//! 定时器和 join
+//!
+//! 真正的异步操作（网络、定时器）在别处完成，完成时通过保存的 `Waker` 通知执行器。
+//! 本课的 `TimerFuture` 用一个后台线程睡眠指定的时间，然后调用 `Waker`；
+//! 等待期间执行器线程可以去运行别的任务，或者直接休眠。
+//!
+//! 依次 `.await` 两个 future 是串行的；`join` 组合器在一次 poll 中轮流 poll 两个 future，
+//! 让它们同时推进，总耗时取决于较慢的那个。
+
+use std::future::Future;
+use std::io::{self, Write};
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::async_basics::executor::new_executor;
+use crate::async_basics::futures::{block_on, poll_to_end, Countdown, YieldNow};
+use crate::context::Ctx;
+
+pub fn main(ctx: &mut Ctx) -> io::Result<()> {
+    writeln!(ctx, "=== {} ===", ctx.text("lesson.timers"))?;
+
+    ctx.section(1, "TimerFuture：在另一个线程中计时", "TimerFuture: timing on another thread")?;
+    let start = Instant::now();
+    block_on(TimerFuture::new(Duration::from_millis(20)));
+    // 具体耗时取决于系统调度，只比较下限
+    writeln!(ctx, "等待了至少 20 毫秒: {}", start.elapsed() >= Duration::from_millis(20))?;
+
+    ctx.section(2, "Pending 期间 Waker 被保存起来", "The Waker is stored while Pending")?;
+    let mut timer = TimerFuture::new(Duration::from_millis(20));
+    let waker = Waker::noop();
+    let mut cx = Context::from_waker(waker);
+    writeln!(ctx, "立即 poll: {:?}", Pin::new(&mut timer).poll(&mut cx))?;
+    writeln!(ctx, "已保存 Waker: {}", timer.has_waker())?;
+    // 睡眠线程结束后状态变为完成，再 poll 就得到 Ready。
+    // 这里的 Waker 什么也不做，只能自己隔一会儿检查一次；系统繁忙时 20 毫秒可能不够
+    while !timer.is_completed() {
+        thread::sleep(Duration::from_millis(5));
+    }
+    writeln!(ctx, "稍后再 poll: {:?}", Pin::new(&mut timer).poll(&mut cx))?;
+
+    ctx.section(3, "依次 .await：耗时相加", ".await one after another: the times add up")?;
+    let start = Instant::now();
+    block_on(async {
+        TimerFuture::new(Duration::from_millis(30)).await;
+        TimerFuture::new(Duration::from_millis(30)).await;
+    });
+    writeln!(ctx, "两个 30 毫秒的定时器依次等待，至少 60 毫秒: {}", start.elapsed() >= Duration::from_millis(60))?;
+
+    ctx.section(4, "join：同时等待，结果按参数顺序返回", "join: waiting concurrently, results in argument order")?;
+    let start = Instant::now();
+    let (a, b) = block_on(join(delayed(30, "慢"), delayed(10, "快")));
+    // 快的先完成，但结果仍然是 (第一个, 第二个)
+    writeln!(ctx, "join 的结果: ({}, {})", a, b)?;
+    writeln!(ctx, "至少等待了较慢的 30 毫秒: {}", start.elapsed() >= Duration::from_millis(30))?;
+
+    ctx.section(5, "join 如何轮流 poll", "How join takes turns polling")?;
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let (left, right) = block_on(join(steps(&log, "左", 3), steps(&log, "右", 1)));
+    writeln!(ctx, "执行记录: {}", log.lock().unwrap().join(" "))?;
+    writeln!(ctx, "结果: {} {}", left, right)?;
+
+    ctx.section(6, "join_all：等待一组 future", "join_all: waiting for a group of futures")?;
+    let start = Instant::now();
+    let labels = block_on(join_all((1..=4).map(|i| delayed(10 * i, "定时器")).collect()));
+    writeln!(ctx, "结果: {:?}", labels)?;
+    writeln!(ctx, "至少等待了最慢的 40 毫秒: {}", start.elapsed() >= Duration::from_millis(40))?;
+
+    ctx.section(7, "在执行器上运行定时器任务", "Running timer tasks on the executor")?;
+    let results = Arc::new(Mutex::new(Vec::new()));
+    let (executor, spawner) = new_executor();
+    for (name, millis) in [("甲", 20), ("乙", 10)] {
+        let results = Arc::clone(&results);
+        spawner.spawn(async move {
+            TimerFuture::new(Duration::from_millis(millis)).await;
+            results.lock().unwrap().push(format!("{} 醒来", name));
+        });
+    }
+    drop(spawner);
+    // 两个任务都在等定时器时，执行器阻塞在 recv 上，直到某个 Waker 把任务送回队列
+    let polls = executor.run();
+    let mut results = results.lock().unwrap().clone();
+    // 完成顺序取决于调度，排序后再输出
+    results.sort();
+    writeln!(ctx, "{:?}，共 poll {} 次", results, polls)?;
+
+    ctx.section(8, "用 Countdown 观察 join 的 poll 次数", "Counting join's polls with Countdown")?;
+    // 两个 future 在同一次 poll 中推进，总次数取决于较慢的那个，而不是两者相加
+    let ((a, b), polls) = poll_to_end(join(Countdown::new(2), Countdown::new(5)));
+    writeln!(ctx, "join(Countdown(2), Countdown(5)) = ({}, {})，poll {} 次", a, b, polls)?;
+
+    Ok(())
+}
+
+// 定时器线程和 future 共享的状态
+struct TimerState {
+    completed: bool,
+    waker: Option<Waker>,
+}
+
+pub struct TimerFuture {
+    state: Arc<Mutex<TimerState>>,
+}
+
+impl TimerFuture {
+    pub fn new(duration: Duration) -> TimerFuture {
+        let state = Arc::new(Mutex::new(TimerState { completed: false, waker: None }));
+        let thread_state = Arc::clone(&state);
+        thread::spawn(move || {
+            thread::sleep(duration);
+            let waker = {
+                let mut state = thread_state.lock().unwrap();
+                state.completed = true;
+                state.waker.take()
+            };
+            if let Some(waker) = waker {
+                waker.wake();
+            }
+        });
+        TimerFuture { state }
+    }
+
+    fn has_waker(&self) -> bool {
+        self.state.lock().unwrap().waker.is_some()
+    }
+
+    fn is_completed(&self) -> bool {
+        self.state.lock().unwrap().completed
+    }
+}
+
+impl Future for TimerFuture {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let mut state = self.state.lock().unwrap();
+        if state.completed {
+            return Poll::Ready(());
+        }
+        // future 可能在两次 poll 之间被移到别的任务，每次都要保存最新的 Waker
+        match &state.waker {
+            Some(waker) if waker.will_wake(cx.waker()) => {}
+            _ => state.waker = Some(cx.waker().clone()),
+        }
+        Poll::Pending
+    }
+}
+
+// 还没完成的 future（装箱固定在堆上），或者它的结果
+enum MaybeDone<F: Future> {
+    Pending(Pin<Box<F>>),
+    Done(F::Output),
+    Taken,
+}
+
+// 结果从来不会被固定（pin），所以不论 F::Output 是什么，MaybeDone 都可以安全地移动
+impl<F: Future> Unpin for MaybeDone<F> {}
+
+impl<F: Future> MaybeDone<F> {
+    fn new(future: F) -> MaybeDone<F> {
+        MaybeDone::Pending(Box::pin(future))
+    }
+
+    // 推进一次，返回是否已经完成
+    fn poll(&mut self, cx: &mut Context<'_>) -> bool {
+        if let MaybeDone::Pending(future) = self {
+            match future.as_mut().poll(cx) {
+                Poll::Ready(value) => *self = MaybeDone::Done(value),
+                Poll::Pending => return false,
+            }
+        }
+        true
+    }
+
+    fn take(&mut self) -> F::Output {
+        match std::mem::replace(self, MaybeDone::Taken) {
+            MaybeDone::Done(value) => value,
+            _ => panic!("结果还没准备好或已经被取走"),
+        }
+    }
+}
+
+pub struct Join<A: Future, B: Future> {
+    a: MaybeDone<A>,
+    b: MaybeDone<B>,
+}
+
+// 同时等待两个 future，返回两者的结果
+pub fn join<A: Future, B: Future>(a: A, b: B) -> Join<A, B> {
+    Join { a: MaybeDone::new(a), b: MaybeDone::new(b) }
+}
+
+impl<A: Future, B: Future> Future for Join<A, B> {
+    type Output = (A::Output, B::Output);
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // 两个都要 poll，不能因为第一个还没完成就跳过第二个
+        let a_done = self.a.poll(cx);
+        let b_done = self.b.poll(cx);
+        if a_done && b_done {
+            Poll::Ready((self.a.take(), self.b.take()))
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+pub struct JoinAll<F: Future> {
+    futures: Vec<MaybeDone<F>>,
+}
+
+// 同时等待一组同类型的 future，结果按原来的顺序排列
+pub fn join_all<F: Future>(futures: Vec<F>) -> JoinAll<F> {
+    JoinAll { futures: futures.into_iter().map(MaybeDone::new).collect() }
+}
+
+impl<F: Future> Future for JoinAll<F> {
+    type Output = Vec<F::Output>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut all_done = true;
+        for future in self.futures.iter_mut() {
+            all_done &= future.poll(cx);
+        }
+        if all_done {
+            Poll::Ready(self.futures.iter_mut().map(MaybeDone::take).collect())
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+async fn delayed(millis: u64, label: &str) -> String {
+    TimerFuture::new(Duration::from_millis(millis)).await;
+    format!("{}（{} 毫秒）", label, millis)
+}
+
+// 每步记录一次再让出，最后返回步数
+async fn steps(log: &Mutex<Vec<String>>, name: &str, count: u32) -> u32 {
+    for step in 1..=count {
+        log.lock().unwrap().push(format!("{}{}", name, step));
+        YieldNow::new().await;
+    }
+    count
+}
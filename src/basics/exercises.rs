@@ -0,0 +1,100 @@
+//! 基础语法练习
+//!
+//! 把下面函数中的 `todo!()` 换成实现，然后运行 `rust_study check <练习>` 检查，
+//! 例如 `rust_study check basics/fahrenheit`。
+
+use crate::check::{expect_eq, Case, Exercise};
+
+// 练习 1：摄氏度转华氏度（参考 functions 课中有返回值的函数）
+pub fn fahrenheit(celsius: f64) -> f64 {
+    todo!()
+}
+
+// 练习 2：交换元组中的两个值（参考 data_types 课中的元组）
+pub fn swap(pair: (i32, i32)) -> (i32, i32) {
+    todo!()
+}
+
+// 练习 3：计算数组中所有元素的和（参考 data_types 课中的数组）
+pub fn sum(numbers: [i32; 5]) -> i32 {
+    todo!()
+}
+
+pub static EXERCISES: &[Exercise] = &[
+    Exercise {
+        id: "fahrenheit",
+        topic: "basics",
+        lesson: "functions",
+        title: "摄氏度转华氏度",
+        title_en: "Celsius to Fahrenheit",
+        cases: &[
+            Case {
+                name: "冰点 0°C",
+                name_en: "Freezing point 0°C",
+                hint: "华氏度 = 摄氏度 × 9 / 5 + 32；注意用 9.0 / 5.0 做浮点运算",
+                hint_en: "Fahrenheit = Celsius × 9 / 5 + 32; use 9.0 / 5.0 so the arithmetic is floating point",
+                run: || expect_eq(fahrenheit(0.0), 32.0),
+            },
+            Case {
+                name: "沸点 100°C",
+                name_en: "Boiling point 100°C",
+                hint: "函数体最后一个表达式不加分号，它就是返回值",
+                hint_en: "The last expression in the body has no semicolon; it is the return value",
+                run: || expect_eq(fahrenheit(100.0), 212.0),
+            },
+            Case {
+                name: "-40°C 与 -40°F 相等",
+                name_en: "-40°C equals -40°F",
+                hint: "负数同样适用这个公式",
+                hint_en: "The same formula works for negative numbers",
+                run: || expect_eq(fahrenheit(-40.0), -40.0),
+            },
+        ],
+    },
+    Exercise {
+        id: "swap",
+        topic: "basics",
+        lesson: "data_types",
+        title: "交换元组",
+        title_en: "Swap a tuple",
+        cases: &[
+            Case {
+                name: "(1, 2) 变为 (2, 1)",
+                name_en: "(1, 2) becomes (2, 1)",
+                hint: "可以用 let (a, b) = pair; 解构元组，再返回 (b, a)",
+                hint_en: "Destructure the tuple with let (a, b) = pair; and return (b, a)",
+                run: || expect_eq(swap((1, 2)), (2, 1)),
+            },
+            Case {
+                name: "相同的值",
+                name_en: "Equal values",
+                hint: "也可以用 pair.0 和 pair.1 访问元组元素",
+                hint_en: "You can also reach the elements with pair.0 and pair.1",
+                run: || expect_eq(swap((7, 7)), (7, 7)),
+            },
+        ],
+    },
+    Exercise {
+        id: "sum",
+        topic: "basics",
+        lesson: "data_types",
+        title: "数组求和",
+        title_en: "Sum an array",
+        cases: &[
+            Case {
+                name: "1 到 5",
+                name_en: "1 to 5",
+                hint: "用 for n in numbers 遍历数组，把每个元素加到一个 let mut total 上",
+                hint_en: "Walk the array with for n in numbers and add each element to a let mut total",
+                run: || expect_eq(sum([1, 2, 3, 4, 5]), 15),
+            },
+            Case {
+                name: "包含负数",
+                name_en: "With negative numbers",
+                hint: "也可以直接用 numbers.iter().sum()",
+                hint_en: "numbers.iter().sum() works too",
+                run: || expect_eq(sum([-3, 3, -2, 2, 10]), 10),
+            },
+        ],
+    },
+];
@@ -3,53 +3,59 @@
 //! Rust 代码中的函数和变量名使用 snake_case 规范。
 //! 函数使用 `fn` 关键字定义，可以有参数和返回值。
 
-pub fn main() {
-    println!("=== 函数示例 ===");
+use std::io::{self, Write};
 
-    // 1. 无参数无返回值的函数
-    print_hello();
+use crate::context::Ctx;
 
-    // 2. 带参数的函数
-    print_number(42);
+pub fn main(ctx: &mut Ctx) -> io::Result<()> {
+    writeln!(ctx, "=== {} ===", ctx.text("lesson.functions"))?;
 
-    // 3. 带多个参数的函数
-    print_sum(5, 3);
+    ctx.section(1, "无参数无返回值的函数", "Functions with no parameters and no return value")?;
+    print_hello(ctx)?;
 
-    // 4. 有返回值的函数
+    ctx.section(2, "带参数的函数", "Functions with a parameter")?;
+    print_number(ctx, 42)?;
+
+    ctx.section(3, "带多个参数的函数", "Functions with several parameters")?;
+    print_sum(ctx, 5, 3)?;
+
+    ctx.section(4, "有返回值的函数", "Functions with a return value")?;
     let result = add(10, 20);
-    println!("10 + 20 = {}", result);
+    writeln!(ctx, "10 + 20 = {}", result)?;
 
-    // 5. 使用表达式作为返回值
+    ctx.section(5, "使用表达式作为返回值", "Expressions as return values")?;
     let squared = square(5);
-    println!("5 的平方 = {}", squared);
+    writeln!(ctx, "5 的平方 = {}", squared)?;
 
-    // 6. 返回多个值（使用元组）
+    ctx.section(6, "返回多个值（使用元组）", "Returning several values (with a tuple)")?;
     let (sum, diff) = add_and_subtract(10, 4);
-    println!("10 + 4 = {}, 10 - 4 = {}", sum, diff);
+    writeln!(ctx, "10 + 4 = {}, 10 - 4 = {}", sum, diff)?;
 
-    // 7. 函数指针
+    ctx.section(7, "函数指针", "Function pointers")?;
     let func_ptr: fn(i32, i32) -> i32 = add;
-    println!("通过函数指针调用: {}", func_ptr(3, 7));
+    writeln!(ctx, "通过函数指针调用: {}", func_ptr(3, 7))?;
 
-    // 8. 高阶函数
+    ctx.section(8, "高阶函数", "Higher-order functions")?;
     let numbers = vec![1, 2, 3, 4, 5];
     let doubled: Vec<i32> = numbers.iter().map(|x| x * 2).collect();
-    println!("加倍后的数组: {:?}", doubled);
+    writeln!(ctx, "加倍后的数组: {:?}", doubled)?;
+
+    Ok(())
 }
 
-// 无参数无返回值的函数
-fn print_hello() {
-    println!("Hello, Rust!");
+// 无参数无返回值的函数（ctx 只是输出的去向，不算示例中的参数）
+fn print_hello(ctx: &mut Ctx) -> io::Result<()> {
+    writeln!(ctx, "Hello, Rust!")
 }
 
 // 带一个参数的函数
-fn print_number(x: i32) {
-    println!("数字是: {}", x);
+fn print_number(ctx: &mut Ctx, x: i32) -> io::Result<()> {
+    writeln!(ctx, "数字是: {}", x)
 }
 
 // 带两个参数的函数
-fn print_sum(a: i32, b: i32) {
-    println!("{} + {} = {}", a, b, a + b);
+fn print_sum(ctx: &mut Ctx, a: i32, b: i32) -> io::Result<()> {
+    writeln!(ctx, "{} + {} = {}", a, b, a + b)
 }
 
 // 有返回值的函数（显式 return）
@@ -63,7 +69,7 @@ fn square(x: i32) -> i32 {
 }
 
 // 返回多个值（使用元组）
-fn add_and_subtract(a: i32, b: i32) -> (i32, i32) {
+pub fn add_and_subtract(a: i32, b: i32) -> (i32, i32) {
     (a + b, a - b)
 }
 
@@ -77,7 +83,7 @@ fn absolute_value(x: i32) -> i32 {
 }
 
 // 递归函数
-fn factorial(n: u64) -> u64 {
+pub fn factorial(n: u64) -> u64 {
     if n == 0 {
         1
     } else {
@@ -86,12 +92,12 @@ fn factorial(n: u64) -> u64 {
 }
 
 // 闭包（匿名函数）
-fn closure_example() {
+fn closure_example(ctx: &mut Ctx) -> io::Result<()> {
     let add_one = |x: i32| -> i32 { x + 1 };
-    println!("闭包: 5 + 1 = {}", add_one(5));
+    writeln!(ctx, "闭包: 5 + 1 = {}", add_one(5))?;
 
     // 捕获环境变量
     let y = 10;
     let add_y = |x| x + y;
-    println!("捕获环境变量: 5 + {} = {}", y, add_y(5));
+    writeln!(ctx, "捕获环境变量: 5 + {} = {}", y, add_y(5))
 }
\ No newline at end of file
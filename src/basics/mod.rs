@@ -6,14 +6,5 @@
 pub mod variables;
 pub mod data_types;
 pub mod functions;
-
-pub fn run_all() {
-    println!("\n--- 变量示例 ---");
-    variables::main();
-
-    println!("\n--- 数据类型示例 ---");
-    data_types::main();
-
-    println!("\n--- 函数示例 ---");
-    functions::main();
-}
\ No newline at end of file
+pub mod exercises;
+pub mod quiz;
@@ -0,0 +1,86 @@
+//! 基础语法小测验
+//!
+//! 预测输出题调用 functions 课中的函数，答案以实际运行结果为准。
+
+use std::io::{self, Write};
+
+use crate::context::Ctx;
+use crate::quiz::{Answer, Question};
+
+use super::functions::{add_and_subtract, factorial};
+
+pub static QUESTIONS: &[Question] = &[
+    Question {
+        topic: "basics",
+        lesson: "functions",
+        prompt: "下面的代码输出什么？",
+        prompt_en: "What does this code print?",
+        code: "println!(\"{}\", factorial(5));",
+        options: &["25", "120", "5", "15"],
+        options_en: &["25", "120", "5", "15"],
+        answer: Answer::Output(|ctx| writeln!(ctx, "{}", factorial(5))),
+        explanation: "factorial 递归计算 5 × 4 × 3 × 2 × 1，n 为 0 时返回 1 结束递归。",
+        explanation_en: "factorial recursively computes 5 × 4 × 3 × 2 × 1 and returns 1 when n is 0, which ends the recursion.",
+    },
+    Question {
+        topic: "basics",
+        lesson: "functions",
+        prompt: "下面的代码输出什么？",
+        prompt_en: "What does this code print?",
+        code: "let (sum, difference) = add_and_subtract(10, 4);\nprintln!(\"{} {}\", sum, difference);",
+        options: &["14 6", "6 14", "(14, 6)", "编译错误：函数只能返回一个值"],
+        options_en: &["14 6", "6 14", "(14, 6)", "Compile error: a function can return only one value"],
+        answer: Answer::Output(tuple_return),
+        explanation: "函数可以返回元组 (a + b, a - b)，调用方用 let (sum, difference) 解构。",
+        explanation_en: "A function can return the tuple (a + b, a - b), and the caller destructures it with let (sum, difference).",
+    },
+    Question {
+        topic: "basics",
+        lesson: "functions",
+        prompt: "把 square 的函数体 `x * x` 改成 `x * x;`（加上分号）会怎样？",
+        prompt_en: "What happens if the body of square changes from `x * x` to `x * x;` (with a semicolon)?",
+        code: "fn square(x: i32) -> i32 {\n    x * x;\n}",
+        options: &[
+            "照常返回 x * x",
+            "返回 0",
+            "编译错误：函数体的值是 ()，与返回类型 i32 不匹配",
+            "运行时 panic",
+        ],
+        options_en: &[
+            "Still returns x * x",
+            "Returns 0",
+            "Compile error: the body's value is (), which doesn't match the return type i32",
+            "Panics at run time",
+        ],
+        answer: Answer::Choice(2),
+        explanation: "加上分号后 x * x 变成语句，函数体最后没有表达式，值为 ()，编译器报 E0308 类型不匹配。",
+        explanation_en: "With the semicolon x * x becomes a statement, so the body ends without an expression and its value is (); the compiler reports E0308 mismatched types.",
+    },
+    Question {
+        topic: "basics",
+        lesson: "variables",
+        prompt: "下面的代码能否编译？",
+        prompt_en: "Does this code compile?",
+        code: "let x = 5;\nx = 6;",
+        options: &[
+            "可以，x 变为 6",
+            "不可以：变量默认不可变，需要写 let mut x",
+            "不可以：需要写 const x",
+            "可以，但会得到警告",
+        ],
+        options_en: &[
+            "Yes, x becomes 6",
+            "No: variables are immutable by default, write let mut x",
+            "No: it has to be const x",
+            "Yes, with a warning",
+        ],
+        answer: Answer::Choice(1),
+        explanation: "Rust 的变量默认不可变，给不可变变量第二次赋值是编译错误 E0384；想修改就声明为 let mut。",
+        explanation_en: "Rust variables are immutable by default, and assigning to an immutable variable twice is error E0384; declare it with let mut to change it.",
+    },
+];
+
+fn tuple_return(ctx: &mut Ctx) -> io::Result<()> {
+    let (sum, difference) = add_and_subtract(10, 4);
+    writeln!(ctx, "{} {}", sum, difference)
+}
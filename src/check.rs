@@ -0,0 +1,221 @@
+//! 练习检查
+//!
+//! 每个主题目录下的 exercises.rs 中有一组待完成的函数（函数体是 `todo!()`），
+//! 以及检查它们的用例。`rust_study check <exercise>` 逐个运行用例，
+//! 报告每一项是否通过；没通过的用例附带提示。
+//!
+//! 用例在 `catch_unwind` 中运行：还没写完的 `todo!()` 会显示为“未完成”，
+//! 其他 panic 和断言失败一样记为失败，不会中断后面的用例。
+
+use std::io::{self, Write};
+use std::panic::{self, AssertUnwindSafe};
+
+use crate::context::Ctx;
+use crate::i18n::Locale;
+use crate::panics;
+use crate::progress::Kind;
+use crate::registry::{self, ResolveError};
+use crate::runner;
+use crate::{advanced, async_basics, basics, collections, concurrency, control_flow, iterators, macros, modules_errors, ownership, smart_pointers, structs_enums};
+
+// 一道练习：一个待完成的函数及其用例
+pub struct Exercise {
+    pub id: &'static str,
+    pub topic: &'static str,
+    // 讲解相关内容的课程 id
+    pub lesson: &'static str,
+    pub title: &'static str,
+    pub title_en: &'static str,
+    pub cases: &'static [Case],
+}
+
+impl Exercise {
+    // 练习的完整路径，例如 "ownership/first_word"
+    pub fn path(&self) -> String {
+        format!("{}/{}", self.topic, self.id)
+    }
+
+    pub fn title_in(&self, locale: Locale) -> &'static str {
+        match locale {
+            Locale::ZhCn => self.title,
+            Locale::EnUs => self.title_en,
+        }
+    }
+}
+
+// 一个用例：调用练习中的函数，把结果与期望值比较
+pub struct Case {
+    pub name: &'static str,
+    pub name_en: &'static str,
+    // 没通过时显示给学习者
+    pub hint: &'static str,
+    pub hint_en: &'static str,
+    pub run: fn() -> Result<(), Mismatch>,
+}
+
+impl Case {
+    pub fn name_in(&self, locale: Locale) -> &'static str {
+        match locale {
+            Locale::ZhCn => self.name,
+            Locale::EnUs => self.name_en,
+        }
+    }
+
+    pub fn hint_in(&self, locale: Locale) -> &'static str {
+        match locale {
+            Locale::ZhCn => self.hint,
+            Locale::EnUs => self.hint_en,
+        }
+    }
+}
+
+// 结果与期望不符：两边都已经用 {:?} 格式化，显示时再按界面语言组成句子
+#[derive(Debug, Clone, PartialEq)]
+pub struct Mismatch {
+    pub expected: String,
+    pub actual: String,
+}
+
+impl Mismatch {
+    pub fn message(&self, locale: Locale) -> String {
+        locale.message("check.mismatch", &[&self.expected, &self.actual])
+    }
+}
+
+// 一个用例的结果
+#[derive(Debug, Clone, PartialEq)]
+pub enum Outcome {
+    Passed,
+    // 结果与期望不符，携带两边的值
+    Failed(Mismatch),
+    // 函数体还是 todo!()
+    Todo,
+    // 其他 panic，携带 panic 信息（取得到的话）
+    Panicked(Option<String>),
+}
+
+// 用例中比较结果的辅助函数
+pub fn expect_eq<T: PartialEq + std::fmt::Debug>(actual: T, expected: T) -> Result<(), Mismatch> {
+    if actual == expected {
+        Ok(())
+    } else {
+        Err(Mismatch { expected: format!("{:?}", expected), actual: format!("{:?}", actual) })
+    }
+}
+
+// 所有练习，按主题顺序排列
+pub fn exercises() -> impl Iterator<Item = &'static Exercise> {
+    [
+        basics::exercises::EXERCISES,
+        control_flow::exercises::EXERCISES,
+        ownership::exercises::EXERCISES,
+        structs_enums::exercises::EXERCISES,
+        collections::exercises::EXERCISES,
+        modules_errors::exercises::EXERCISES,
+        advanced::exercises::EXERCISES,
+        concurrency::exercises::EXERCISES,
+        smart_pointers::exercises::EXERCISES,
+        iterators::exercises::EXERCISES,
+        async_basics::exercises::EXERCISES,
+        macros::exercises::EXERCISES,
+    ]
+    .into_iter()
+    .flatten()
+}
+
+// 按名称查找练习：完整路径、练习 id，或唯一的前缀、子串
+pub fn resolve(query: &str) -> Result<&'static Exercise, ResolveError> {
+    let query = query.trim().trim_matches('/').to_lowercase();
+
+    let matchers: [fn(&Exercise, &str) -> bool; 3] = [
+        |exercise, query| exercise.path() == query || exercise.id == query,
+        |exercise, query| exercise.path().starts_with(query) || exercise.id.starts_with(query),
+        |exercise, query| exercise.path().contains(query),
+    ];
+
+    if !query.is_empty() {
+        for matches in matchers {
+            let found: Vec<&Exercise> = exercises().filter(|exercise| matches(exercise, &query)).collect();
+            match found.as_slice() {
+                [] => continue,
+                [exercise] => return Ok(exercise),
+                _ => {
+                    return Err(ResolveError::Ambiguous {
+                        query,
+                        candidates: found.iter().map(|exercise| exercise.path()).collect(),
+                    });
+                }
+            }
+        }
+    }
+
+    let mut scored: Vec<(usize, String)> = exercises()
+        .map(|exercise| (registry::edit_distance(&query, exercise.id), exercise.path()))
+        .filter(|(distance, _)| *distance <= 2)
+        .collect();
+    scored.sort();
+    Err(ResolveError::NotFound {
+        query,
+        suggestions: scored.into_iter().map(|(_, path)| path).collect(),
+    })
+}
+
+// 运行一个用例
+pub fn run_case(case: &Case) -> Outcome {
+    match panic::catch_unwind(AssertUnwindSafe(case.run)) {
+        Ok(Ok(())) => Outcome::Passed,
+        Ok(Err(message)) => Outcome::Failed(message),
+        Err(payload) => match runner::panic_message(payload.as_ref()) {
+            Some(message) if message.starts_with("not yet implemented") => Outcome::Todo,
+            message => Outcome::Panicked(message),
+        },
+    }
+}
+
+// 运行一道练习的全部用例并逐项报告；全部通过时返回 true
+pub fn check(ctx: &mut Ctx, exercise: &Exercise) -> io::Result<bool> {
+    let heading = ctx.message("check.heading", &[&exercise.path(), &exercise.title_in(ctx.locale())]);
+    writeln!(ctx, "\n--- {} ---", heading)?;
+
+    // 用例 panic 是预料之中的，不让默认钩子把信息打到标准错误上
+    let outcomes: Vec<Outcome> = panics::quietly(|| exercise.cases.iter().map(run_case).collect());
+
+    for (case, outcome) in exercise.cases.iter().zip(&outcomes) {
+        let name = case.name_in(ctx.locale());
+        let line = match outcome {
+            Outcome::Passed => ctx.message("check.passed", &[&name]),
+            Outcome::Failed(mismatch) => ctx.message("check.failed", &[&name, &mismatch.message(ctx.locale())]),
+            Outcome::Panicked(message) => {
+                let message = message.as_deref().unwrap_or(ctx.text("runner.unknown_panic"));
+                ctx.message("check.panicked", &[&name, &message])
+            }
+            Outcome::Todo => ctx.message("check.todo", &[&name]),
+        };
+        writeln!(ctx, "{}", line)?;
+        if *outcome != Outcome::Passed {
+            writeln!(ctx, "{}", ctx.message("check.hint", &[&case.hint_in(ctx.locale())]))?;
+        }
+    }
+
+    let passed = outcomes.iter().filter(|outcome| **outcome == Outcome::Passed).count();
+    let totals = ctx.message("check.totals", &[&outcomes.len(), &passed, &(outcomes.len() - passed)]);
+    writeln!(ctx, "{}", totals)?;
+    if passed < outcomes.len() {
+        let lesson = format!("{}/{}", exercise.topic, exercise.lesson);
+        writeln!(ctx, "{}", ctx.message("check.see_lesson", &[&lesson]))?;
+    }
+
+    if passed == outcomes.len() {
+        ctx.record_progress(Kind::Exercise, &exercise.path(), None)?;
+    }
+    Ok(passed == outcomes.len())
+}
+
+// 列出所有练习
+pub fn list(ctx: &mut Ctx) -> io::Result<()> {
+    writeln!(ctx, "{}", ctx.text("check.list_title"))?;
+    for exercise in exercises() {
+        writeln!(ctx, "  {:<34}{}", exercise.path(), exercise.title_in(ctx.locale()))?;
+    }
+    Ok(())
+}
@@ -0,0 +1,347 @@
+//! 命令行参数解析
+//!
+//! 支持以下用法（不带参数时回退到交互式数字菜单）：
+//! - `rust_study run <topic> [<lesson>]`：运行一个主题或其中一课
+//! - `rust_study run <topic>/<lesson>`、`rust_study run <lesson>`：按路径或课程名运行一课
+//! - `rust_study list`：列出所有主题和课程
+//! - `rust_study all`：运行所有示例
+//! - `rust_study quiz [<topic>]`：小测验，可以只考一个主题或一课
+//! - `rust_study check [<exercise>]`：检查一道练习，不带参数时列出所有练习
+//! - `rust_study progress [reset|export]`：查看、清空或导出学习进度
+//! - `rust_study show <lesson> [--with-output]`：显示课程源码，可以把输出插在对应小节后面
+//! - `rust_study search <term>`：在所有课程的标题、小节、代码和讲解中搜索
+//! - `rust_study export [<dir>] [--html]`：把所有课程导出为静态 Markdown 或 HTML 网站
+//!
+//! 全局选项 `--output <file>` 把输出写入文件而不是终端，
+//! `--lang <zh-CN|en-US>` 选择界面语言，`--keep-workspace` 保留本次运行的临时工作目录，
+//! `--step` 让课程在每一节结束后暂停，
+//! `--format json` 让 run 和 all 输出 JSON 报告（见 report.rs）。帮助文字在 i18n 的消息目录中（cli.usage）。
+
+use std::fmt;
+use std::path::PathBuf;
+
+use rust_study::export::Markup;
+use rust_study::i18n::Locale;
+use rust_study::registry::ResolveError;
+use rust_study::report::Format;
+
+// 解析后的命令
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Menu,
+    Help,
+    List,
+    All,
+    // 查询字符串，交给 registry::resolve 解析，例如 "advanced/lifetimes"
+    Run { query: String },
+    // 查询字符串，交给 registry::resolve 解析；为 None 时考所有主题
+    Quiz { query: Option<String> },
+    // 查询字符串，交给 check::resolve 解析；为 None 时列出所有练习
+    Check { query: Option<String> },
+    Progress(ProgressAction),
+    // 查询字符串，交给 registry::resolve 解析；with_output 时运行课程并插入输出
+    Show { query: String, with_output: bool },
+    // 要搜索的词，多个参数用空格连接
+    Search { term: String },
+    // 输出目录，默认是当前目录下的 site
+    Export { dir: PathBuf, markup: Markup },
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ProgressAction {
+    Show,
+    Reset,
+    Export,
+}
+
+// 解析后的全部参数
+#[derive(Debug, PartialEq)]
+pub struct Args {
+    pub command: Command,
+    pub output: Option<PathBuf>,
+    pub locale: Option<Locale>,
+    pub keep_workspace: bool,
+    pub step: bool,
+    pub format: Format,
+}
+
+// 命令行错误
+#[derive(Debug, PartialEq)]
+pub enum CliError {
+    UnknownCommand(String),
+    MissingTopic,
+    MissingLesson,
+    MissingTerm,
+    UnexpectedArgument(String),
+    MissingValue(&'static str),
+    UnknownLocale(String),
+    UnknownFormat(String),
+    // 只有运行课程的命令能输出报告
+    FormatNotSupported,
+    Resolve(ResolveError),
+    // 按名称查找练习失败
+    Exercise(ResolveError),
+}
+
+impl CliError {
+    pub fn message(&self, locale: Locale) -> String {
+        match self {
+            CliError::UnknownCommand(cmd) => locale.message("cli.unknown_command", &[cmd]),
+            CliError::MissingTopic => locale.text("cli.missing_topic").to_string(),
+            CliError::MissingLesson => locale.text("cli.missing_lesson").to_string(),
+            CliError::MissingTerm => locale.text("cli.missing_term").to_string(),
+            CliError::UnexpectedArgument(arg) => locale.message("cli.unexpected_argument", &[arg]),
+            CliError::MissingValue(option) => locale.message("cli.missing_value", &[option]),
+            CliError::UnknownLocale(tag) => locale.message("cli.unknown_locale", &[tag]),
+            CliError::UnknownFormat(name) => locale.message("cli.unknown_format", &[name]),
+            CliError::FormatNotSupported => locale.text("cli.format_not_supported").to_string(),
+            CliError::Resolve(e) => e.message(locale),
+            CliError::Exercise(e) => e.message_with(locale, "check.not_found"),
+        }
+    }
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.message(Locale::default()))
+    }
+}
+
+impl std::error::Error for CliError {}
+
+impl From<ResolveError> for CliError {
+    fn from(e: ResolveError) -> Self {
+        CliError::Resolve(e)
+    }
+}
+
+impl CliError {
+    // 进程退出码：用法错误与查找失败分开，便于脚本区分
+    pub fn exit_code(&self) -> u8 {
+        match self {
+            CliError::Resolve(_) | CliError::Exercise(_) => 3,
+            _ => 2,
+        }
+    }
+}
+
+pub fn parse<I>(args: I) -> Result<Args, CliError>
+where
+    I: IntoIterator<Item = String>,
+{
+    let mut output = None;
+    let mut locale = None;
+    let mut keep_workspace = false;
+    let mut step = false;
+    let mut format = Format::default();
+    let mut positional = Vec::new();
+
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-o" | "--output" => {
+                let path = args.next().ok_or(CliError::MissingValue("--output"))?;
+                output = Some(PathBuf::from(path));
+            }
+            "--lang" => {
+                let tag = args.next().ok_or(CliError::MissingValue("--lang"))?;
+                locale = Some(Locale::parse(&tag).ok_or(CliError::UnknownLocale(tag))?);
+            }
+            "--keep-workspace" => keep_workspace = true,
+            "--step" => step = true,
+            "--format" => {
+                let name = args.next().ok_or(CliError::MissingValue("--format"))?;
+                format = Format::parse(&name).ok_or(CliError::UnknownFormat(name))?;
+            }
+            _ => positional.push(arg),
+        }
+    }
+
+    let command = parse_command(positional)?;
+    if format == Format::Json && !matches!(command, Command::Run { .. } | Command::All) {
+        return Err(CliError::FormatNotSupported);
+    }
+    Ok(Args {
+        command,
+        output,
+        locale,
+        keep_workspace,
+        step,
+        format,
+    })
+}
+
+fn parse_command(args: Vec<String>) -> Result<Command, CliError> {
+    let mut args = args.into_iter();
+
+    let command = match args.next() {
+        None => return Ok(Command::Menu),
+        Some(command) => command,
+    };
+
+    let command = match command.as_str() {
+        "help" | "-h" | "--help" => Command::Help,
+        "list" => Command::List,
+        "all" => Command::All,
+        "run" => {
+            let topic = args.next().ok_or(CliError::MissingTopic)?;
+            let query = match args.next() {
+                Some(lesson) => format!("{}/{}", topic, lesson),
+                None => topic,
+            };
+            Command::Run { query }
+        }
+        "quiz" => Command::Quiz { query: args.next() },
+        "check" => Command::Check { query: args.next() },
+        "progress" => match args.next().as_deref() {
+            None => Command::Progress(ProgressAction::Show),
+            Some("reset") => Command::Progress(ProgressAction::Reset),
+            Some("export") => Command::Progress(ProgressAction::Export),
+            Some(action) => return Err(CliError::UnexpectedArgument(action.to_string())),
+        },
+        "show" => {
+            let mut query = None;
+            let mut with_output = false;
+            for arg in args.by_ref() {
+                match arg.as_str() {
+                    "--with-output" => with_output = true,
+                    _ if query.is_none() => query = Some(arg),
+                    _ => return Err(CliError::UnexpectedArgument(arg)),
+                }
+            }
+            let query = query.ok_or(CliError::MissingLesson)?;
+            Command::Show { query, with_output }
+        }
+        "search" => {
+            let words: Vec<String> = args.by_ref().collect();
+            if words.is_empty() {
+                return Err(CliError::MissingTerm);
+            }
+            Command::Search { term: words.join(" ") }
+        }
+        "export" => {
+            let mut dir = None;
+            let mut markup = Markup::Markdown;
+            for arg in args.by_ref() {
+                match arg.as_str() {
+                    "--html" => markup = Markup::Html,
+                    "--markdown" => markup = Markup::Markdown,
+                    _ if dir.is_none() => dir = Some(PathBuf::from(arg)),
+                    _ => return Err(CliError::UnexpectedArgument(arg)),
+                }
+            }
+            let dir = dir.unwrap_or_else(|| PathBuf::from("site"));
+            Command::Export { dir, markup }
+        }
+        _ => return Err(CliError::UnknownCommand(command)),
+    };
+
+    match args.next() {
+        Some(extra) => Err(CliError::UnexpectedArgument(extra)),
+        None => Ok(command),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_study::check;
+    use rust_study::registry::{self, Target};
+
+    fn parse_args(args: &[&str]) -> Result<Args, CliError> {
+        parse(args.iter().map(|arg| arg.to_string()))
+    }
+
+    fn command(args: &[&str]) -> Command {
+        parse_args(args).expect("参数应当解析成功").command
+    }
+
+    fn run(query: &str) -> Command {
+        Command::Run { query: String::from(query) }
+    }
+
+    #[test]
+    fn no_arguments_fall_back_to_the_menu() {
+        let args = parse_args(&[]).unwrap();
+        assert_eq!(args.command, Command::Menu);
+        assert_eq!((args.output, args.locale, args.keep_workspace, args.step), (None, None, false, false));
+        assert_eq!(args.format, Format::Text);
+        // 只有全局选项时同样进入菜单
+        assert_eq!(command(&["--lang", "en-US"]), Command::Menu);
+    }
+
+    #[test]
+    fn run_takes_a_topic_and_an_optional_lesson() {
+        assert_eq!(command(&["run", "basics"]), run("basics"));
+        assert_eq!(command(&["run", "basics", "functions"]), run("basics/functions"));
+        assert_eq!(command(&["run", "basics/functions"]), run("basics/functions"));
+        assert_eq!(parse_args(&["run"]), Err(CliError::MissingTopic));
+    }
+
+    #[test]
+    fn list_and_all_take_no_arguments() {
+        assert_eq!(command(&["list"]), Command::List);
+        assert_eq!(command(&["all"]), Command::All);
+        assert_eq!(parse_args(&["list", "basics"]), Err(CliError::UnexpectedArgument(String::from("basics"))));
+        assert_eq!(parse_args(&["all", "x"]), Err(CliError::UnexpectedArgument(String::from("x"))));
+        assert_eq!(
+            parse_args(&["run", "basics", "functions", "extra"]),
+            Err(CliError::UnexpectedArgument(String::from("extra")))
+        );
+    }
+
+    #[test]
+    fn options_can_appear_anywhere() {
+        let args = parse_args(&["--keep-workspace", "run", "basics", "-o", "out.txt", "--lang", "en-US", "--step"]).unwrap();
+        assert_eq!(args.command, run("basics"));
+        assert_eq!(args.output, Some(PathBuf::from("out.txt")));
+        assert_eq!(args.locale, Some(Locale::EnUs));
+        assert!(args.keep_workspace && args.step);
+        assert_eq!(parse_args(&["all", "--format", "json"]).unwrap().format, Format::Json);
+    }
+
+    #[test]
+    fn usage_errors() {
+        assert_eq!(parse_args(&["frobnicate"]), Err(CliError::UnknownCommand(String::from("frobnicate"))));
+        assert_eq!(parse_args(&["run", "basics", "--output"]), Err(CliError::MissingValue("--output")));
+        assert_eq!(parse_args(&["--lang"]), Err(CliError::MissingValue("--lang")));
+        assert_eq!(parse_args(&["--format"]), Err(CliError::MissingValue("--format")));
+        assert_eq!(parse_args(&["--lang", "fr"]), Err(CliError::UnknownLocale(String::from("fr"))));
+        assert_eq!(parse_args(&["all", "--format", "xml"]), Err(CliError::UnknownFormat(String::from("xml"))));
+        assert_eq!(parse_args(&["list", "--format", "json"]), Err(CliError::FormatNotSupported));
+        assert_eq!(parse_args(&["show"]), Err(CliError::MissingLesson));
+        assert_eq!(parse_args(&["search"]), Err(CliError::MissingTerm));
+        assert_eq!(parse_args(&["progress", "wipe"]), Err(CliError::UnexpectedArgument(String::from("wipe"))));
+    }
+
+    // 未知的主题和课程在 registry::resolve 中才发现，main 把错误转换成 CliError
+    #[test]
+    fn unknown_topic_or_lesson_is_a_resolve_error() {
+        let query = match command(&["run", "no_such_topic"]) {
+            Command::Run { query } => query,
+            other => panic!("不是 run 命令: {:?}", other),
+        };
+        let error = CliError::from(registry::resolve(&query).err().expect("不应找到"));
+        assert!(matches!(error, CliError::Resolve(_)));
+
+        let query = match command(&["run", "basics", "no_such_lesson"]) {
+            Command::Run { query } => query,
+            other => panic!("不是 run 命令: {:?}", other),
+        };
+        assert!(registry::resolve(&query).is_err());
+        assert!(matches!(registry::resolve("basics"), Ok(Target::Topic(_))));
+    }
+
+    // 脚本据此区分：3 表示找不到要运行的东西，2 表示命令行写错了
+    #[test]
+    fn exit_codes_separate_resolve_and_usage_errors() {
+        let resolve = CliError::from(registry::resolve("no_such_topic").err().expect("不应找到"));
+        assert_eq!(resolve.exit_code(), 3);
+        let exercise = CliError::Exercise(check::resolve("no_such_exercise").err().expect("不应找到"));
+        assert_eq!(exercise.exit_code(), 3);
+
+        for args in [&["frobnicate"][..], &["run"], &["list", "x"], &["--lang"], &["--lang", "fr"], &["list", "--format", "json"]] {
+            assert_eq!(parse_args(args).unwrap_err().exit_code(), 2, "{:?}", args);
+        }
+    }
+}
@@ -0,0 +1,108 @@
+//! 集合练习
+//!
+//! 把下面函数中的 `todo!()` 换成实现，然后运行 `rust_study check <练习>` 检查，
+//! 例如 `rust_study check collections/median`。
+
+use std::collections::HashMap;
+
+use crate::check::{expect_eq, Case, Exercise};
+
+// 练习 1：中位数。数量为偶数时取中间两个数的平均值，空切片返回 None（参考 vectors 课）
+pub fn median(numbers: &[i32]) -> Option<f64> {
+    todo!()
+}
+
+// 练习 2：统计每个单词出现的次数，单词以空白分隔（参考 hashmaps 课中的 entry）
+pub fn word_count(text: &str) -> HashMap<String, usize> {
+    todo!()
+}
+
+// 练习 3：把单词转换为 Pig Latin：辅音开头时把首字母移到末尾并加 "ay"（"first" → "irst-fay"），
+// 元音开头时在末尾加 "-hay"（"apple" → "apple-hay"）（参考 strings 课）
+pub fn pig_latin(word: &str) -> String {
+    todo!()
+}
+
+pub static EXERCISES: &[Exercise] = &[
+    Exercise {
+        id: "median",
+        topic: "collections",
+        lesson: "vectors",
+        title: "中位数",
+        title_en: "Median",
+        cases: &[
+            Case {
+                name: "奇数个元素",
+                name_en: "Odd number of elements",
+                hint: "先 let mut sorted = numbers.to_vec(); sorted.sort(); 再取 sorted[len / 2]",
+                hint_en: "let mut sorted = numbers.to_vec(); sorted.sort(); then take sorted[len / 2]",
+                run: || expect_eq(median(&[5, 1, 3]), Some(3.0)),
+            },
+            Case {
+                name: "偶数个元素",
+                name_en: "Even number of elements",
+                hint: "取 sorted[len / 2 - 1] 和 sorted[len / 2] 的平均值，用 as f64 转换后再相加",
+                hint_en: "Average sorted[len / 2 - 1] and sorted[len / 2]; convert with as f64 before adding",
+                run: || expect_eq(median(&[4, 1, 3, 2]), Some(2.5)),
+            },
+            Case {
+                name: "空切片",
+                name_en: "Empty slice",
+                hint: "numbers.is_empty() 时返回 None，否则 len / 2 - 1 会溢出",
+                hint_en: "Return None when numbers.is_empty(), otherwise len / 2 - 1 overflows",
+                run: || expect_eq(median(&[]), None),
+            },
+        ],
+    },
+    Exercise {
+        id: "word_count",
+        topic: "collections",
+        lesson: "hashmaps",
+        title: "单词计数",
+        title_en: "Word count",
+        cases: &[
+            Case {
+                name: "重复的单词",
+                name_en: "Repeated words",
+                hint: "for word in text.split_whitespace()，再用 *map.entry(word.to_string()).or_insert(0) += 1",
+                hint_en: "for word in text.split_whitespace(), then *map.entry(word.to_string()).or_insert(0) += 1",
+                run: || {
+                    let counts = word_count("hello world wonderful world");
+                    expect_eq(counts.get("world"), Some(&2))?;
+                    expect_eq(counts.get("hello"), Some(&1))?;
+                    expect_eq(counts.len(), 3)
+                },
+            },
+            Case {
+                name: "空文本",
+                name_en: "Empty text",
+                hint: "没有单词时返回空的 HashMap::new()",
+                hint_en: "With no words, return an empty HashMap::new()",
+                run: || expect_eq(word_count("   ").len(), 0),
+            },
+        ],
+    },
+    Exercise {
+        id: "pig_latin",
+        topic: "collections",
+        lesson: "strings",
+        title: "Pig Latin",
+        title_en: "Pig Latin",
+        cases: &[
+            Case {
+                name: "辅音开头",
+                name_en: "Starts with a consonant",
+                hint: "用 word.chars() 取得首字母，&word[first.len_utf8()..] 是剩下的部分",
+                hint_en: "Get the first letter from word.chars(); &word[first.len_utf8()..] is the rest",
+                run: || expect_eq(pig_latin("first"), String::from("irst-fay")),
+            },
+            Case {
+                name: "元音开头",
+                name_en: "Starts with a vowel",
+                hint: "用 matches!(first, 'a' | 'e' | 'i' | 'o' | 'u') 判断元音，再用 format! 拼接",
+                hint_en: "Check for a vowel with matches!(first, 'a' | 'e' | 'i' | 'o' | 'u'), then join with format!",
+                run: || expect_eq(pig_latin("apple"), String::from("apple-hay")),
+            },
+        ],
+    },
+];
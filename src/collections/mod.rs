@@ -6,14 +6,5 @@
 pub mod vectors;
 pub mod strings;
 pub mod hashmaps;
-
-pub fn run_all() {
-    println!("\n--- 向量示例 ---");
-    vectors::main();
-
-    println!("\n--- 字符串示例 ---");
-    strings::main();
-
-    println!("\n--- 哈希映射示例 ---");
-    hashmaps::main();
-}
\ No newline at end of file
+pub mod exercises;
+pub mod quiz;
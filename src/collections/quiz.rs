@@ -0,0 +1,76 @@
+//! 集合小测验
+//!
+//! 预测输出题运行与 vectors、strings、hashmaps 课相同的代码，答案以实际运行结果为准。
+
+use std::collections::HashMap;
+use std::io::{self, Write};
+
+use crate::context::Ctx;
+use crate::quiz::{Answer, Question};
+
+pub static QUESTIONS: &[Question] = &[
+    Question {
+        topic: "collections",
+        lesson: "hashmaps",
+        prompt: "下面的代码输出什么？",
+        prompt_en: "What does this code print?",
+        code: "let mut scores = HashMap::new();\nscores.insert(\"Blue\", 10);\nscores.entry(\"Blue\").or_insert(50);\nscores.entry(\"Yellow\").or_insert(50);\nprintln!(\"{} {}\", scores[\"Blue\"], scores[\"Yellow\"]);",
+        options: &["50 50", "10 50", "10 0", "60 50"],
+        options_en: &["50 50", "10 50", "10 0", "60 50"],
+        answer: Answer::Output(entry_or_insert),
+        explanation: "entry().or_insert() 只在键不存在时插入，Blue 已经存在，保留原来的 10。",
+        explanation_en: "entry().or_insert() only inserts when the key is missing; Blue is already there, so it keeps the original 10.",
+    },
+    Question {
+        topic: "collections",
+        lesson: "vectors",
+        prompt: "下面的代码输出什么？",
+        prompt_en: "What does this code print?",
+        code: "let v = vec![1, 2, 3];\nprintln!(\"{:?}\", v.get(10));",
+        options: &["0", "None", "panic: index out of bounds", "Some(3)"],
+        options_en: &["0", "None", "panic: index out of bounds", "Some(3)"],
+        answer: Answer::Output(|ctx| writeln!(ctx, "{:?}", vec![1, 2, 3].get(10))),
+        explanation: "get 返回 Option，越界时是 None；用 &v[10] 索引才会 panic。",
+        explanation_en: "get returns an Option that is None when out of bounds; only indexing with &v[10] panics.",
+    },
+    Question {
+        topic: "collections",
+        lesson: "strings",
+        prompt: "下面的代码输出什么？",
+        prompt_en: "What does this code print?",
+        code: "println!(\"{}\", String::from(\"你好\").len());",
+        options: &["2", "4", "6"],
+        options_en: &["2", "4", "6"],
+        answer: Answer::Output(|ctx| writeln!(ctx, "{}", String::from("你好").len())),
+        explanation: "len 返回 UTF-8 编码的字节数，每个汉字占 3 个字节；字符数要用 chars().count()。",
+        explanation_en: "len returns the number of bytes in the UTF-8 encoding, and each Chinese character takes 3 bytes; count characters with chars().count().",
+    },
+    Question {
+        topic: "collections",
+        lesson: "strings",
+        prompt: "下面的代码能否编译？",
+        prompt_en: "Does this code compile?",
+        code: "let s = String::from(\"hello\");\nlet h = s[0];",
+        options: &[
+            "可以，h 是 'h'",
+            "可以，h 是字节 104",
+            "不可以：String 不支持用整数索引",
+        ],
+        options_en: &[
+            "Yes, h is 'h'",
+            "Yes, h is the byte 104",
+            "No: String can't be indexed with an integer",
+        ],
+        answer: Answer::Choice(2),
+        explanation: "一个字符可能占多个字节，按下标取“字符”没有明确含义，所以 String 没有实现 Index<usize>（E0277）；用切片 &s[0..1] 或 chars()。",
+        explanation_en: "A character may take several bytes, so taking the \"character\" at an index has no clear meaning and String doesn't implement Index<usize> (E0277); use a slice &s[0..1] or chars().",
+    },
+];
+
+fn entry_or_insert(ctx: &mut Ctx) -> io::Result<()> {
+    let mut scores = HashMap::new();
+    scores.insert("Blue", 10);
+    scores.entry("Blue").or_insert(50);
+    scores.entry("Yellow").or_insert(50);
+    writeln!(ctx, "{} {}", scores["Blue"], scores["Yellow"])
+}
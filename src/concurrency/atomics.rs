@@ -0,0 +1,145 @@
+//! 原子类型（Atomics）
+//!
+//! `std::sync::atomic` 中的 `AtomicUsize`、`AtomicBool` 等类型可以在线程之间共享，
+//! 不需要加锁就能安全地读写，适合计数器、标志位这类简单的状态。
+//!
+//! 每个原子操作都要指定内存顺序（`Ordering`）：
+//! - `Relaxed` 只保证这个操作本身是原子的，适合独立的计数器
+//! - `Release` 写入和 `Acquire` 读取配对使用，写入之前的修改对读到这个值的线程可见
+//! - `SeqCst` 在此基础上还保证所有线程看到同一个全局顺序，最保守也最容易理解
+
+use std::io::{self, Write};
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, OnceLock};
+use std::thread;
+
+use crate::context::Ctx;
+
+pub fn main(ctx: &mut Ctx) -> io::Result<()> {
+    writeln!(ctx, "=== {} ===", ctx.text("lesson.atomics"))?;
+
+    ctx.section(1, "原子计数器", "Atomic counters")?;
+    let counter = AtomicUsize::new(0);
+    // 作用域线程可以直接借用 counter，不需要 Arc
+    thread::scope(|s| {
+        for _ in 0..4 {
+            s.spawn(|| {
+                for _ in 0..1000 {
+                    counter.fetch_add(1, Ordering::Relaxed);
+                }
+            });
+        }
+    });
+    writeln!(ctx, "4 个线程各加 1000 次: {}", counter.load(Ordering::Relaxed))?;
+
+    ctx.section(2, "load、store 和 swap", "load, store and swap")?;
+    let value = AtomicUsize::new(10);
+    value.store(20, Ordering::SeqCst);
+    let old = value.swap(30, Ordering::SeqCst);
+    writeln!(ctx, "swap 返回旧值 {}，现在是 {}", old, value.load(Ordering::SeqCst))?;
+    // fetch_ 系列操作返回修改之前的值
+    writeln!(ctx, "fetch_sub(5) 返回 {}", value.fetch_sub(5, Ordering::SeqCst))?;
+    writeln!(ctx, "fetch_max(100) 返回 {}", value.fetch_max(100, Ordering::SeqCst))?;
+    writeln!(ctx, "最后的值: {}", value.into_inner())?;
+
+    ctx.section(3, "compare_exchange：只有一个线程能成功", "compare_exchange: only one thread succeeds")?;
+    let owner = AtomicUsize::new(0);
+    let winners = AtomicUsize::new(0);
+    thread::scope(|s| {
+        for id in 1..=8 {
+            let (owner, winners) = (&owner, &winners);
+            s.spawn(move || {
+                // 只有当前值还是 0 时才写入自己的编号
+                if owner.compare_exchange(0, id, Ordering::AcqRel, Ordering::Acquire).is_ok() {
+                    winners.fetch_add(1, Ordering::Relaxed);
+                }
+            });
+        }
+    });
+    let owner = owner.into_inner();
+    writeln!(ctx, "成功的线程数: {}", winners.into_inner())?;
+    writeln!(ctx, "拥有者的编号在 1 到 8 之间: {}", (1..=8).contains(&owner))?;
+
+    ctx.section(4, "AtomicBool 作为停止标志", "AtomicBool as a stop flag")?;
+    let stop = Arc::new(AtomicBool::new(false));
+    let worker = {
+        let stop = Arc::clone(&stop);
+        thread::spawn(move || {
+            let mut rounds = 0_u64;
+            while !stop.load(Ordering::Relaxed) {
+                rounds += 1;
+                thread::yield_now();
+            }
+            rounds
+        })
+    };
+    stop.store(true, Ordering::Relaxed);
+    // 工作了多少轮取决于调度，不同的运行结果不同，所以这里不输出
+    let _rounds = worker.join().unwrap();
+    writeln!(ctx, "工作线程看到停止标志，已经退出")?;
+
+    ctx.section(5, "Release 和 Acquire：安全地发布数据", "Release and Acquire: publishing data safely")?;
+    let data = AtomicU64::new(0);
+    let ready = AtomicBool::new(false);
+    thread::scope(|s| {
+        s.spawn(|| {
+            data.store(42, Ordering::Relaxed);
+            // Release：上面的写入一定在 ready 变成 true 之前完成
+            ready.store(true, Ordering::Release);
+        });
+        let reader = s.spawn(|| {
+            // Acquire：读到 true 之后，一定能看到 Release 之前的写入
+            while !ready.load(Ordering::Acquire) {
+                thread::yield_now();
+            }
+            data.load(Ordering::Relaxed)
+        });
+        writeln!(ctx, "读者看到的数据: {}", reader.join().unwrap())
+    })?;
+
+    ctx.section(6, "生成唯一编号", "Generating unique ids")?;
+    let ids: Vec<usize> = thread::scope(|s| {
+        let handles: Vec<_> = (0..4)
+            .map(|_| s.spawn(|| (0..5).map(|_| next_id()).collect::<Vec<_>>()))
+            .collect();
+        handles.into_iter().flat_map(|handle| handle.join().unwrap()).collect()
+    });
+    let mut sorted = ids.clone();
+    sorted.sort();
+    sorted.dedup();
+    writeln!(ctx, "4 个线程共取得 {} 个编号，其中不同的有 {} 个", ids.len(), sorted.len())?;
+
+    ctx.section(7, "OnceLock：只初始化一次", "OnceLock: initialize exactly once")?;
+    static CONFIG: OnceLock<String> = OnceLock::new();
+    static INITS: AtomicUsize = AtomicUsize::new(0);
+    let values: Vec<&'static str> = thread::scope(|s| {
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                s.spawn(|| {
+                    CONFIG
+                        .get_or_init(|| {
+                            INITS.fetch_add(1, Ordering::SeqCst);
+                            String::from("只计算一次的配置")
+                        })
+                        .as_str()
+                })
+            })
+            .collect();
+        handles.into_iter().map(|handle| handle.join().unwrap()).collect()
+    });
+    writeln!(ctx, "4 个线程都拿到同一个值: {}", values.iter().all(|v| *v == values[0]))?;
+    writeln!(ctx, "初始化函数运行次数: {}", INITS.load(Ordering::SeqCst))?;
+
+    ctx.section(8, "原子类型和 Mutex 的选择", "Choosing between atomics and Mutex")?;
+    // 单个整数或布尔值用原子类型；需要同时修改多个字段、或者保持不变量时用 Mutex
+    writeln!(ctx, "计数器、标志位、编号 -> 原子类型")?;
+    writeln!(ctx, "向量、映射、多个相关字段 -> Mutex 或 RwLock")?;
+
+    Ok(())
+}
+
+// 全局递增的编号；fetch_add 保证每次调用得到不同的值
+fn next_id() -> usize {
+    static NEXT: AtomicUsize = AtomicUsize::new(1);
+    NEXT.fetch_add(1, Ordering::Relaxed)
+}
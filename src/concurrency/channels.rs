@@ -0,0 +1,162 @@
+//! 通道（Channels）
+//!
+//! `std::sync::mpsc` 提供多生产者、单消费者（multiple producer, single consumer）的通道：
+//! 发送端 `Sender` 可以克隆给多个线程，接收端 `Receiver` 只有一个。
+//! 通过通道发送值会转移所有权，线程之间不需要共享内存。
+//!
+//! 来自同一个发送端的消息按发送顺序到达，来自不同发送端的消息之间没有固定顺序，
+//! 本课在需要时先收集再排序，保证输出稳定。
+
+use std::io::{self, Write};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender, TryRecvError, TrySendError};
+use std::thread;
+use std::time::Duration;
+
+use crate::context::Ctx;
+
+pub fn main(ctx: &mut Ctx) -> io::Result<()> {
+    writeln!(ctx, "=== {} ===", ctx.text("lesson.channels"))?;
+
+    ctx.section(1, "创建通道，发送和接收一个值", "Creating a channel, sending and receiving a value")?;
+    let (tx, rx) = mpsc::channel();
+    let handle = thread::spawn(move || {
+        let message = String::from("你好");
+        tx.send(message).unwrap();
+        // println!("{}", message); // 错误：message 的所有权已经随 send 转移
+    });
+    // recv 阻塞等待，直到收到一个值
+    writeln!(ctx, "收到: {}", rx.recv().unwrap())?;
+    handle.join().unwrap();
+
+    ctx.section(2, "把接收端当作迭代器", "Using the receiver as an iterator")?;
+    let (tx, rx) = mpsc::channel();
+    thread::spawn(move || {
+        for word in ["来自", "线程", "的", "消息"] {
+            tx.send(word).unwrap();
+            thread::sleep(Duration::from_millis(1));
+        }
+        // tx 在这里被丢弃，通道关闭，下面的 for 循环随之结束
+    });
+    for received in rx {
+        writeln!(ctx, "收到: {}", received)?;
+    }
+
+    ctx.section(3, "克隆发送端：多个生产者", "Cloning the sender: multiple producers")?;
+    let (tx, rx) = mpsc::channel();
+    for id in 1..=3 {
+        let tx = tx.clone();
+        thread::spawn(move || {
+            for n in 1..=2 {
+                tx.send((id, n)).unwrap();
+            }
+        });
+    }
+    // 丢弃最初的发送端，否则接收端会一直等下去
+    drop(tx);
+    let mut messages: Vec<(i32, i32)> = rx.iter().collect();
+    writeln!(ctx, "共收到 {} 条消息", messages.len())?;
+    // 不同生产者的消息交错到达，排序后再输出
+    messages.sort();
+    writeln!(ctx, "排序后: {:?}", messages)?;
+
+    ctx.section(4, "发送端全部丢弃后通道关闭", "The channel closes once every sender is dropped")?;
+    let (tx, rx) = mpsc::channel::<i32>();
+    tx.send(1).unwrap();
+    drop(tx);
+    writeln!(ctx, "第一次 recv: {:?}", rx.recv())?;
+    writeln!(ctx, "第二次 recv: {:?}", rx.recv())?;
+
+    // 反过来，接收端丢弃后 send 返回错误，并把值还回来
+    let (tx, rx) = mpsc::channel();
+    drop(rx);
+    if let Err(mpsc::SendError(value)) = tx.send(String::from("没人接收")) {
+        writeln!(ctx, "send 失败，取回值: {}", value)?;
+    }
+
+    ctx.section(5, "不阻塞的 try_recv 和带超时的 recv_timeout", "Non-blocking try_recv and recv_timeout")?;
+    let (tx, rx) = mpsc::channel::<i32>();
+    writeln!(ctx, "通道为空时 try_recv: {}", describe_try(rx.try_recv()))?;
+    tx.send(7).unwrap();
+    writeln!(ctx, "有值时 try_recv: {}", describe_try(rx.try_recv()))?;
+    match rx.recv_timeout(Duration::from_millis(10)) {
+        Ok(value) => writeln!(ctx, "recv_timeout 收到 {}", value)?,
+        Err(RecvTimeoutError::Timeout) => writeln!(ctx, "recv_timeout 超时")?,
+        Err(RecvTimeoutError::Disconnected) => writeln!(ctx, "recv_timeout: 通道已关闭")?,
+    }
+    drop(tx);
+    writeln!(ctx, "发送端丢弃后 try_recv: {}", describe_try(rx.try_recv()))?;
+
+    ctx.section(6, "有界的同步通道", "Bounded synchronous channels")?;
+    // 容量为 1：缓冲区满时 send 会阻塞，try_send 返回 Full
+    let (tx, rx) = mpsc::sync_channel(1);
+    tx.send(1).unwrap();
+    match tx.try_send(2) {
+        Ok(()) => writeln!(ctx, "try_send 成功")?,
+        Err(TrySendError::Full(value)) => writeln!(ctx, "缓冲区已满，{} 没有发送", value)?,
+        Err(TrySendError::Disconnected(value)) => writeln!(ctx, "通道已关闭，{} 没有发送", value)?,
+    }
+    writeln!(ctx, "取出: {}", rx.recv().unwrap())?;
+    writeln!(ctx, "再次 try_send: {:?}", tx.try_send(2).is_ok())?;
+    writeln!(ctx, "取出: {}", rx.recv().unwrap())?;
+
+    // 容量为 0 的通道是“会合点”：send 要等到对方 recv 才返回
+    let (tx, rx) = mpsc::sync_channel(0);
+    let handle = thread::spawn(move || {
+        tx.send("握手").unwrap();
+        "发送方在对方收到后才继续"
+    });
+    writeln!(ctx, "收到: {}", rx.recv().unwrap())?;
+    writeln!(ctx, "{}", handle.join().unwrap())?;
+
+    ctx.section(7, "流水线：用通道串起多个阶段", "Pipelines: chaining stages with channels")?;
+    let (numbers_tx, numbers_rx) = mpsc::channel();
+    let (squares_tx, squares_rx) = mpsc::channel();
+    let producer = thread::spawn(move || produce(numbers_tx, 1..=5));
+    let squarer = thread::spawn(move || square_all(numbers_rx, squares_tx));
+    // 每个阶段只有一个线程，消息顺序保持不变
+    let squares: Vec<u64> = squares_rx.iter().collect();
+    producer.join().unwrap();
+    squarer.join().unwrap();
+    writeln!(ctx, "1 到 5 的平方: {:?}", squares)?;
+
+    ctx.section(8, "用通道收集工作线程的结果", "Collecting worker results through a channel")?;
+    let results = squares_via_channel(vec![3, 1, 4, 1, 5]);
+    writeln!(ctx, "每个线程算一个平方，排序后: {:?}", results)?;
+
+    Ok(())
+}
+
+fn describe_try(result: Result<i32, TryRecvError>) -> String {
+    match result {
+        Ok(value) => format!("收到 {}", value),
+        Err(TryRecvError::Empty) => String::from("Empty（暂时没有值）"),
+        Err(TryRecvError::Disconnected) => String::from("Disconnected（通道已关闭）"),
+    }
+}
+
+// 流水线的第一段：依次发送数字，结束时丢弃发送端
+fn produce(tx: Sender<u64>, numbers: impl Iterator<Item = u64>) {
+    for n in numbers {
+        tx.send(n).unwrap();
+    }
+}
+
+// 流水线的第二段：收到一个数就发送它的平方，上游关闭后自己也结束
+fn square_all(rx: Receiver<u64>, tx: Sender<u64>) {
+    for n in rx {
+        tx.send(n * n).unwrap();
+    }
+}
+
+// 每个数字交给一个线程计算平方，结果从通道收集后排序
+pub fn squares_via_channel(numbers: Vec<u64>) -> Vec<u64> {
+    let (tx, rx) = mpsc::channel();
+    for n in numbers {
+        let tx = tx.clone();
+        thread::spawn(move || tx.send(n * n).unwrap());
+    }
+    drop(tx);
+    let mut results: Vec<u64> = rx.iter().collect();
+    results.sort();
+    results
+}
@@ -0,0 +1,116 @@
+//! 并发练习
+//!
+//! 把下面函数中的 `todo!()` 换成实现，然后运行 `rust_study check <练习>` 检查，
+//! 例如 `rust_study check concurrency/parallel_max`。
+
+use crate::check::{expect_eq, Case, Exercise};
+
+// 练习 1：把切片分成 threads 块，在作用域线程中分别求最大值，再取其中最大的；
+// 空切片返回 None（参考 threads 课中的 parallel_sum）
+pub fn parallel_max(numbers: &[i64], threads: usize) -> Option<i64> {
+    todo!()
+}
+
+// 练习 2：为每个单词启动一个线程，通过通道发回 (单词, 长度)，
+// 收集后按单词排序返回（参考 channels 课中的 squares_via_channel）
+pub fn word_lengths(words: Vec<String>) -> Vec<(String, usize)> {
+    todo!()
+}
+
+// 练习 3：threads 个线程共享一个 Arc<Mutex<Vec<usize>>>，
+// 第 i 个线程（从 0 开始）把 i 推入向量；返回排序后的向量（参考 shared_state 课）
+pub fn collect_ids(threads: usize) -> Vec<usize> {
+    todo!()
+}
+
+pub static EXERCISES: &[Exercise] = &[
+    Exercise {
+        id: "parallel_max",
+        topic: "concurrency",
+        lesson: "threads",
+        title: "并行求最大值",
+        title_en: "Parallel maximum",
+        cases: &[
+            Case {
+                name: "空切片",
+                name_en: "Empty slice",
+                hint: "numbers 为空时直接返回 None，不要启动线程",
+                hint_en: "Return None right away when numbers is empty; don't start any threads",
+                run: || expect_eq(parallel_max(&[], 4), None),
+            },
+            Case {
+                name: "多个线程",
+                name_en: "Several threads",
+                hint: "用 numbers.chunks(size) 分块，size 至少为 1，每块在 s.spawn 中调用 iter().max()",
+                hint_en: "Split with numbers.chunks(size), size at least 1, and call iter().max() on each chunk inside s.spawn",
+                run: || expect_eq(parallel_max(&[3, -7, 42, 8, 15, 4, 23], 3), Some(42)),
+            },
+            Case {
+                name: "线程比元素多",
+                name_en: "More threads than elements",
+                hint: "块的大小用 len.div_ceil(threads).max(1)，避免 chunks(0) panic",
+                hint_en: "Use len.div_ceil(threads).max(1) as the chunk size so chunks(0) doesn't panic",
+                run: || expect_eq(parallel_max(&[-5, -2], 8), Some(-2)),
+            },
+        ],
+    },
+    Exercise {
+        id: "word_lengths",
+        topic: "concurrency",
+        lesson: "channels",
+        title: "通过通道收集结果",
+        title_en: "Collecting results over a channel",
+        cases: &[
+            Case {
+                name: "一个单词",
+                name_en: "One word",
+                hint: "let (tx, rx) = mpsc::channel(); 线程中用 tx.send((word, len))",
+                hint_en: "let (tx, rx) = mpsc::channel(); each thread calls tx.send((word, len))",
+                run: || expect_eq(word_lengths(vec![String::from("rust")]), vec![(String::from("rust"), 4)]),
+            },
+            Case {
+                name: "多个单词按字母排序",
+                name_en: "Several words, sorted alphabetically",
+                hint: "每个线程用 tx.clone()，启动完后 drop(tx)，再 rx.iter().collect() 并排序",
+                hint_en: "Give each thread a tx.clone(), drop(tx) after starting them, then rx.iter().collect() and sort",
+                run: || {
+                    let words = ["thread", "arc", "mutex"].map(String::from).to_vec();
+                    expect_eq(
+                        word_lengths(words),
+                        vec![(String::from("arc"), 3), (String::from("mutex"), 5), (String::from("thread"), 6)],
+                    )
+                },
+            },
+            Case {
+                name: "按字节计算长度",
+                name_en: "Length counted in bytes",
+                hint: "String::len 返回字节数，中文字符每个 3 个字节",
+                hint_en: "String::len returns the number of bytes; each Chinese character takes 3 bytes",
+                run: || expect_eq(word_lengths(vec![String::from("并发")]), vec![(String::from("并发"), 6)]),
+            },
+        ],
+    },
+    Exercise {
+        id: "collect_ids",
+        topic: "concurrency",
+        lesson: "shared_state",
+        title: "共享向量",
+        title_en: "A shared vector",
+        cases: &[
+            Case {
+                name: "没有线程",
+                name_en: "No threads",
+                hint: "threads 为 0 时返回空向量",
+                hint_en: "Return an empty vector when threads is 0",
+                run: || expect_eq(collect_ids(0), Vec::new()),
+            },
+            Case {
+                name: "八个线程",
+                name_en: "Eight threads",
+                hint: "每个线程用 Arc::clone 拿到向量，lock().unwrap().push(i)；join 所有线程后再排序",
+                hint_en: "Each thread gets the vector through Arc::clone and calls lock().unwrap().push(i); sort after joining all threads",
+                run: || expect_eq(collect_ids(8), (0..8).collect()),
+            },
+        ],
+    },
+];
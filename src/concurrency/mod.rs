@@ -0,0 +1,11 @@
+//! 并发模块
+//!
+//! 包含线程、通道、共享状态和原子类型等并发示例。
+
+// 声明子模块
+pub mod threads;
+pub mod channels;
+pub mod shared_state;
+pub mod atomics;
+pub mod exercises;
+pub mod quiz;
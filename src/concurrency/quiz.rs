@@ -0,0 +1,87 @@
+//! 并发小测验
+//!
+//! 预测输出题运行与 threads 和 channels 课相同的代码，答案以实际运行结果为准。
+
+use std::io::{self, Write};
+use std::sync::mpsc;
+use std::thread;
+
+use crate::context::Ctx;
+use crate::quiz::{Answer, Question};
+
+pub static QUESTIONS: &[Question] = &[
+    Question {
+        topic: "concurrency",
+        lesson: "threads",
+        prompt: "下面的代码输出什么？",
+        prompt_en: "What does this code print?",
+        code: "let handles: Vec<_> = (1..=3)\n    .map(|i| thread::spawn(move || i * 10))\n    .collect();\nlet results: Vec<i32> = handles.into_iter().map(|h| h.join().unwrap()).collect();\nprintln!(\"{:?}\", results);",
+        options: &["[10, 20, 30]", "顺序不确定，取决于线程调度", "[30, 20, 10]", "编译错误：i 没有 move"],
+        options_en: &["[10, 20, 30]", "Nondeterministic, depends on thread scheduling", "[30, 20, 10]", "Compile error: i isn't moved"],
+        answer: Answer::Output(join_in_order),
+        explanation: "线程完成的先后不确定，但 join 的顺序就是 handles 的顺序，所以结果总是按启动顺序排列。",
+        explanation_en: "The threads may finish in any order, but the joins happen in the order of handles, so the results always follow the start order.",
+    },
+    Question {
+        topic: "concurrency",
+        lesson: "channels",
+        prompt: "下面的代码输出什么？",
+        prompt_en: "What does this code print?",
+        code: "let (tx, rx) = mpsc::channel();\nlet tx2 = tx.clone();\ntx.send(1).unwrap();\ntx2.send(2).unwrap();\ndrop(tx);\ndrop(tx2);\nprintln!(\"{:?}\", rx.iter().collect::<Vec<_>>());",
+        options: &["[1, 2]", "[2, 1]", "[]", "程序一直阻塞"],
+        options_en: &["[1, 2]", "[2, 1]", "[]", "The program blocks forever"],
+        answer: Answer::Output(closed_channel),
+        explanation: "两个值在同一个线程中先后发送，按发送顺序到达；所有发送端都被丢弃后通道关闭，rx.iter() 随之结束。",
+        explanation_en: "Both values are sent one after the other from the same thread and arrive in that order; once every sender is dropped the channel closes and rx.iter() ends.",
+    },
+    Question {
+        topic: "concurrency",
+        lesson: "channels",
+        prompt: "如果删掉上一题中的 drop(tx2)，会发生什么？",
+        prompt_en: "What happens if you remove drop(tx2) from the previous question?",
+        code: "",
+        options: &["输出 [1, 2]", "输出 [1]", "rx.iter() 永远等待下一个值，程序不会结束", "编译错误"],
+        options_en: &["It prints [1, 2]", "It prints [1]", "rx.iter() waits forever for the next value and the program never ends", "Compile error"],
+        answer: Answer::Choice(2),
+        explanation: "只要还有一个发送端活着，接收端就认为以后可能还有消息，rx.iter() 会一直阻塞。",
+        explanation_en: "As long as one sender is alive, the receiver assumes more messages may come, so rx.iter() keeps blocking.",
+    },
+    Question {
+        topic: "concurrency",
+        lesson: "shared_state",
+        prompt: "把 Rc<Mutex<i32>> 移动到 thread::spawn 的闭包中，为什么不能编译？",
+        prompt_en: "Why doesn't moving an Rc<Mutex<i32>> into a thread::spawn closure compile?",
+        code: "let counter = Rc::new(Mutex::new(0));\nthread::spawn(move || {\n    *counter.lock().unwrap() += 1;\n});",
+        options: &[
+            "Mutex 不能放在 Rc 里",
+            "Rc 的引用计数不是原子操作，Rc 没有实现 Send",
+            "闭包需要写成 FnMut",
+            "thread::spawn 只接受 'static 的引用",
+        ],
+        options_en: &[
+            "A Mutex can't live inside an Rc",
+            "Rc's reference count isn't atomic, so Rc doesn't implement Send",
+            "The closure has to be FnMut",
+            "thread::spawn only accepts 'static references",
+        ],
+        answer: Answer::Choice(1),
+        explanation: "两个线程同时修改 Rc 的计数会产生数据竞争，所以 Rc 不是 Send（E0277）；换成 Arc 即可。",
+        explanation_en: "Two threads updating the Rc count at once would be a data race, so Rc isn't Send (E0277); use Arc instead.",
+    },
+];
+
+fn join_in_order(ctx: &mut Ctx) -> io::Result<()> {
+    let handles: Vec<_> = (1..=3).map(|i| thread::spawn(move || i * 10)).collect();
+    let results: Vec<i32> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+    writeln!(ctx, "{:?}", results)
+}
+
+fn closed_channel(ctx: &mut Ctx) -> io::Result<()> {
+    let (tx, rx) = mpsc::channel();
+    let tx2 = tx.clone();
+    tx.send(1).unwrap();
+    tx2.send(2).unwrap();
+    drop(tx);
+    drop(tx2);
+    writeln!(ctx, "{:?}", rx.iter().collect::<Vec<_>>())
+}
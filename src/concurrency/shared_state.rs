@@ -0,0 +1,205 @@
+//! 共享状态（Mutex、Arc 和 RwLock）
+//!
+//! 通道之外的另一种做法是让多个线程访问同一份数据：
+//! - `Mutex<T>` 保证同一时刻只有一个线程能访问数据，`lock` 返回的守卫离开作用域时自动解锁
+//! - `Arc<T>` 是原子引用计数的智能指针，可以在线程之间共享所有权（`Rc<T>` 不行）
+//! - `RwLock<T>` 允许多个读者同时读，或者一个写者独占
+//! - `Condvar` 让线程等待某个条件成立
+//!
+//! 持有锁的线程 panic 时锁会“中毒”，之后的 `lock` 返回 `Err`，但数据仍然可以取回。
+
+use std::io::{self, Write};
+use std::sync::{Arc, Condvar, Mutex, RwLock, TryLockError};
+use std::thread;
+
+use crate::context::Ctx;
+use crate::panics;
+
+pub fn main(ctx: &mut Ctx) -> io::Result<()> {
+    writeln!(ctx, "=== {} ===", ctx.text("lesson.shared_state"))?;
+
+    ctx.section(1, "Mutex 基础", "Mutex basics")?;
+    let m = Mutex::new(5);
+    {
+        // lock 返回 MutexGuard，通过它访问数据
+        let mut num = m.lock().unwrap();
+        *num = 6;
+    } // 守卫在这里被丢弃，锁随之释放
+    writeln!(ctx, "m = {}", m.lock().unwrap())?;
+
+    ctx.section(2, "用 Arc<Mutex<T>> 在线程间共享计数器", "Sharing a counter between threads with Arc<Mutex<T>>")?;
+    writeln!(ctx, "10 个线程各加 1 后: {}", shared_counter(10, 1))?;
+    writeln!(ctx, "4 个线程各加 1000 后: {}", shared_counter(4, 1000))?;
+
+    ctx.section(3, "Rc 不能跨线程，要用 Arc", "Rc can't cross threads; use Arc")?;
+    // 错误：Rc<T> 的引用计数不是原子操作，没有实现 Send（见 tests/compile_fail/rc_not_send.rs）
+    // let counter = Rc::new(Mutex::new(0));
+    // thread::spawn(move || {
+    //     *counter.lock().unwrap() += 1;
+    // });
+    let shared = Arc::new(String::from("共享的只读数据"));
+    let handles: Vec<_> = (0..3)
+        .map(|_| {
+            let shared = Arc::clone(&shared);
+            thread::spawn(move || shared.len())
+        })
+        .collect();
+    let lengths: Vec<usize> = handles.into_iter().map(|handle| handle.join().unwrap()).collect();
+    writeln!(ctx, "三个线程读到的字节数: {:?}", lengths)?;
+    writeln!(ctx, "线程结束后的强引用计数: {}", Arc::strong_count(&shared))?;
+
+    ctx.section(4, "持有锁时 try_lock 失败", "try_lock fails while the lock is held")?;
+    let m = Mutex::new(vec![1, 2, 3]);
+    let guard = m.lock().unwrap();
+    writeln!(ctx, "已持有锁时 try_lock: {}", describe_try_lock(m.try_lock().map(|_| ())))?;
+    drop(guard);
+    writeln!(ctx, "释放后 try_lock: {}", describe_try_lock(m.try_lock().map(|_| ())))?;
+
+    ctx.section(5, "RwLock：多个读者或一个写者", "RwLock: many readers or one writer")?;
+    let lock = RwLock::new(String::from("v1"));
+    {
+        let r1 = lock.read().unwrap();
+        let r2 = lock.read().unwrap();
+        writeln!(ctx, "两个读者同时读到: {} 和 {}", *r1, *r2)?;
+        writeln!(ctx, "有读者时 try_write 成功: {}", lock.try_write().is_ok())?;
+    }
+    lock.write().unwrap().push_str(" -> v2");
+    writeln!(ctx, "写者修改后: {}", lock.read().unwrap())?;
+
+    ctx.section(6, "在线程间共享 RwLock 保护的配置", "Sharing an RwLock-protected configuration between threads")?;
+    let config = Arc::new(RwLock::new(Config { threads: 2, verbose: false }));
+    let writer = {
+        let config = Arc::clone(&config);
+        thread::spawn(move || {
+            let mut config = config.write().unwrap();
+            config.threads = 8;
+            config.verbose = true;
+        })
+    };
+    // 先等写者结束，读者读到的就一定是修改后的配置
+    writer.join().unwrap();
+    let readers: Vec<_> = (1..=3)
+        .map(|id| {
+            let config = Arc::clone(&config);
+            thread::spawn(move || {
+                let config = config.read().unwrap();
+                format!("读者 {} 看到 threads = {}, verbose = {}", id, config.threads, config.verbose)
+            })
+        })
+        .collect();
+    for reader in readers {
+        writeln!(ctx, "{}", reader.join().unwrap())?;
+    }
+
+    ctx.section(7, "锁中毒：持有锁的线程 panic", "Lock poisoning: a thread panics while holding the lock")?;
+    let data = Arc::new(Mutex::new(0));
+    let result = {
+        let data = Arc::clone(&data);
+        // panic 是预料之中的，只在这个线程里静默，不动全局钩子
+        thread::spawn(move || {
+            panics::quietly(|| {
+                let mut guard = data.lock().unwrap();
+                *guard += 1;
+                panic!("持有锁时出错");
+            })
+        })
+        .join()
+    };
+    writeln!(ctx, "线程 panic: {}", result.is_err())?;
+    writeln!(ctx, "锁已中毒: {}", data.is_poisoned())?;
+    match data.lock() {
+        Ok(value) => writeln!(ctx, "lock 成功: {}", *value)?,
+        // 中毒只是提醒数据可能不完整，into_inner 仍然可以取回守卫
+        Err(poisoned) => writeln!(ctx, "lock 返回 Err，取回数据: {}", *poisoned.into_inner())?,
+    }
+
+    ctx.section(8, "Condvar：等待条件成立", "Condvar: waiting for a condition")?;
+    let pair = Arc::new((Mutex::new(false), Condvar::new()));
+    let worker = {
+        let pair = Arc::clone(&pair);
+        thread::spawn(move || {
+            let (ready, condvar) = &*pair;
+            *ready.lock().unwrap() = true;
+            condvar.notify_one();
+        })
+    };
+    let (ready, condvar) = &*pair;
+    // wait_while 在条件为真时继续等待，可以应对虚假唤醒
+    let guard = condvar.wait_while(ready.lock().unwrap(), |ready| !*ready).unwrap();
+    writeln!(ctx, "收到通知，ready = {}", *guard)?;
+    drop(guard);
+    worker.join().unwrap();
+
+    ctx.section(9, "按固定顺序加锁避免死锁", "Locking in a fixed order to avoid deadlock")?;
+    let alice = Arc::new(Account { id: 1, balance: Mutex::new(100) });
+    let bob = Arc::new(Account { id: 2, balance: Mutex::new(50) });
+    // 两个线程朝相反的方向转账；如果都先锁自己的账户，可能互相等待对方而死锁
+    let transfers: Vec<_> = [(Arc::clone(&alice), Arc::clone(&bob), 30), (Arc::clone(&bob), Arc::clone(&alice), 10)]
+        .into_iter()
+        .map(|(from, to, amount)| thread::spawn(move || transfer(&from, &to, amount)))
+        .collect();
+    for handle in transfers {
+        handle.join().unwrap();
+    }
+    writeln!(
+        ctx,
+        "转账后: 账户 1 = {}, 账户 2 = {}",
+        alice.balance.lock().unwrap(),
+        bob.balance.lock().unwrap()
+    )?;
+
+    Ok(())
+}
+
+// 启动 threads 个线程，每个线程给共享计数器加 increments 次
+pub fn shared_counter(threads: usize, increments: usize) -> usize {
+    let counter = Arc::new(Mutex::new(0));
+    let handles: Vec<_> = (0..threads)
+        .map(|_| {
+            let counter = Arc::clone(&counter);
+            thread::spawn(move || {
+                for _ in 0..increments {
+                    *counter.lock().unwrap() += 1;
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    let total = *counter.lock().unwrap();
+    total
+}
+
+fn describe_try_lock(result: Result<(), TryLockError<impl Sized>>) -> &'static str {
+    match result {
+        Ok(()) => "成功",
+        Err(TryLockError::WouldBlock) => "WouldBlock（锁被占用）",
+        Err(TryLockError::Poisoned(_)) => "Poisoned（锁已中毒）",
+    }
+}
+
+#[derive(Debug)]
+struct Config {
+    threads: usize,
+    verbose: bool,
+}
+
+struct Account {
+    id: u32,
+    balance: Mutex<i64>,
+}
+
+// 总是先锁 id 小的账户，所有线程的加锁顺序一致，就不会互相等待
+fn transfer(from: &Account, to: &Account, amount: i64) {
+    let (first, second) = if from.id < to.id { (from, to) } else { (to, from) };
+    let mut first = first.balance.lock().unwrap();
+    let mut second = second.balance.lock().unwrap();
+    let (from_balance, to_balance) = if from.id < to.id {
+        (&mut *first, &mut *second)
+    } else {
+        (&mut *second, &mut *first)
+    };
+    *from_balance -= amount;
+    *to_balance += amount;
+}
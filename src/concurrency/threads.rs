@@ -0,0 +1,131 @@
+//! 线程（Threads）
+//!
+//! `thread::spawn` 创建一个操作系统线程，返回 `JoinHandle`；调用 `join` 等待线程结束，
+//! 并取回线程闭包的返回值。没有 join 的线程可能在主线程退出时还没有运行完，
+//! 它的输出也就丢了。
+//!
+//! 线程之间的执行顺序不确定，所以本课中的线程只计算结果，
+//! 输出都在主线程中按固定顺序写出。
+
+use std::io::{self, Write};
+use std::thread;
+use std::time::Duration;
+
+use crate::context::Ctx;
+use crate::panics;
+
+pub fn main(ctx: &mut Ctx) -> io::Result<()> {
+    writeln!(ctx, "=== {} ===", ctx.text("lesson.threads"))?;
+
+    ctx.section(1, "创建线程并等待它结束", "Spawning a thread and waiting for it")?;
+    let handle = thread::spawn(|| {
+        thread::sleep(Duration::from_millis(1));
+        "来自新线程的问候"
+    });
+    // join 阻塞当前线程，直到新线程运行完毕
+    let greeting = handle.join().expect("线程 panic 了");
+    writeln!(ctx, "join 返回: {}", greeting)?;
+
+    ctx.section(2, "JoinHandle 带回计算结果", "JoinHandle brings back a result")?;
+    let handle: thread::JoinHandle<u64> = thread::spawn(|| (1..=100).sum());
+    writeln!(ctx, "新线程计算 1 到 100 的和: {}", handle.join().unwrap())?;
+
+    ctx.section(3, "move 闭包把所有权交给线程", "A move closure hands ownership to the thread")?;
+    let numbers = vec![1, 2, 3];
+    // 线程可能比当前函数活得更久，所以闭包必须拿走 numbers 的所有权
+    let handle = thread::spawn(move || {
+        let doubled: Vec<i32> = numbers.iter().map(|n| n * 2).collect();
+        doubled
+    });
+    // println!("{:?}", numbers); // 错误：numbers 已经移动到线程中
+    writeln!(ctx, "线程返回加倍后的向量: {:?}", handle.join().unwrap())?;
+
+    ctx.section(4, "启动多个线程，按启动顺序 join", "Starting several threads and joining them in order")?;
+    let handles: Vec<thread::JoinHandle<(usize, usize)>> = (1..=4)
+        .map(|id| thread::spawn(move || (id, id * id)))
+        .collect();
+    // 线程完成的顺序不确定，但按顺序 join 得到的结果顺序是确定的
+    for handle in handles {
+        let (id, square) = handle.join().unwrap();
+        writeln!(ctx, "线程 {} 计算出 {} 的平方: {}", id, id, square)?;
+    }
+
+    ctx.section(5, "用 Builder 给线程命名", "Naming threads with Builder")?;
+    let handle = thread::Builder::new()
+        .name(String::from("worker-1"))
+        .spawn(|| thread::current().name().map(String::from))?;
+    writeln!(ctx, "线程中看到的名字: {:?}", handle.join().unwrap())?;
+
+    ctx.section(6, "线程 panic 时 join 返回 Err", "join returns Err when the thread panics")?;
+    writeln!(ctx, "{}", panicking_thread())?;
+
+    ctx.section(7, "作用域线程：借用局部变量", "Scoped threads: borrowing local variables")?;
+    let words = vec!["apple", "banana", "cherry"];
+    let mut total = 0;
+    // thread::scope 保证作用域结束前所有线程都已 join，
+    // 所以线程可以直接借用 words，不需要 move 或 Arc
+    thread::scope(|s| {
+        let longest = s.spawn(|| words.iter().map(|w| w.len()).max());
+        let joined = s.spawn(|| words.join("+"));
+        total = words.len();
+        writeln!(ctx, "最长单词的长度: {:?}", longest.join().unwrap())?;
+        writeln!(ctx, "拼接结果: {}", joined.join().unwrap())
+    })?;
+    writeln!(ctx, "作用域结束后 words 仍然可用: {:?}，共 {} 个", words, total)?;
+
+    ctx.section(8, "作用域线程分块修改同一个向量", "Scoped threads modifying chunks of one vector")?;
+    let mut data: Vec<u32> = (1..=12).collect();
+    // chunks_mut 切出互不重叠的可变切片，每个线程修改一块
+    thread::scope(|s| {
+        for (i, chunk) in data.chunks_mut(4).enumerate() {
+            s.spawn(move || {
+                for value in chunk.iter_mut() {
+                    *value *= 10_u32.pow(i as u32);
+                }
+            });
+        }
+    });
+    writeln!(ctx, "分块处理后: {:?}", data)?;
+
+    ctx.section(9, "并行求和", "Parallel sum")?;
+    let numbers: Vec<u64> = (1..=1000).collect();
+    writeln!(ctx, "4 个线程求和: {}", parallel_sum(&numbers, 4))?;
+    writeln!(ctx, "单线程求和:   {}", numbers.iter().sum::<u64>())?;
+
+    Ok(())
+}
+
+// 运行一个会 panic 的线程，描述 join 得到的结果。
+// panic 是预料之中的，用 panics::quietly 只在新线程里静默，不动全局钩子
+fn panicking_thread() -> String {
+    let result = thread::spawn(|| {
+        panics::quietly(|| {
+            let v: Vec<i32> = Vec::new();
+            v[0]
+        })
+    })
+    .join();
+
+    match result {
+        Ok(value) => format!("线程正常结束: {}", value),
+        Err(payload) => {
+            let message = payload
+                .downcast_ref::<String>()
+                .map(String::as_str)
+                .unwrap_or("（不是字符串）");
+            format!("线程 panic 了，join 返回 Err: {}", message)
+        }
+    }
+}
+
+// 把切片分成 threads 块，每块在一个作用域线程中求和
+pub fn parallel_sum(numbers: &[u64], threads: usize) -> u64 {
+    let size = numbers.len().div_ceil(threads.max(1)).max(1);
+    thread::scope(|s| {
+        let handles: Vec<_> = numbers
+            .chunks(size)
+            .map(|chunk| s.spawn(move || chunk.iter().sum::<u64>()))
+            .collect();
+        handles.into_iter().map(|handle| handle.join().unwrap()).sum()
+    })
+}
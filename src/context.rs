@@ -0,0 +1,295 @@
+//! 课程运行上下文
+//!
+//! 课程不直接使用 println! 和标准输入，而是通过 `Ctx` 读写。
+//! 运行器决定输出的去向：终端、文件，或者测试中的内存缓冲区，
+//! 界面文字使用的语言，课程读写文件用的临时工作目录，以及学习进度记录在哪里。
+//!
+//! 课程用 `ctx.section(1, "创建向量", "Creating vectors")?` 声明编号小节（中英文标题各一个）；单步模式下每一节结束后暂停。
+//! `writeln!(ctx, ...)` 和 `ctx.section` 会记下调用它们的源码位置（需要时），
+//! show 命令据此把每段输出放到产生它的小节后面。
+
+use std::env;
+use std::fmt;
+use std::panic::Location;
+use std::io::{self, BufRead, Write};
+use std::path::{Path, PathBuf};
+
+use crate::i18n::Locale;
+use crate::progress::{Kind, Progress};
+use crate::workspace::Workspace;
+
+pub struct Ctx<'a> {
+    out: &'a mut dyn Write,
+    input: &'a mut dyn BufRead,
+    locale: Locale,
+    workspace_root: PathBuf,
+    keep_workspace: bool,
+    // 当前课程的工作目录名和（用到后才创建的）目录本身
+    workspace_name: String,
+    workspace: Option<Workspace>,
+    // 本次运行的工作目录，各课的目录都在它下面；Ctx 销毁时一起删除
+    run_workspace: Option<Workspace>,
+    // 正在运行的课程的输出副本，供报告使用
+    capture: Option<Vec<u8>>,
+    // 学习进度；为 None 时不记录
+    progress: Option<Progress>,
+    // 每次 write!/writeln! 的调用位置和写出的内容，供 show 命令使用
+    trace: Option<Vec<(&'static Location<'static>, String)>>,
+    // 输出中是否使用 ANSI 颜色
+    color: bool,
+    // 单步模式：每一节结束后等待回车
+    step: bool,
+    // 当前课程正在进行的小节：编号和中英文标题
+    section: Option<(usize, &'static str, &'static str)>,
+}
+
+impl<'a> Ctx<'a> {
+    pub fn new(out: &'a mut dyn Write, input: &'a mut dyn BufRead) -> Self {
+        Ctx {
+            out,
+            input,
+            locale: Locale::default(),
+            workspace_root: env::temp_dir(),
+            keep_workspace: false,
+            workspace_name: String::from("scratch"),
+            workspace: None,
+            run_workspace: None,
+            capture: None,
+            progress: None,
+            trace: None,
+            color: false,
+            step: false,
+            section: None,
+        }
+    }
+
+    pub fn with_locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    // 工作目录创建在 root 下，默认是系统临时目录
+    pub fn with_workspace_root(mut self, root: PathBuf) -> Self {
+        self.workspace_root = root;
+        self
+    }
+
+    // 运行结束后保留工作目录（其中每课一个子目录），便于查看课程留下的文件
+    pub fn keep_workspace(mut self, keep: bool) -> Self {
+        self.keep_workspace = keep;
+        self
+    }
+
+    // 把完成的课程、练习和小测验记录到 progress 中
+    pub fn with_progress(mut self, progress: Progress) -> Self {
+        self.progress = Some(progress);
+        self
+    }
+
+    // 输出到终端时使用颜色，例如 show 命令的语法高亮
+    pub fn with_color(mut self, color: bool) -> Self {
+        self.color = color;
+        self
+    }
+
+    // 单步模式：课程的每一节结束后暂停，回车继续，q 退出单步模式
+    pub fn step_through(mut self, step: bool) -> Self {
+        self.step = step;
+        self
+    }
+
+    pub fn locale(&self) -> Locale {
+        self.locale
+    }
+
+    pub fn workspace_root(&self) -> &Path {
+        &self.workspace_root
+    }
+
+    pub fn keeps_workspace(&self) -> bool {
+        self.keep_workspace
+    }
+
+    // 当前语言的一条消息，见 i18n 中的消息目录
+    pub fn text(&self, key: &'static str) -> &'static str {
+        self.locale.text(key)
+    }
+
+    // 当前语言的一条带参数的消息
+    pub fn message(&self, key: &'static str, args: &[&dyn fmt::Display]) -> String {
+        self.locale.message(key, args)
+    }
+
+    // 课程工作目录中某个文件的路径；第一次调用时创建目录
+    pub fn workspace_file(&mut self, name: &str) -> io::Result<PathBuf> {
+        let workspace = match self.workspace.take() {
+            Some(workspace) => workspace,
+            None => {
+                let run = match self.run_workspace.take() {
+                    Some(run) => run,
+                    None => Workspace::for_run(&self.workspace_root, self.keep_workspace)?,
+                };
+                let workspace = run.for_lesson(&self.workspace_name);
+                self.run_workspace = Some(run);
+                workspace?
+            }
+        };
+        let path = workspace.path().join(name);
+        self.workspace = Some(workspace);
+        Ok(path)
+    }
+
+    // 运行器在每课开始前调用，之后用到的工作目录以 name 命名
+    pub fn start_workspace(&mut self, name: String) {
+        self.workspace = None;
+        self.workspace_name = name;
+    }
+
+    // 运行器在每课结束后调用：删除这一课的工作目录，或在需要保留时返回它的路径
+    pub fn finish_workspace(&mut self) -> Option<PathBuf> {
+        let workspace = self.workspace.take()?;
+        workspace.is_kept().then(|| workspace.path().to_path_buf())
+    }
+
+    // 开始记录输出：之后写入的内容照常输出，同时保留一份副本
+    pub fn start_capture(&mut self) {
+        self.capture = Some(Vec::new());
+    }
+
+    // 停止记录，返回记录下的输出
+    pub fn finish_capture(&mut self) -> String {
+        let captured = self.capture.take().unwrap_or_default();
+        String::from_utf8_lossy(&captured).into_owned()
+    }
+
+    // 课程中声明一个编号小节，写在这一节的代码之前；界面上按当前语言选用标题。
+    // 单步模式下先为上一节暂停；记录调用位置时，这一节的位置也记入（内容为空）
+    #[track_caller]
+    pub fn section(&mut self, number: usize, title: &'static str, title_en: &'static str) -> io::Result<()> {
+        if let Some(trace) = &mut self.trace {
+            trace.push((Location::caller(), String::new()));
+        }
+        self.pause()?;
+        self.section = Some((number, title, title_en));
+        Ok(())
+    }
+
+    // 运行器在每课结束后调用：课程正常结束时为最后一节暂停，然后清除小节
+    pub fn finish_sections(&mut self, pause: bool) -> io::Result<()> {
+        if pause {
+            self.pause()?;
+        }
+        self.section = None;
+        Ok(())
+    }
+
+    // 单步模式下显示刚结束的小节并等待输入；输入 q 或输入结束时退出单步模式。
+    // 提示直接写到输出，不计入课程输出的副本
+    fn pause(&mut self) -> io::Result<()> {
+        let Some((number, title, title_en)) = self.section else {
+            return Ok(());
+        };
+        let title = match self.locale {
+            Locale::ZhCn => title,
+            Locale::EnUs => title_en,
+        };
+        if !self.step {
+            return Ok(());
+        }
+        let prompt = self.message("step.pause", &[&number, &title]);
+        write!(self.out, "{}", prompt)?;
+        let mut line = String::new();
+        if self.read_line(&mut line)? == 0 || line.trim() == "q" {
+            self.step = false;
+        }
+        if line.is_empty() {
+            writeln!(self.out)?;
+        }
+        Ok(())
+    }
+
+    // 开始记录每次 write!/writeln! 的调用位置
+    pub fn start_trace(&mut self) {
+        self.trace = Some(Vec::new());
+    }
+
+    // 停止记录，按写出的顺序返回（调用位置，写出的内容）
+    pub fn finish_trace(&mut self) -> Vec<(&'static Location<'static>, String)> {
+        self.trace.take().unwrap_or_default()
+    }
+
+    pub fn color(&self) -> bool {
+        self.color
+    }
+
+    pub fn progress(&self) -> Option<&Progress> {
+        self.progress.as_ref()
+    }
+
+    pub fn progress_mut(&mut self) -> Option<&mut Progress> {
+        self.progress.as_mut()
+    }
+
+    // 记录完成了一项内容；保存失败时提示一次，之后本次运行不再记录
+    pub fn record_progress(&mut self, kind: Kind, id: &str, score: Option<(usize, usize)>) -> io::Result<()> {
+        let Some(progress) = &mut self.progress else {
+            return Ok(());
+        };
+        if let Err(e) = progress.record(kind, id, score) {
+            let path = progress.path().display().to_string();
+            let message = self.message("progress.save_failed", &[&path, &e]);
+            self.progress = None;
+            writeln!(self, "{}", message)?;
+        }
+        Ok(())
+    }
+
+    // 读取一行输入，返回读到的字节数；0 表示输入已结束
+    pub fn read_line(&mut self, buf: &mut String) -> io::Result<usize> {
+        self.out.flush()?;
+        self.input.read_line(buf)
+    }
+}
+
+// 课程中使用 writeln!(ctx, ...) 输出
+impl Write for Ctx<'_> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let written = self.out.write(buf)?;
+        if let Some(capture) = &mut self.capture {
+            capture.extend_from_slice(&buf[..written]);
+        }
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.out.flush()
+    }
+
+    // write!/writeln! 展开后调用这里；track_caller 让 Location::caller() 指向课程中的那一行
+    #[track_caller]
+    fn write_fmt(&mut self, args: fmt::Arguments) -> io::Result<()> {
+        if self.trace.is_none() {
+            return Plain(self).write_fmt(args);
+        }
+        let mut text = String::new();
+        fmt::write(&mut text, args).map_err(|_| io::Error::other("formatter error"))?;
+        self.write_all(text.as_bytes())?;
+        if let Some(trace) = &mut self.trace {
+            trace.push((Location::caller(), text));
+        }
+        Ok(())
+    }
+}
+
+// 不带记录的 Ctx，用来调用 Write 默认的 write_fmt
+struct Plain<'c, 'a>(&'c mut Ctx<'a>);
+
+impl Write for Plain<'_, '_> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.0.flush()
+    }
+}
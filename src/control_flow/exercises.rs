@@ -0,0 +1,90 @@
+//! 流程控制练习
+//!
+//! 把下面函数中的 `todo!()` 换成实现，然后运行 `rust_study check <练习>` 检查，
+//! 例如 `rust_study check control_flow/fizzbuzz`。
+
+use crate::check::{expect_eq, Case, Exercise};
+
+// 练习 1：3 的倍数返回 "Fizz"，5 的倍数返回 "Buzz"，
+// 同时是 3 和 5 的倍数返回 "FizzBuzz"，其他返回数字本身（参考 if_else 课）
+pub fn fizzbuzz(n: u32) -> String {
+    todo!()
+}
+
+// 练习 2：第 n 个斐波那契数，fibonacci(0) = 0，fibonacci(1) = 1（参考 loops 课）
+pub fn fibonacci(n: u32) -> u64 {
+    todo!()
+}
+
+pub static EXERCISES: &[Exercise] = &[
+    Exercise {
+        id: "fizzbuzz",
+        topic: "control_flow",
+        lesson: "if_else",
+        title: "FizzBuzz",
+        title_en: "FizzBuzz",
+        cases: &[
+            Case {
+                name: "普通数字",
+                name_en: "Plain numbers",
+                hint: "不是 3 或 5 的倍数时返回 n.to_string()",
+                hint_en: "Return n.to_string() when n is not a multiple of 3 or 5",
+                run: || expect_eq(fizzbuzz(7), String::from("7")),
+            },
+            Case {
+                name: "3 的倍数",
+                name_en: "Multiples of 3",
+                hint: "用 n % 3 == 0 判断是否是 3 的倍数",
+                hint_en: "n % 3 == 0 tells whether n is a multiple of 3",
+                run: || expect_eq(fizzbuzz(9), String::from("Fizz")),
+            },
+            Case {
+                name: "5 的倍数",
+                name_en: "Multiples of 5",
+                hint: "用 n % 5 == 0 判断是否是 5 的倍数",
+                hint_en: "n % 5 == 0 tells whether n is a multiple of 5",
+                run: || expect_eq(fizzbuzz(10), String::from("Buzz")),
+            },
+            Case {
+                name: "15 的倍数",
+                name_en: "Multiples of 15",
+                hint: "先判断 15 的倍数，否则会被 3 的分支提前返回",
+                hint_en: "Check for multiples of 15 first, otherwise the branch for 3 returns early",
+                run: || expect_eq(fizzbuzz(30), String::from("FizzBuzz")),
+            },
+        ],
+    },
+    Exercise {
+        id: "fibonacci",
+        topic: "control_flow",
+        lesson: "loops",
+        title: "斐波那契数",
+        title_en: "Fibonacci numbers",
+        cases: &[
+            Case {
+                name: "前两项",
+                name_en: "First two terms",
+                hint: "n 为 0 和 1 时直接返回 n",
+                hint_en: "Return n directly when n is 0 or 1",
+                run: || {
+                    expect_eq(fibonacci(0), 0)?;
+                    expect_eq(fibonacci(1), 1)
+                },
+            },
+            Case {
+                name: "第 10 项",
+                name_en: "Term 10",
+                hint: "用两个 let mut 变量保存前两项，在 for _ in 0..n 循环中向前推进",
+                hint_en: "Keep the previous two terms in two let mut variables and advance them in a for _ in 0..n loop",
+                run: || expect_eq(fibonacci(10), 55),
+            },
+            Case {
+                name: "第 90 项",
+                name_en: "Term 90",
+                hint: "递归写法会非常慢，改用循环；结果需要 u64 才放得下",
+                hint_en: "The recursive version is very slow, so use a loop; the result only fits in a u64",
+                run: || expect_eq(fibonacci(90), 2_880_067_194_370_816_120),
+            },
+        ],
+    },
+];
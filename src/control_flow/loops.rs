@@ -3,21 +3,25 @@
 //! Rust 有三种循环：loop、while 和 for。
 //! loop 是无限循环，while 是条件循环，for 是迭代循环。
 
-pub fn main() {
-    println!("=== loop 循环 ===");
+use std::io::{self, Write};
 
-    // 1. loop 无限循环
+use crate::context::Ctx;
+
+pub fn main(ctx: &mut Ctx) -> io::Result<()> {
+    writeln!(ctx, "=== {} ===", ctx.text("lesson.loops.loop"))?;
+
+    ctx.section(1, "loop 无限循环", "Infinite loops with loop")?;
     let mut count = 0;
     loop {
         count += 1;
-        println!("loop 计数: {}", count);
+        writeln!(ctx, "loop 计数: {}", count)?;
 
         if count == 3 {
             break; // 跳出循环
         }
     }
 
-    // 2. loop 返回值
+    ctx.section(2, "loop 返回值", "Returning values from loop")?;
     let mut counter = 0;
     let result = loop {
         counter += 1;
@@ -26,107 +30,109 @@ pub fn main() {
             break counter * 2; // 从循环中返回值
         }
     };
-    println!("loop 返回值: {}", result);
+    writeln!(ctx, "loop 返回值: {}", result)?;
 
-    println!("\n=== while 循环 ===");
+    writeln!(ctx, "\n=== {} ===", ctx.text("lesson.loops.while"))?;
 
-    // 3. while 条件循环
+    ctx.section(3, "while 条件循环", "Conditional loops with while")?;
     let mut number = 3;
     while number != 0 {
-        println!("{}!", number);
+        writeln!(ctx, "{}!", number)?;
         number -= 1;
     }
-    println!("发射!");
+    writeln!(ctx, "发射!")?;
 
-    // 4. while 遍历数组
+    ctx.section(4, "while 遍历数组", "Iterating over an array with while")?;
     let a = [10, 20, 30, 40, 50];
     let mut index = 0;
 
     while index < 5 {
-        println!("a[{}] = {}", index, a[index]);
+        writeln!(ctx, "a[{}] = {}", index, a[index])?;
         index += 1;
     }
 
-    println!("\n=== for 循环 ===");
+    writeln!(ctx, "\n=== {} ===", ctx.text("lesson.loops.for"))?;
 
-    // 5. for 遍历数组（推荐方式）
+    ctx.section(5, "for 遍历数组（推荐方式）", "Iterating over an array with for (preferred)")?;
     let a = [10, 20, 30, 40, 50];
     for element in a.iter() {
-        println!("值: {}", element);
+        writeln!(ctx, "值: {}", element)?;
     }
 
-    // 6. for 遍历范围
+    ctx.section(6, "for 遍历范围", "Iterating over a range with for")?;
     for number in 1..4 {
-        println!("{}!", number);
+        writeln!(ctx, "{}!", number)?;
     }
 
-    // 7. for 遍历范围（包含结束值）
+    ctx.section(7, "for 遍历范围（包含结束值）", "Iterating over an inclusive range with for")?;
     for number in 1..=4 {
-        println!("包含结束值: {}", number);
+        writeln!(ctx, "包含结束值: {}", number)?;
     }
 
-    // 8. for 反向遍历
+    ctx.section(8, "for 反向遍历", "Iterating in reverse with for")?;
     for number in (1..4).rev() {
-        println!("反向: {}!", number);
+        writeln!(ctx, "反向: {}!", number)?;
     }
 
-    // 9. for 遍历字符串字符
+    ctx.section(9, "for 遍历字符串字符", "Iterating over a string's chars with for")?;
     let s = "hello";
     for c in s.chars() {
-        println!("字符: {}", c);
+        writeln!(ctx, "字符: {}", c)?;
     }
 
-    // 10. for 遍历索引和值
+    ctx.section(10, "for 遍历索引和值", "Iterating over indices and values with for")?;
     let a = [10, 20, 30];
     for (index, value) in a.iter().enumerate() {
-        println!("a[{}] = {}", index, value);
+        writeln!(ctx, "a[{}] = {}", index, value)?;
     }
 
-    println!("\n=== 循环控制 ===");
+    writeln!(ctx, "\n=== {} ===", ctx.text("lesson.loops.control"))?;
 
-    // 11. continue 跳过当前迭代
+    ctx.section(11, "continue 跳过当前迭代", "continue skips the current iteration")?;
     for number in 1..10 {
         if number % 2 == 0 {
             continue; // 跳过偶数
         }
-        println!("奇数: {}", number);
+        writeln!(ctx, "奇数: {}", number)?;
     }
 
-    // 12. 嵌套循环和标签
+    ctx.section(12, "嵌套循环和标签", "Nested loops and labels")?;
     'outer: for x in 0..5 {
         'inner: for y in 0..5 {
             if x == 2 && y == 2 {
-                println!("在 ({}, {}) 处跳出外层循环", x, y);
+                writeln!(ctx, "在 ({}, {}) 处跳出外层循环", x, y)?;
                 break 'outer;
             }
             if x == 1 && y == 1 {
-                println!("在 ({}, {}) 处继续外层循环", x, y);
+                writeln!(ctx, "在 ({}, {}) 处继续外层循环", x, y)?;
                 continue 'outer;
             }
-            println!("({}, {})", x, y);
+            writeln!(ctx, "({}, {})", x, y)?;
         }
     }
 
-    println!("\n=== 迭代器方法 ===");
+    writeln!(ctx, "\n=== {} ===", ctx.text("lesson.loops.iterators"))?;
 
-    // 13. 使用迭代器方法
+    ctx.section(13, "使用迭代器方法", "Using iterator methods")?;
     let numbers = vec![1, 2, 3, 4, 5];
 
     // map: 转换每个元素
     let doubled: Vec<i32> = numbers.iter().map(|x| x * 2).collect();
-    println!("加倍: {:?}", doubled);
+    writeln!(ctx, "加倍: {:?}", doubled)?;
 
     // filter: 过滤元素
     let evens: Vec<&i32> = numbers.iter().filter(|x| *x % 2 == 0).collect();
-    println!("偶数: {:?}", evens);
+    writeln!(ctx, "偶数: {:?}", evens)?;
 
     // fold: 累积计算
     let sum: i32 = numbers.iter().fold(0, |acc, x| acc + x);
-    println!("总和: {}", sum);
+    writeln!(ctx, "总和: {}", sum)?;
 
-    // 14. for 循环中的模式匹配
+    ctx.section(14, "for 循环中的模式匹配", "Patterns in for loops")?;
     let pairs = vec![(1, "one"), (2, "two"), (3, "three")];
     for (num, word) in pairs {
-        println!("{}: {}", num, word);
+        writeln!(ctx, "{}: {}", num, word)?;
     }
+
+    Ok(())
 }
\ No newline at end of file
@@ -5,11 +5,5 @@
 // 声明子模块
 pub mod if_else;
 pub mod loops;
-
-pub fn run_all() {
-    println!("\n--- if-else 示例 ---");
-    if_else::main();
-
-    println!("\n--- 循环示例 ---");
-    loops::main();
-}
\ No newline at end of file
+pub mod exercises;
+pub mod quiz;
@@ -0,0 +1,75 @@
+//! 流程控制小测验
+//!
+//! 预测输出题运行与 loops 课相同的代码，答案以实际运行结果为准。
+
+use std::io::{self, Write};
+
+use crate::context::Ctx;
+use crate::quiz::{Answer, Question};
+
+pub static QUESTIONS: &[Question] = &[
+    Question {
+        topic: "control_flow",
+        lesson: "loops",
+        prompt: "下面的代码输出什么？",
+        prompt_en: "What does this code print?",
+        code: "let mut counter = 0;\nlet result = loop {\n    counter += 1;\n    if counter == 10 {\n        break counter * 2;\n    }\n};\nprintln!(\"{}\", result);",
+        options: &["10", "20", "0", "编译错误：loop 不能有值"],
+        options_en: &["10", "20", "0", "Compile error: loop can't have a value"],
+        answer: Answer::Output(loop_value),
+        explanation: "loop 是表达式，break 后面的值就是整个 loop 的值：counter 为 10 时返回 10 * 2。",
+        explanation_en: "loop is an expression and the value after break is the value of the whole loop: when counter is 10 it returns 10 * 2.",
+    },
+    Question {
+        topic: "control_flow",
+        lesson: "loops",
+        prompt: "下面的代码输出什么？",
+        prompt_en: "What does this code print?",
+        code: "for number in (1..4).rev() {\n    print!(\"{} \", number);\n}",
+        options: &["4 3 2 1", "3 2 1", "1 2 3", "3 2 1 0"],
+        options_en: &["4 3 2 1", "3 2 1", "1 2 3", "3 2 1 0"],
+        answer: Answer::Output(reversed_range),
+        explanation: "1..4 是左闭右开区间，包含 1、2、3；rev() 把它反过来。",
+        explanation_en: "1..4 is a half-open range containing 1, 2 and 3; rev() reverses it.",
+    },
+    Question {
+        topic: "control_flow",
+        lesson: "if_else",
+        prompt: "下面的代码能否编译？",
+        prompt_en: "Does this code compile?",
+        code: "let condition = true;\nlet number = if condition { 5 } else { \"six\" };",
+        options: &[
+            "可以，number 是 5",
+            "可以，number 的类型由运行时决定",
+            "不可以：if 和 else 分支的值类型必须相同",
+            "不可以：if 不能用在 let 右边",
+        ],
+        options_en: &[
+            "Yes, number is 5",
+            "Yes, the type of number is decided at run time",
+            "No: the if and else branches must have the same type",
+            "No: if can't appear on the right of let",
+        ],
+        answer: Answer::Choice(2),
+        explanation: "if 是表达式，变量只能有一个类型，所以两个分支的值类型必须一致（E0308）。",
+        explanation_en: "if is an expression and a variable has exactly one type, so both branches must produce the same type (E0308).",
+    },
+];
+
+fn loop_value(ctx: &mut Ctx) -> io::Result<()> {
+    let mut counter = 0;
+    let result = loop {
+        counter += 1;
+        if counter == 10 {
+            break counter * 2;
+        }
+    };
+    writeln!(ctx, "{}", result)
+}
+
+fn reversed_range(ctx: &mut Ctx) -> io::Result<()> {
+    for number in (1..4).rev() {
+        write!(ctx, "{} ", number)?;
+    }
+    writeln!(ctx)
+}
@@ -0,0 +1,521 @@
+//! 导出静态网站
+//!
+//! `rust_study export [<dir>] [--html]` 把注册表中的每一课写成一个页面：
+//! 模块文档（课程文件开头的 `//!` 注释）、按小节分开的源码，以及每一节运行时的输出。
+//! 输出的捕获方式与 `show --with-output` 相同（见 source.rs）。
+//!
+//! 目录结构与主题树一致：
+//!
+//! ```text
+//! index.md                 目录：所有主题和其中的课程
+//! basics/index.md          主题页：本主题的课程和说明
+//! basics/functions.md      课程页
+//! ```
+//!
+//! 默认生成 Markdown，`--html` 时生成 HTML（样式写在页面内）。页面之间只用相对链接，
+//! 不引用任何外部资源，可以直接从磁盘打开。
+
+use std::fmt::Write as _;
+use std::fs;
+use std::io::{self, Write};
+use std::path::Path;
+
+use crate::context::Ctx;
+use crate::registry::{self, Lesson, Topic, TOPICS};
+use crate::source::{self, Section};
+
+// 页面格式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Markup {
+    #[default]
+    Markdown,
+    Html,
+}
+
+impl Markup {
+    pub fn extension(self) -> &'static str {
+        match self {
+            Markup::Markdown => "md",
+            Markup::Html => "html",
+        }
+    }
+}
+
+// 导出的结果
+#[derive(Debug, Default)]
+pub struct Export {
+    pub pages: usize,
+    pub lessons: usize,
+    // 运行时 panic 或出错的课程路径；这些课程的页面上也写明了原因
+    pub failed: Vec<String>,
+}
+
+// export：写出整个网站，并报告写了多少页
+pub fn run(ctx: &mut Ctx, dir: &Path, markup: Markup) -> io::Result<bool> {
+    let export = export(ctx, dir, markup)?;
+    for path in &export.failed {
+        writeln!(ctx, "{}", ctx.message("export.lesson_failed", &[path]))?;
+    }
+    let index = dir.join(format!("index.{}", markup.extension()));
+    writeln!(ctx, "{}", ctx.message("export.done", &[&export.lessons, &export.pages, &index.display()]))?;
+    Ok(export.failed.is_empty())
+}
+
+pub fn export(ctx: &Ctx, dir: &Path, markup: Markup) -> io::Result<Export> {
+    let ext = markup.extension();
+    let mut export = Export::default();
+    let lessons: Vec<&'static Lesson> = TOPICS.iter().flat_map(|topic| registry::lessons_of(topic.id)).collect();
+
+    fs::create_dir_all(dir)?;
+    fs::write(dir.join(format!("index.{}", ext)), index_page(ctx, markup))?;
+    export.pages += 1;
+
+    for topic in TOPICS {
+        fs::create_dir_all(dir.join(topic.id))?;
+        fs::write(dir.join(topic.id).join(format!("index.{}", ext)), topic_page(ctx, markup, topic))?;
+        export.pages += 1;
+    }
+
+    for (i, lesson) in lessons.iter().enumerate() {
+        let previous = i.checked_sub(1).map(|i| lessons[i]);
+        let next = lessons.get(i + 1).copied();
+        let (page, failed) = lesson_page(ctx, markup, lesson, previous, next);
+        fs::write(dir.join(lesson.topic).join(format!("{}.{}", lesson.id, ext)), page)?;
+        export.pages += 1;
+        export.lessons += 1;
+        if failed {
+            export.failed.push(lesson.path());
+        }
+    }
+    Ok(export)
+}
+
+// 目录：主题和课程两层
+fn index_page(ctx: &Ctx, markup: Markup) -> String {
+    let locale = ctx.locale();
+    let ext = markup.extension();
+    let title = ctx.text("menu.title");
+    let mut page = Page::new(markup);
+    page.heading(1, title, None);
+    page.heading(2, ctx.text("export.contents"), None);
+    let items: Vec<Item> = TOPICS
+        .iter()
+        .map(|topic| Item {
+            href: format!("{}/index.{}", topic.id, ext),
+            text: topic.title_in(locale).to_string(),
+            note: topic.description_in(locale).to_string(),
+            children: registry::lessons_of(topic.id)
+                .map(|lesson| Item {
+                    href: format!("{}/{}.{}", topic.id, lesson.id, ext),
+                    text: lesson.title_in(locale).to_string(),
+                    note: lesson.description_in(locale).to_string(),
+                    children: Vec::new(),
+                })
+                .collect(),
+        })
+        .collect();
+    page.list(&items);
+    page.finish(ctx, title)
+}
+
+fn topic_page(ctx: &Ctx, markup: Markup, topic: &Topic) -> String {
+    let locale = ctx.locale();
+    let ext = markup.extension();
+    let title = topic.title_in(locale);
+    let mut page = Page::new(markup);
+    page.nav(&[(format!("../index.{}", ext), ctx.text("export.contents").to_string())]);
+    page.heading(1, title, None);
+    page.paragraph(topic.description_in(locale));
+    let items: Vec<Item> = registry::lessons_of(topic.id)
+        .map(|lesson| Item {
+            href: format!("{}.{}", lesson.id, ext),
+            text: lesson.title_in(locale).to_string(),
+            note: lesson.description_in(locale).to_string(),
+            children: Vec::new(),
+        })
+        .collect();
+    page.list(&items);
+    page.finish(ctx, title)
+}
+
+// 课程页：运行这一课，把输出放在产生它的小节后面；返回页面和这一课是否失败
+fn lesson_page(
+    ctx: &Ctx,
+    markup: Markup,
+    lesson: &'static Lesson,
+    previous: Option<&'static Lesson>,
+    next: Option<&'static Lesson>,
+) -> (String, bool) {
+    let locale = ctx.locale();
+    let ext = markup.extension();
+    let title = lesson.title_in(locale);
+    let topic = TOPICS.iter().find(|topic| topic.id == lesson.topic);
+    let link = |lesson: &Lesson| format!("../{}/{}.{}", lesson.topic, lesson.id, ext);
+
+    let mut page = Page::new(markup);
+    let mut trail = vec![(format!("../index.{}", ext), ctx.text("export.contents").to_string())];
+    if let Some(topic) = topic {
+        trail.push((format!("index.{}", ext), topic.title_in(locale).to_string()));
+    }
+    page.nav(&trail);
+    page.heading(1, title, None);
+    page.paragraph(lesson.description_in(locale));
+    let (docs, docs_end) = module_docs(lesson.source);
+    page.docs(&docs);
+    page.paragraph(&ctx.message("export.source", &[&lesson.file()]));
+
+    let sections = source::sections(lesson.source);
+    let (outputs, failure) = source::run_traced(ctx, lesson, &sections);
+    let contents: Vec<Item> = sections
+        .iter()
+        .filter_map(|section| {
+            let number = section.number?;
+            Some(Item {
+                href: format!("#{}", anchor(number)),
+                text: format!("§{} {}", number, section.title_in(locale).unwrap_or("")),
+                note: String::new(),
+                children: Vec::new(),
+            })
+        })
+        .collect();
+    if !contents.is_empty() {
+        page.heading(2, ctx.text("export.sections"), None);
+        page.list(&contents);
+    }
+
+    let lines: Vec<&str> = lesson.source.lines().collect();
+    for (k, (section, output)) in sections.iter().zip(&outputs).enumerate() {
+        page.heading(2, &section_heading(ctx, section, k), section.number.map(anchor).as_deref());
+        // 文档注释已经在页面开头显示过
+        let start = section.lines.start.max(docs_end).min(section.lines.end);
+        let code = lines[start..section.lines.end].join("\n");
+        if !code.trim().is_empty() {
+            page.code("rust", code.trim_end());
+        }
+        if !output.is_empty() {
+            page.paragraph(ctx.text("show.output"));
+            page.code("text", output.trim_end_matches('\n'));
+        }
+    }
+    if let Some(message) = &failure {
+        page.paragraph(message);
+    }
+
+    let mut around = Vec::new();
+    if let Some(lesson) = previous {
+        around.push((link(lesson), ctx.message("export.previous", &[&lesson.title_in(locale)])));
+    }
+    if let Some(lesson) = next {
+        around.push((link(lesson), ctx.message("export.next", &[&lesson.title_in(locale)])));
+    }
+    page.nav(&around);
+    (page.finish(ctx, title), failure.is_some())
+}
+
+// 编号小节用 "§3 标题"；第一个小节之前和 main 之后的代码没有编号
+fn section_heading(ctx: &Ctx, section: &Section, index: usize) -> String {
+    match (section.number, section.title_in(ctx.locale())) {
+        (Some(number), Some(title)) => format!("§{} {}", number, title),
+        (Some(number), None) => format!("§{}", number),
+        (None, _) if index == 0 => ctx.text("export.preamble").to_string(),
+        (None, _) => ctx.text("export.rest").to_string(),
+    }
+}
+
+fn anchor(number: usize) -> String {
+    format!("s{}", number)
+}
+
+// 课程文件开头的 //! 注释，去掉第一行标题（页面标题已经显示），
+// 同时返回注释和其后空行之后的第一行
+fn module_docs(source: &str) -> (String, usize) {
+    let lines: Vec<&str> = source.lines().collect();
+    let count = lines.iter().take_while(|line| line.starts_with("//!")).count();
+    let docs: Vec<&str> = lines[..count]
+        .iter()
+        .skip(1)
+        .map(|line| {
+            let text = &line["//!".len()..];
+            text.strip_prefix(' ').unwrap_or(text)
+        })
+        .skip_while(|line| line.is_empty())
+        .collect();
+    let end = count + lines[count..].iter().take_while(|line| line.is_empty()).count();
+    (docs.join("\n"), end)
+}
+
+// 列表中的一项：链接、说明和下一层
+struct Item {
+    href: String,
+    text: String,
+    note: String,
+    children: Vec<Item>,
+}
+
+// 正在生成的页面；同一套调用生成 Markdown 或 HTML
+struct Page {
+    markup: Markup,
+    body: String,
+}
+
+impl Page {
+    fn new(markup: Markup) -> Page {
+        Page {
+            markup,
+            body: String::new(),
+        }
+    }
+
+    fn heading(&mut self, level: usize, text: &str, id: Option<&str>) {
+        let text = &self.inline(text);
+        match (self.markup, id) {
+            (Markup::Markdown, None) => {
+                let _ = writeln!(self.body, "{} {}\n", "#".repeat(level), text);
+            }
+            // Markdown 没有统一的标题锚点规则，中文标题更是各不相同，所以显式写出锚点
+            (Markup::Markdown, Some(id)) => {
+                let _ = writeln!(self.body, "<a id=\"{}\"></a>\n\n{} {}\n", id, "#".repeat(level), text);
+            }
+            (Markup::Html, None) => {
+                let _ = writeln!(self.body, "<h{0}>{1}</h{0}>", level, text);
+            }
+            (Markup::Html, Some(id)) => {
+                let _ = writeln!(self.body, "<h{0} id=\"{1}\">{2}</h{0}>", level, id, text);
+            }
+        }
+    }
+
+    fn paragraph(&mut self, text: &str) {
+        let text = self.inline(text);
+        match self.markup {
+            Markup::Markdown => {
+                let _ = writeln!(self.body, "{}\n", text);
+            }
+            Markup::Html => {
+                let _ = writeln!(self.body, "<p>{}</p>", text);
+            }
+        }
+    }
+
+    // 标题、说明等普通文字：Markdown 中转义会被当作标记的字符（例如 Box<T> 会被当作 HTML 标签吞掉），
+    // HTML 中转义尖括号和 &
+    fn inline(&self, text: &str) -> String {
+        match self.markup {
+            Markup::Markdown => markdown_escape(text),
+            Markup::Html => escape(text),
+        }
+    }
+
+    // 一行导航链接；没有链接时什么也不写
+    fn nav(&mut self, links: &[(String, String)]) {
+        if links.is_empty() {
+            return;
+        }
+        let links: Vec<String> = links.iter().map(|(href, text)| self.link(href, text)).collect();
+        match self.markup {
+            Markup::Markdown => {
+                let _ = writeln!(self.body, "{}\n", links.join(" · "));
+            }
+            Markup::Html => {
+                let _ = writeln!(self.body, "<nav>{}</nav>", links.join(" · "));
+            }
+        }
+    }
+
+    fn link(&self, href: &str, text: &str) -> String {
+        match self.markup {
+            Markup::Markdown => format!("[{}]({})", markdown_escape(text), href),
+            Markup::Html => format!("<a href=\"{}\">{}</a>", escape(href), escape(text)),
+        }
+    }
+
+    fn list(&mut self, items: &[Item]) {
+        let mut list = String::new();
+        self.list_into(&mut list, items, 0);
+        self.body.push_str(&list);
+        if self.markup == Markup::Markdown {
+            self.body.push('\n');
+        }
+    }
+
+    fn list_into(&self, out: &mut String, items: &[Item], depth: usize) {
+        let indent = "  ".repeat(depth);
+        if self.markup == Markup::Html {
+            let _ = writeln!(out, "{}<ul>", indent);
+        }
+        for item in items {
+            let mut entry = self.link(&item.href, &item.text);
+            if !item.note.is_empty() {
+                let _ = write!(entry, " - {}", self.inline(&item.note));
+            }
+            match self.markup {
+                Markup::Markdown => {
+                    let _ = writeln!(out, "{}- {}", indent, entry);
+                    self.list_into(out, &item.children, depth + 1);
+                }
+                Markup::Html if item.children.is_empty() => {
+                    let _ = writeln!(out, "{}  <li>{}</li>", indent, entry);
+                }
+                Markup::Html => {
+                    let _ = writeln!(out, "{}  <li>{}", indent, entry);
+                    self.list_into(out, &item.children, depth + 1);
+                    let _ = writeln!(out, "{}  </li>", indent);
+                }
+            }
+        }
+        if self.markup == Markup::Html {
+            let _ = writeln!(out, "{}</ul>", indent);
+        }
+    }
+
+    fn code(&mut self, language: &str, text: &str) {
+        match self.markup {
+            Markup::Markdown => {
+                let fence = fence(text);
+                let _ = writeln!(self.body, "{}{}\n{}\n{}\n", fence, language, text, fence);
+            }
+            Markup::Html => {
+                let _ = writeln!(
+                    self.body,
+                    "<pre class=\"{}\"><code>{}</code></pre>",
+                    language,
+                    escape(text)
+                );
+            }
+        }
+    }
+
+    // 模块文档本身就是 Markdown；HTML 只转换其中用到的段落、列表和行内代码
+    fn docs(&mut self, docs: &str) {
+        if docs.is_empty() {
+            return;
+        }
+        if self.markup == Markup::Markdown {
+            let _ = writeln!(self.body, "{}\n", docs);
+            return;
+        }
+        for block in docs.split("\n\n") {
+            let mut open: Option<&str> = None;
+            let mut paragraph: Vec<String> = Vec::new();
+            for line in block.lines() {
+                let (tag, text) = match list_item(line) {
+                    Some((tag, text)) => (Some(tag), text),
+                    None => (None, line),
+                };
+                if tag.is_some() || open.is_some() {
+                    flush_paragraph(&mut self.body, &mut paragraph);
+                }
+                if open != tag {
+                    if let Some(open) = open {
+                        let _ = writeln!(self.body, "</{}>", open);
+                    }
+                    if let Some(tag) = tag {
+                        let _ = writeln!(self.body, "<{}>", tag);
+                    }
+                    open = tag;
+                }
+                match tag {
+                    Some(_) => {
+                        let _ = writeln!(self.body, "  <li>{}</li>", inline_code(text));
+                    }
+                    None => paragraph.push(inline_code(text)),
+                }
+            }
+            flush_paragraph(&mut self.body, &mut paragraph);
+            if let Some(open) = open {
+                let _ = writeln!(self.body, "</{}>", open);
+            }
+        }
+    }
+
+    fn finish(self, ctx: &Ctx, title: &str) -> String {
+        match self.markup {
+            Markup::Markdown => format!("{}\n", self.body.trim_end()),
+            Markup::Html => format!(
+                "<!DOCTYPE html>\n<html lang=\"{}\">\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n<style>{}</style>\n</head>\n<body>\n{}</body>\n</html>\n",
+                ctx.locale().tag(),
+                escape(title),
+                STYLE,
+                self.body
+            ),
+        }
+    }
+}
+
+const STYLE: &str = "
+body { max-width: 60rem; margin: 2rem auto; padding: 0 1rem; font-family: sans-serif; line-height: 1.6; }
+pre { padding: 0.75rem; overflow-x: auto; border-radius: 4px; }
+pre.rust { background: #f6f8fa; }
+pre.text { background: #1e1e1e; color: #d4d4d4; }
+code { font-family: monospace; }
+nav { margin: 1rem 0; }
+";
+
+// "- 文字" 或 "1. 文字" -> 列表标签和文字
+fn list_item(line: &str) -> Option<(&'static str, &str)> {
+    if let Some(text) = line.strip_prefix("- ") {
+        return Some(("ul", text));
+    }
+    let (number, text) = line.split_once(". ")?;
+    if !number.is_empty() && number.bytes().all(|b| b.is_ascii_digit()) {
+        Some(("ol", text))
+    } else {
+        None
+    }
+}
+
+fn flush_paragraph(body: &mut String, paragraph: &mut Vec<String>) {
+    if !paragraph.is_empty() {
+        let _ = writeln!(body, "<p>{}</p>", paragraph.join("\n"));
+        paragraph.clear();
+    }
+}
+
+// 转义后把 `代码` 换成 <code>
+fn inline_code(text: &str) -> String {
+    let mut out = String::new();
+    for (i, part) in escape(text).split('`').enumerate() {
+        if i % 2 == 1 {
+            let _ = write!(out, "<code>{}</code>", part);
+        } else {
+            out.push_str(part);
+        }
+    }
+    out
+}
+
+// 比内容中最长的一串反引号多一个，至少三个
+fn fence(text: &str) -> String {
+    let longest = text
+        .split(|c| c != '`')
+        .map(str::len)
+        .max()
+        .unwrap_or(0);
+    "`".repeat((longest + 1).max(3))
+}
+
+// Markdown 的反斜杠转义：只转义可能被当作标记的 ASCII 标点，中文和其他字符原样保留
+fn markdown_escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '\\' | '<' | '>' | '&' | '[' | ']' | '*' | '_' | '`') {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out
+}
+
+fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            c => out.push(c),
+        }
+    }
+    out
+}
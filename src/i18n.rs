@@ -0,0 +1,421 @@
+//! 界面语言
+//!
+//! 菜单、运行器、命令行提示和各课标题的文字都放在下面的消息目录中，
+//! 每种语言一份，按键查找。课程正文中的讲解仍然只有中文。
+//!
+//! 语言来自 `--lang` 参数或 `LANG` 环境变量，都没有时使用中文。
+
+use std::env;
+use std::fmt::{self, Write};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Locale {
+    #[default]
+    ZhCn,
+    EnUs,
+}
+
+impl Locale {
+    pub const ALL: [Locale; 2] = [Locale::ZhCn, Locale::EnUs];
+
+    // 解析 "zh-CN"、"en"、"en_US.UTF-8" 这类写法
+    pub fn parse(tag: &str) -> Option<Locale> {
+        let tag = tag.split('.').next().unwrap_or("").to_lowercase().replace('_', "-");
+        match tag.split('-').next() {
+            Some("zh") => Some(Locale::ZhCn),
+            Some("en") => Some(Locale::EnUs),
+            _ => None,
+        }
+    }
+
+    // 从 LANG 环境变量推断；未设置或无法识别时使用默认语言
+    pub fn from_env() -> Locale {
+        env::var("LANG")
+            .ok()
+            .and_then(|lang| Locale::parse(&lang))
+            .unwrap_or_default()
+    }
+
+    pub fn tag(self) -> &'static str {
+        match self {
+            Locale::ZhCn => "zh-CN",
+            Locale::EnUs => "en-US",
+        }
+    }
+
+    fn catalogue(self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Locale::ZhCn => ZH_CN,
+            Locale::EnUs => EN_US,
+        }
+    }
+
+    // 目录中的所有键，按目录中的顺序
+    pub fn keys(self) -> impl Iterator<Item = &'static str> {
+        self.catalogue().iter().map(|(key, _)| *key)
+    }
+
+    // 查找一条消息；目录中没有这个键时原样返回键，便于发现遗漏
+    pub fn text(self, key: &'static str) -> &'static str {
+        self.catalogue()
+            .iter()
+            .find(|(k, _)| *k == key)
+            .map_or(key, |(_, text)| *text)
+    }
+
+    // 查找一条消息，并依次用参数替换其中的 {}
+    pub fn message(self, key: &'static str, args: &[&dyn fmt::Display]) -> String {
+        fill(self.text(key), args)
+    }
+}
+
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.tag())
+    }
+}
+
+// 依次用参数替换模板中的 {}；参数不够时剩下的 {} 替换为空
+pub fn fill(template: &str, args: &[&dyn fmt::Display]) -> String {
+    let mut out = String::new();
+    let mut args = args.iter();
+    for (i, part) in template.split("{}").enumerate() {
+        if i > 0
+            && let Some(arg) = args.next()
+        {
+            let _ = write!(out, "{}", arg);
+        }
+        out.push_str(part);
+    }
+    out
+}
+
+static ZH_CN: &[(&str, &str)] = &[
+    // 命令行
+    ("cli.usage", "\
+用法:
+  rust_study                     进入交互式菜单
+  rust_study run <topic> [<lesson>]
+                                 运行一个主题，或主题中的某一课
+  rust_study run <topic>/<lesson>
+  rust_study run <lesson>        按路径或课程名（可模糊匹配）运行一课
+  rust_study list                列出所有主题和课程
+  rust_study all                 运行所有示例
+  rust_study quiz [<topic>]      小测验，可以只考一个主题或一课
+  rust_study check [<exercise>]  检查练习的答案，不带参数时列出所有练习
+  rust_study progress [reset|export]
+                                 查看、清空或以 JSON 导出学习进度
+  rust_study show <lesson> [--with-output]
+                                 显示课程源码，--with-output 时把输出插在对应小节后面
+  rust_study search <term>       在所有课程的标题、小节、代码和讲解中搜索
+  rust_study export [<dir>] [--html]
+                                 把所有课程导出为静态网站（默认 Markdown，目录 site）
+  rust_study help                显示本帮助
+
+选项:
+  -o, --output <file>            把输出写入文件
+  --keep-workspace               保留本次运行的临时工作目录（每课一个子目录），便于查看
+  --step                         单步运行：课程的每一节结束后暂停，回车继续
+  --format <text|json>           run 和 all 的输出格式，json 输出运行报告
+  --lang <zh-CN|en-US>           界面语言（默认取自 LANG 环境变量）"),
+    ("cli.error", "错误: {}"),
+    ("cli.create_output_failed", "无法创建输出文件 {}: {}"),
+    ("cli.write_failed", "写入输出失败: {}"),
+    ("cli.unknown_command", "未知命令: {}"),
+    ("cli.missing_topic", "run 需要指定主题"),
+    ("cli.missing_lesson", "show 需要指定课程"),
+    ("cli.missing_term", "search 需要一个要搜索的词"),
+    ("cli.unexpected_argument", "多余的参数: {}"),
+    ("cli.missing_value", "{} 需要一个值"),
+    ("cli.unknown_locale", "不支持的语言: {}（可选: zh-CN, en-US）"),
+    ("cli.unknown_format", "不支持的输出格式: {}（可选: text, json）"),
+    ("cli.format_not_supported", "--format json 只能用于 run 和 all"),
+    // 名称查找
+    ("resolve.not_found", "找不到主题或课程: {}"),
+    ("resolve.suggestions", "（你是不是想找: {}）"),
+    ("resolve.ambiguous", "{} 匹配到多个目标: {}"),
+    // 运行器
+    ("runner.topic", "运行{}"),
+    ("runner.done", "所有示例运行完成"),
+    ("runner.workspace_kept", "（工作目录已保留: {}）"),
+    ("runner.lesson_panicked", "!!! 本课发生 panic: {}"),
+    ("runner.lesson_failed", "!!! 本课出错: {}"),
+    ("runner.unknown_panic", "（panic 载荷不是字符串）"),
+    ("runner.summary", "运行结果"),
+    ("runner.column_lesson", "课程"),
+    ("runner.column_time", "耗时"),
+    ("runner.column_status", "状态"),
+    ("runner.passed", "通过"),
+    ("runner.panicked", "panic: {}"),
+    ("runner.failed", "出错: {}"),
+    ("runner.totals", "共 {} 课：{} 通过，{} 失败"),
+    ("step.pause", "—— §{} {} 结束。回车继续，q 退出单步模式 ——"),
+    // 交互式菜单
+    ("menu.title", "Rust 学习项目"),
+    ("menu.prompt", "选择要运行的示例（也可以输入课程名，如 traits）："),
+    ("menu.entry", "{}（{}）"),
+    ("menu.separator", "、"),
+    ("menu.run_all", "运行所有示例"),
+    ("menu.quit", "退出"),
+    ("menu.run_topic", "运行本主题全部课程"),
+    ("menu.show_source", "s<编号>. 查看课程源码（例如 s1）"),
+    ("menu.back", "返回上级菜单"),
+    ("menu.commands", "r. 重新运行上一次  h. 历史  b. 返回  q. 退出"),
+    ("menu.nothing_to_rerun", "还没有运行过任何示例"),
+    ("menu.invalid", "无效选择: {}"),
+    ("menu.history_empty", "本次会话还没有运行过任何示例"),
+    ("menu.history", "本次会话运行过："),
+    ("menu.goodbye", "再见！"),
+    ("menu.done", " ✓"),
+    // 学习进度
+    ("progress.title", "学习进度（保存在 {}）："),
+    ("progress.topic", "{} {}%  课程 {}/{}  练习 {}/{}  测验 {}/{}"),
+    ("progress.last", "最近一次学习：{}"),
+    ("progress.empty", "还没有完成任何内容"),
+    ("progress.reset", "已清空学习进度（{}）"),
+    ("progress.disabled", "没有记录学习进度：找不到用户配置目录，或 RUST_STUDY_PROGRESS 为空"),
+    ("progress.load_failed", "无法读取学习进度 {}: {}"),
+    ("progress.save_failed", "无法保存学习进度 {}: {}（本次运行不再记录）"),
+    // 小测验
+    ("quiz.prompt", "你的答案（a-{}，q 交卷）："),
+    ("quiz.invalid", "无效答案: {}"),
+    ("quiz.correct", "回答正确！"),
+    ("quiz.wrong", "回答错误，正确答案是 {}。"),
+    ("quiz.actual_output", "实际输出："),
+    ("quiz.explanation", "解释：{}"),
+    ("quiz.see_lesson", "相关课程：rust_study run {}"),
+    ("quiz.score", "得分：{}/{}"),
+    ("quiz.empty", "这里还没有题目"),
+    // 练习检查
+    ("check.heading", "练习 {}：{}"),
+    ("check.not_found", "找不到练习: {}"),
+    ("check.passed", "  [通过]   {}"),
+    ("check.failed", "  [失败]   {}：{}"),
+    ("check.mismatch", "期望 {}，实际得到 {}"),
+    ("check.panicked", "  [panic]  {}：{}"),
+    ("check.todo", "  [未完成] {}：函数体还是 todo!()"),
+    ("check.hint", "           提示：{}"),
+    ("check.totals", "共 {} 项：{} 通过，{} 未通过"),
+    ("check.see_lesson", "复习相关课程：rust_study run {}"),
+    ("check.list_title", "练习（用 rust_study check <练习> 检查）："),
+    // 课程源码
+    ("show.title", "{}（{}）"),
+    ("show.output", "输出："),
+    // 搜索
+    ("search.none", "没有找到 {}"),
+    ("search.more", "……还有 {} 处结果没有显示"),
+    // 导出网站
+    ("export.contents", "目录"),
+    ("export.sections", "小节"),
+    ("export.source", "源码：{}"),
+    ("export.preamble", "准备"),
+    ("export.rest", "其余代码"),
+    ("export.previous", "上一课：{}"),
+    ("export.next", "下一课：{}"),
+    ("export.lesson_failed", "{} 运行失败，页面中记录了原因"),
+    ("export.done", "已导出 {} 课（共 {} 页），从 {} 开始阅读"),
+    // 各课标题
+    ("lesson.functions", "函数示例"),
+    ("lesson.if_else", "if-else 条件语句"),
+    ("lesson.loops.loop", "loop 循环"),
+    ("lesson.loops.while", "while 循环"),
+    ("lesson.loops.for", "for 循环"),
+    ("lesson.loops.control", "循环控制"),
+    ("lesson.loops.iterators", "迭代器方法"),
+    ("lesson.ownership_basics", "所有权基础"),
+    ("lesson.references_borrowing", "引用和借用"),
+    ("lesson.structs", "结构体基础"),
+    ("lesson.enums", "枚举基础"),
+    ("lesson.pattern_matching", "模式匹配基础"),
+    ("lesson.vectors", "向量基础"),
+    ("lesson.strings", "字符串基础"),
+    ("lesson.hashmaps", "哈希映射基础"),
+    ("lesson.modules", "模块系统示例"),
+    ("lesson.modules.visibility", "可见性测试"),
+    ("lesson.error_handling", "错误处理示例"),
+    ("lesson.advanced_error_handling", "高级错误处理示例"),
+    ("lesson.generics", "泛型基础"),
+    ("lesson.traits", "Trait 基础"),
+    ("lesson.lifetimes", "生命周期基础"),
+    ("lesson.threads", "线程基础"),
+    ("lesson.channels", "通道基础"),
+    ("lesson.shared_state", "共享状态基础"),
+    ("lesson.atomics", "原子类型基础"),
+    ("lesson.box_pointers", "Box<T> 基础"),
+    ("lesson.rc", "Rc<T> 引用计数"),
+    ("lesson.refcell", "RefCell<T> 与内部可变性"),
+    ("lesson.weak", "Weak<T> 与引用循环"),
+    ("lesson.closures", "闭包基础"),
+    ("lesson.iterator_basics", "迭代器基础"),
+    ("lesson.custom_adapters", "自定义迭代器适配器"),
+    ("lesson.futures", "Future 基础"),
+    ("lesson.executor", "执行器基础"),
+    ("lesson.timers", "定时器和 join"),
+    ("lesson.declarative", "声明宏基础"),
+    ("lesson.derive", "派生宏基础"),
+];
+
+static EN_US: &[(&str, &str)] = &[
+    // 命令行
+    ("cli.usage", "\
+Usage:
+  rust_study                     start the interactive menu
+  rust_study run <topic> [<lesson>]
+                                 run a whole topic, or one lesson of it
+  rust_study run <topic>/<lesson>
+  rust_study run <lesson>        run one lesson by path or (fuzzy) name
+  rust_study list                list all topics and lessons
+  rust_study all                 run every example
+  rust_study quiz [<topic>]      take a quiz on every topic, one topic or one lesson
+  rust_study check [<exercise>]  check an exercise; without one, list them all
+  rust_study progress [reset|export]
+                                 show, clear or export (as JSON) your progress
+  rust_study show <lesson> [--with-output]
+                                 show a lesson's source; --with-output puts the output after each section
+  rust_study search <term>       search titles, sections, code and narration of every lesson
+  rust_study export [<dir>] [--html]
+                                 export every lesson as a static site (Markdown by default, into site)
+  rust_study help                show this help
+
+Options:
+  -o, --output <file>            write output to a file
+  --keep-workspace               keep this run's temporary workspace (one subdirectory per lesson)
+  --step                         pause after each section of a lesson; Enter continues
+  --format <text|json>           output format of run and all; json prints a report
+  --lang <zh-CN|en-US>           interface language (defaults to $LANG)"),
+    ("cli.error", "error: {}"),
+    ("cli.create_output_failed", "cannot create output file {}: {}"),
+    ("cli.write_failed", "failed to write output: {}"),
+    ("cli.unknown_command", "unknown command: {}"),
+    ("cli.missing_topic", "run needs a topic"),
+    ("cli.missing_lesson", "show needs a lesson"),
+    ("cli.missing_term", "search needs a term to look for"),
+    ("cli.unexpected_argument", "unexpected argument: {}"),
+    ("cli.missing_value", "{} needs a value"),
+    ("cli.unknown_locale", "unsupported language: {} (choose zh-CN or en-US)"),
+    ("cli.unknown_format", "unsupported output format: {} (choose text or json)"),
+    ("cli.format_not_supported", "--format json only works with run and all"),
+    // 名称查找
+    ("resolve.not_found", "no topic or lesson named: {}"),
+    ("resolve.suggestions", " (did you mean: {}?)"),
+    ("resolve.ambiguous", "{} matches several targets: {}"),
+    // 运行器
+    ("runner.topic", "Running {}"),
+    ("runner.done", "All examples finished"),
+    ("runner.workspace_kept", "(workspace kept at {})"),
+    ("runner.lesson_panicked", "!!! lesson panicked: {}"),
+    ("runner.lesson_failed", "!!! lesson failed: {}"),
+    ("runner.unknown_panic", "(non-string panic payload)"),
+    ("runner.summary", "Summary"),
+    ("runner.column_lesson", "Lesson"),
+    ("runner.column_time", "Time"),
+    ("runner.column_status", "Status"),
+    ("runner.passed", "passed"),
+    ("runner.panicked", "panicked: {}"),
+    ("runner.failed", "failed: {}"),
+    ("runner.totals", "{} lessons: {} passed, {} failed"),
+    ("step.pause", "—— end of §{} {}. Press Enter to continue, q to stop stepping ——"),
+    // 交互式菜单
+    ("menu.title", "Rust Study"),
+    ("menu.prompt", "Choose an example to run (or type a lesson name, e.g. traits):"),
+    ("menu.entry", "{} ({})"),
+    ("menu.separator", ", "),
+    ("menu.run_all", "Run all examples"),
+    ("menu.quit", "Quit"),
+    ("menu.run_topic", "Run every lesson in this topic"),
+    ("menu.show_source", "s<number>. Show a lesson's source (e.g. s1)"),
+    ("menu.back", "Back to the main menu"),
+    ("menu.commands", "r. rerun last  h. history  b. back  q. quit"),
+    ("menu.nothing_to_rerun", "Nothing has been run yet"),
+    ("menu.invalid", "Invalid choice: {}"),
+    ("menu.history_empty", "Nothing has been run in this session yet"),
+    ("menu.history", "Run in this session:"),
+    ("menu.goodbye", "Goodbye!"),
+    ("menu.done", " ✓"),
+    // 学习进度
+    ("progress.title", "Progress (saved in {}):"),
+    ("progress.topic", "{} {}%  lessons {}/{}  exercises {}/{}  quiz {}/{}"),
+    ("progress.last", "Last studied: {}"),
+    ("progress.empty", "Nothing completed yet"),
+    ("progress.reset", "Progress cleared ({})"),
+    ("progress.disabled", "Progress is not recorded: no user config directory, or RUST_STUDY_PROGRESS is empty"),
+    ("progress.load_failed", "cannot read progress file {}: {}"),
+    ("progress.save_failed", "cannot save progress to {}: {} (no longer recording in this run)"),
+    // 小测验
+    ("quiz.prompt", "Your answer (a-{}, q to finish): "),
+    ("quiz.invalid", "invalid answer: {}"),
+    ("quiz.correct", "Correct!"),
+    ("quiz.wrong", "Wrong, the answer is {}."),
+    ("quiz.actual_output", "Actual output:"),
+    ("quiz.explanation", "Explanation: {}"),
+    ("quiz.see_lesson", "Related lesson: rust_study run {}"),
+    ("quiz.score", "Score: {}/{}"),
+    ("quiz.empty", "There are no questions here yet"),
+    // 练习检查
+    ("check.heading", "Exercise {}: {}"),
+    ("check.not_found", "no exercise named: {}"),
+    ("check.passed", "  [pass]   {}"),
+    ("check.failed", "  [fail]   {}: {}"),
+    ("check.mismatch", "expected {}, got {}"),
+    ("check.panicked", "  [panic]  {}: {}"),
+    ("check.todo", "  [todo]   {}: the function body is still todo!()"),
+    ("check.hint", "           hint: {}"),
+    ("check.totals", "{} cases: {} passed, {} not passed"),
+    ("check.see_lesson", "Review the lesson: rust_study run {}"),
+    ("check.list_title", "Exercises (check one with rust_study check <exercise>):"),
+    // 课程源码
+    ("show.title", "{} ({})"),
+    ("show.output", "Output:"),
+    // 搜索
+    ("search.none", "No results for {}"),
+    ("search.more", "... {} more results not shown"),
+    // 导出网站
+    ("export.contents", "Contents"),
+    ("export.sections", "Sections"),
+    ("export.source", "Source: {}"),
+    ("export.preamble", "Setup"),
+    ("export.rest", "Remaining code"),
+    ("export.previous", "Previous: {}"),
+    ("export.next", "Next: {}"),
+    ("export.lesson_failed", "{} failed to run; the page records why"),
+    ("export.done", "Exported {} lessons ({} pages); start reading at {}"),
+    // 各课标题
+    ("lesson.functions", "Functions"),
+    ("lesson.if_else", "if-else conditionals"),
+    ("lesson.loops.loop", "loop"),
+    ("lesson.loops.while", "while loops"),
+    ("lesson.loops.for", "for loops"),
+    ("lesson.loops.control", "Loop control"),
+    ("lesson.loops.iterators", "Iterator methods"),
+    ("lesson.ownership_basics", "Ownership basics"),
+    ("lesson.references_borrowing", "References and borrowing"),
+    ("lesson.structs", "Struct basics"),
+    ("lesson.enums", "Enum basics"),
+    ("lesson.pattern_matching", "Pattern matching basics"),
+    ("lesson.vectors", "Vector basics"),
+    ("lesson.strings", "String basics"),
+    ("lesson.hashmaps", "Hash map basics"),
+    ("lesson.modules", "The module system"),
+    ("lesson.modules.visibility", "Visibility test"),
+    ("lesson.error_handling", "Error handling"),
+    ("lesson.advanced_error_handling", "Advanced error handling"),
+    ("lesson.generics", "Generics basics"),
+    ("lesson.traits", "Trait basics"),
+    ("lesson.lifetimes", "Lifetime basics"),
+    ("lesson.threads", "Thread basics"),
+    ("lesson.channels", "Channel basics"),
+    ("lesson.shared_state", "Shared state basics"),
+    ("lesson.atomics", "Atomics basics"),
+    ("lesson.box_pointers", "Box<T> basics"),
+    ("lesson.rc", "Rc<T> reference counting"),
+    ("lesson.refcell", "RefCell<T> and interior mutability"),
+    ("lesson.weak", "Weak<T> and reference cycles"),
+    ("lesson.closures", "Closure basics"),
+    ("lesson.iterator_basics", "Iterator basics"),
+    ("lesson.custom_adapters", "Custom iterator adapters"),
+    ("lesson.futures", "Future basics"),
+    ("lesson.executor", "Executor basics"),
+    ("lesson.timers", "Timers and join"),
+    ("lesson.declarative", "Declarative macros"),
+    ("lesson.derive", "Derive macros"),
+];
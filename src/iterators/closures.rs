@@ -0,0 +1,168 @@
+//! 闭包（Closures）
+//!
+//! 闭包是可以捕获所在环境的匿名函数。编译器根据闭包体如何使用捕获的值，
+//! 自动为它实现下面一个或多个 trait：
+//! - `FnOnce`：会拿走捕获值的所有权，只能调用一次
+//! - `FnMut`：会修改捕获的值，可以调用多次
+//! - `Fn`：只读取捕获的值，可以调用多次，也可以同时被多处调用
+//!
+//! 三者是包含关系：实现 `Fn` 的闭包也实现了 `FnMut` 和 `FnOnce`。
+//! `move` 关键字只决定捕获的方式（移动还是借用），不决定闭包实现哪个 trait。
+
+use std::collections::HashMap;
+use std::io::{self, Write};
+
+use crate::context::Ctx;
+
+pub fn main(ctx: &mut Ctx) -> io::Result<()> {
+    writeln!(ctx, "=== {} ===", ctx.text("lesson.closures"))?;
+
+    ctx.section(1, "闭包的写法和类型推断", "Closure syntax and type inference")?;
+    fn add_one_fn(x: u32) -> u32 {
+        x + 1
+    }
+    let add_one_full = |x: u32| -> u32 { x + 1 };
+    let add_one = |x| x + 1;
+    writeln!(ctx, "函数: {}，完整写法: {}，省略写法: {}", add_one_fn(1), add_one_full(1), add_one(1u32))?;
+    // 闭包的参数类型在第一次调用时确定，之后不能换成别的类型
+    let identity = |x| x;
+    let s = identity(String::from("hello"));
+    // let n = identity(5); // 错误：期望 String，得到整数
+    writeln!(ctx, "identity: {}", s)?;
+
+    ctx.section(2, "三种捕获方式", "Three ways to capture")?;
+    let list = vec![1, 2, 3];
+    // 只读：不可变借用 list
+    let only_borrows = || list.len();
+    writeln!(ctx, "不可变借用: 长度 {}，list 仍然可用: {:?}", only_borrows(), list)?;
+    // 修改：可变借用，闭包存活期间不能再借用 list
+    let mut list = list;
+    let mut borrows_mutably = || list.push(4);
+    borrows_mutably();
+    borrows_mutably();
+    writeln!(ctx, "可变借用之后: {:?}", list)?;
+    // 拿走所有权：闭包体中移动了 list
+    let consumes = move || list.into_iter().sum::<i32>();
+    writeln!(ctx, "移动进闭包后求和: {}", consumes())?;
+    // writeln!(ctx, "{:?}", list); // 错误：list 已经被移动
+
+    ctx.section(3, "FnOnce、FnMut 和 Fn", "FnOnce, FnMut and Fn")?;
+    let name = String::from("Rust");
+    let greet = || format!("你好，{}", name);
+    writeln!(ctx, "Fn 可以调用多次: {} / {}", call_twice(&greet), call_once(greet))?;
+    let mut total = 0;
+    let mut add = |x| total += x;
+    call_mut(&mut add, 3);
+    call_mut(&mut add, 4);
+    writeln!(ctx, "FnMut 累加后 total = {}", total)?;
+    let message = String::from("只能用一次");
+    let take = move || message;
+    writeln!(ctx, "FnOnce 返回捕获的值: {}", call_once(take))?;
+    // 错误：take 已经在第一次调用时交出了 message（见 tests/compile_fail/fn_once_twice.rs）
+    // call_once(take);
+
+    ctx.section(4, "标准库如何选择闭包 trait", "How the standard library picks a closure trait")?;
+    // unwrap_or_else 只调用一次，接受 FnOnce
+    let fallback = String::from("默认值");
+    let value: Option<String> = None;
+    writeln!(ctx, "unwrap_or_else: {}", value.unwrap_or_else(|| fallback))?;
+    // sort_by_key 对每个元素调用一次，接受 FnMut，可以顺便计数
+    let mut words = vec!["pear", "fig", "banana", "kiwi"];
+    let mut calls = 0;
+    words.sort_by_key(|word| {
+        calls += 1;
+        word.len()
+    });
+    writeln!(ctx, "按长度排序: {:?}，key 函数被调用过: {}", words, calls > 0)?;
+
+    ctx.section(5, "move 闭包", "move closures")?;
+    let prefix = String::from("item");
+    // 返回的闭包比 prefix 活得久，必须用 move 把它移动进去
+    let label = make_labeler(prefix);
+    writeln!(ctx, "{} {}", label(1), label(2))?;
+    // move 只改变捕获方式：这个闭包拿走了 count 的副本，修改不影响外面的 count
+    let mut count = 0;
+    let mut inc = move || {
+        count += 1;
+        count
+    };
+    inc();
+    writeln!(ctx, "闭包内部: {}，外部的 count: {}", inc(), count)?;
+
+    ctx.section(6, "返回闭包：impl Fn 和 Box<dyn Fn>", "Returning closures: impl Fn and Box<dyn Fn>")?;
+    let double = multiplier(2);
+    writeln!(ctx, "impl Fn: double(21) = {}", double(21))?;
+    // 不同闭包的类型各不相同，放进同一个向量需要装箱
+    let operations: Vec<(&str, Box<dyn Fn(i32) -> i32>)> = vec![
+        ("加 10", Box::new(|x| x + 10)),
+        ("平方", Box::new(|x| x * x)),
+        ("乘 3", Box::new(multiplier(3))),
+    ];
+    for (name, operation) in &operations {
+        writeln!(ctx, "{}: 7 -> {}", name, operation(7))?;
+    }
+
+    ctx.section(7, "组合闭包", "Composing closures")?;
+    let add_then_double = compose(|x: i32| x + 1, |x| x * 2);
+    writeln!(ctx, "compose(加一, 乘二)(5) = {}", add_then_double(5))?;
+    let pipeline = operations.iter().fold(0, |value, (_, operation)| operation(value));
+    writeln!(ctx, "依次应用所有操作: 0 -> {}", pipeline)?;
+
+    ctx.section(8, "闭包和缓存", "Closures and caching")?;
+    let mut calls = 0;
+    let mut cache = Memo::new(|n: u64| {
+        calls += 1;
+        (1..=n).product::<u64>()
+    });
+    let results = [cache.get(10), cache.get(5), cache.get(10), cache.get(5)];
+    drop(cache);
+    writeln!(ctx, "结果: {:?}，实际计算了 {} 次", results, calls)?;
+
+    Ok(())
+}
+
+fn call_once<F: FnOnce() -> String>(f: F) -> String {
+    f()
+}
+
+fn call_mut<F: FnMut(i32)>(f: &mut F, x: i32) {
+    f(x)
+}
+
+fn call_twice<F: Fn() -> String>(f: &F) -> String {
+    format!("{} {}", f(), f())
+}
+
+fn make_labeler(prefix: String) -> impl Fn(u32) -> String {
+    move |n| format!("{}-{}", prefix, n)
+}
+
+pub fn multiplier(factor: i32) -> impl Fn(i32) -> i32 {
+    move |x| x * factor
+}
+
+// 先调用 f 再调用 g
+pub fn compose<A, B, C>(f: impl Fn(A) -> B, g: impl Fn(B) -> C) -> impl Fn(A) -> C {
+    move |x| g(f(x))
+}
+
+// 记住每个参数的结果，同样的参数只计算一次
+struct Memo<F: FnMut(u64) -> u64> {
+    compute: F,
+    values: HashMap<u64, u64>,
+}
+
+impl<F: FnMut(u64) -> u64> Memo<F> {
+    fn new(compute: F) -> Memo<F> {
+        Memo { compute, values: HashMap::new() }
+    }
+
+    fn get(&mut self, n: u64) -> u64 {
+        if let Some(value) = self.values.get(&n) {
+            return *value;
+        }
+        let value = (self.compute)(n);
+        self.values.insert(n, value);
+        value
+    }
+}
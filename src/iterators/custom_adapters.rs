@@ -0,0 +1,334 @@
+//! 自定义迭代器适配器
+//!
+//! 标准库的适配器都是普通的结构体：保存内层迭代器，在自己的 `next` 中调用内层的 `next`。
+//! 自己写适配器也是同样的做法，再用一个扩展 trait（extension trait）为所有迭代器
+//! 加上对应的方法，就能像 `map`、`filter` 一样链式调用。
+//!
+//! 本课实现一个小型适配器库（`pairs`、`dedup`、`chunked`、`interleave`），
+//! 然后把几个任务分别用手写循环和迭代器链实现，比较两种写法。
+//! 迭代器链在优化后通常编译成与循环相同的机器码，选择哪种写法主要看可读性。
+
+use std::collections::HashMap;
+use std::io::{self, Write};
+use std::iter::{self, Peekable};
+
+use crate::advanced::traits::Countdown;
+use crate::context::Ctx;
+
+pub fn main(ctx: &mut Ctx) -> io::Result<()> {
+    writeln!(ctx, "=== {} ===", ctx.text("lesson.custom_adapters"))?;
+
+    ctx.section(1, "适配器就是包装了迭代器的结构体", "An adapter is a struct wrapping an iterator")?;
+    // 直接构造结构体也能用，但写起来很啰嗦
+    let pairs: Vec<(i32, i32)> = Pairs::new([1, 2, 3, 4].into_iter()).collect();
+    writeln!(ctx, "Pairs::new: {:?}", pairs)?;
+
+    ctx.section(2, "扩展 trait：给所有迭代器加上方法", "Extension traits: adding methods to every iterator")?;
+    let readings = [20, 22, 21, 25];
+    let deltas: Vec<i32> = readings.iter().pairs().map(|(a, b)| b - a).collect();
+    writeln!(ctx, "相邻读数的差: {:?}", deltas)?;
+    let deduped: Vec<char> = "aabbbcdda".chars().dedup().collect();
+    writeln!(ctx, "dedup: {:?}", deduped)?;
+    let chunks: Vec<Vec<i32>> = (1..=7).chunked(3).collect();
+    writeln!(ctx, "chunked(3): {:?}", chunks)?;
+    let mixed: Vec<&str> = ["a", "b", "c"].into_iter().interleave(["1", "2"]).collect();
+    writeln!(ctx, "interleave: {:?}", mixed)?;
+
+    ctx.section(3, "和标准适配器自由组合", "Mixing freely with the standard adapters")?;
+    // Countdown 是手写的迭代器，同样获得了扩展方法
+    let report: Vec<String> = Countdown { count: 6 }
+        .chunked(2)
+        .map(|chunk| chunk.iter().sum::<i32>())
+        .pairs()
+        .map(|(a, b)| format!("{}->{}", a, b))
+        .collect();
+    writeln!(ctx, "Countdown 分块求和后两两比较: {:?}", report)?;
+    let squeezed: String = "hello    world  !".chars().dedup().collect();
+    writeln!(ctx, "压缩重复字符: {}", squeezed)?;
+
+    ctx.section(4, "适配器仍然是惰性的", "Adapters are still lazy")?;
+    let mut pulled = 0;
+    let first = iter::repeat_with(|| {
+        pulled += 1;
+        pulled
+    })
+    .chunked(3)
+    .next();
+    writeln!(ctx, "无限迭代器分块，只取第一块: {:?}，拉取了 {} 个元素", first, pulled)?;
+
+    ctx.section(5, "传递 size_hint 和 ExactSizeIterator", "Forwarding size_hint and ExactSizeIterator")?;
+    let chunked = Countdown { count: 7 }.chunked(3);
+    writeln!(ctx, "7 个元素按 3 个一块: len = {}", chunked.len())?;
+    let pairs = [1, 2, 3, 4].iter().pairs();
+    writeln!(ctx, "4 个元素的 pairs: size_hint = {:?}", pairs.size_hint())?;
+    // 内层是 filter，只有上限时外层也只能给出范围
+    let unknown = (1..=10).filter(|n| n % 3 == 0).chunked(2);
+    writeln!(ctx, "filter 之后 chunked: size_hint = {:?}", unknown.size_hint())?;
+
+    ctx.section(6, "不写结构体：用闭包构造迭代器", "No struct needed: building an iterator from a closure")?;
+    let powers: Vec<u32> = iter::successors(Some(1u32), |n| n.checked_mul(10)).collect();
+    writeln!(ctx, "successors: 共 {} 项，最后一项 {:?}", powers.len(), powers.last())?;
+    let mut state = (0u64, 1u64);
+    let fibonacci: Vec<u64> = iter::from_fn(|| {
+        let current = state.0;
+        state = (state.1, state.0 + state.1);
+        Some(current)
+    })
+    .take(10)
+    .collect();
+    writeln!(ctx, "from_fn 斐波那契: {:?}", fibonacci)?;
+    let every_third: Vec<i32> = every_nth(1..=10, 3).collect();
+    writeln!(ctx, "every_nth(1..=10, 3): {:?}", every_third)?;
+
+    ctx.section(7, "对比：奇数的平方和", "Comparison: the sum of squares of odd numbers")?;
+    writeln!(ctx, "循环: {}，迭代器: {}", odd_squares_loop(10), odd_squares_iter(10))?;
+
+    ctx.section(8, "对比：出现最多的单词", "Comparison: the most frequent word")?;
+    let text = "the cat and the dog and the bird";
+    writeln!(ctx, "循环: {:?}", top_words_loop(text, 2))?;
+    writeln!(ctx, "迭代器: {:?}", top_words_iter(text, 2))?;
+
+    ctx.section(9, "对比：遇到错误提前退出", "Comparison: stopping early on an error")?;
+    for input in [["1", "2", "3"], ["4", "x", "6"]] {
+        writeln!(ctx, "{:?} -> 循环: {:?}，迭代器: {:?}", input, sum_loop(&input), sum_iter(&input))?;
+    }
+
+    ctx.section(10, "循环更清楚的时候", "When a loop is clearer")?;
+    // 一次遍历同时更新几个互相依赖的状态，用循环更直接
+    let (longest_run, value) = longest_run(&[1, 1, 2, 2, 2, 3, 2, 2]);
+    writeln!(ctx, "最长的连续相同元素: {} 出现 {} 次", value, longest_run)?;
+    // 同样的事情用扩展方法组合也能做到，但需要先想清楚中间结构
+    let runs: Vec<(i32, usize)> = run_lengths(&[1, 1, 2, 2, 2, 3, 2, 2]);
+    writeln!(ctx, "用迭代器求每段长度: {:?}", runs)?;
+
+    Ok(())
+}
+
+// 产生相邻的两个元素 (a, b)、(b, c)……
+pub struct Pairs<I: Iterator> {
+    iter: I,
+    previous: Option<I::Item>,
+}
+
+impl<I: Iterator> Pairs<I> {
+    pub fn new(mut iter: I) -> Pairs<I> {
+        let previous = iter.next();
+        Pairs { iter, previous }
+    }
+}
+
+impl<I> Iterator for Pairs<I>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    type Item = (I::Item, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = self.iter.next()?;
+        let previous = self.previous.replace(next.clone())?;
+        Some((previous, next))
+    }
+
+    // 剩下的每个元素正好组成一对
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.previous.is_none() {
+            return (0, Some(0));
+        }
+        self.iter.size_hint()
+    }
+}
+
+// 连续重复的元素只保留一个
+pub struct Dedup<I: Iterator> {
+    iter: Peekable<I>,
+}
+
+impl<I> Iterator for Dedup<I>
+where
+    I: Iterator,
+    I::Item: PartialEq,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        while self.iter.next_if_eq(&item).is_some() {}
+        Some(item)
+    }
+}
+
+// 每次取 size 个元素组成一个 Vec，最后一块可能不满
+pub struct Chunked<I: Iterator> {
+    iter: I,
+    size: usize,
+}
+
+impl<I: Iterator> Iterator for Chunked<I> {
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let chunk: Vec<I::Item> = self.iter.by_ref().take(self.size).collect();
+        if chunk.is_empty() { None } else { Some(chunk) }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        (lower.div_ceil(self.size), upper.map(|upper| upper.div_ceil(self.size)))
+    }
+}
+
+// 内层知道确切长度时，块数也是确定的
+impl<I: ExactSizeIterator> ExactSizeIterator for Chunked<I> {}
+
+// 轮流从两个迭代器取元素，一个取完后继续取另一个
+pub struct Interleave<I, J> {
+    first: I,
+    second: J,
+    flag: bool,
+}
+
+impl<I, J> Iterator for Interleave<I, J>
+where
+    I: Iterator,
+    J: Iterator<Item = I::Item>,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.flag = !self.flag;
+        if self.flag {
+            self.first.next().or_else(|| self.second.next())
+        } else {
+            self.second.next().or_else(|| self.first.next())
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (first_lower, first_upper) = self.first.size_hint();
+        let (second_lower, second_upper) = self.second.size_hint();
+        let upper = match (first_upper, second_upper) {
+            (Some(a), Some(b)) => a.checked_add(b),
+            _ => None,
+        };
+        (first_lower.saturating_add(second_lower), upper)
+    }
+}
+
+// 扩展 trait：为所有迭代器加上上面的适配器
+pub trait IteratorExt: Iterator + Sized {
+    fn pairs(self) -> Pairs<Self> {
+        Pairs::new(self)
+    }
+
+    fn dedup(self) -> Dedup<Self> {
+        Dedup { iter: self.peekable() }
+    }
+
+    fn chunked(self, size: usize) -> Chunked<Self> {
+        assert!(size > 0, "块的大小必须大于 0");
+        Chunked { iter: self, size }
+    }
+
+    fn interleave<J>(self, other: J) -> Interleave<Self, J::IntoIter>
+    where
+        J: IntoIterator<Item = Self::Item>,
+    {
+        Interleave { first: self, second: other.into_iter(), flag: false }
+    }
+}
+
+// 空的 impl 块：方法全部使用默认实现
+impl<I: Iterator> IteratorExt for I {}
+
+// 不需要新结构体的适配器，直接组合已有的适配器
+pub fn every_nth<I: Iterator>(iter: I, n: usize) -> impl Iterator<Item = I::Item> {
+    iter.enumerate().filter(move |(i, _)| i % n == n - 1).map(|(_, item)| item)
+}
+
+pub fn odd_squares_loop(limit: u32) -> u32 {
+    let mut total = 0;
+    for n in 1..=limit {
+        if n % 2 == 1 {
+            total += n * n;
+        }
+    }
+    total
+}
+
+pub fn odd_squares_iter(limit: u32) -> u32 {
+    (1..=limit).filter(|n| n % 2 == 1).map(|n| n * n).sum()
+}
+
+fn top_words_loop(text: &str, n: usize) -> Vec<(String, usize)> {
+    let mut counts: HashMap<&str, usize> = HashMap::new();
+    for word in text.split_whitespace() {
+        *counts.entry(word).or_insert(0) += 1;
+    }
+    let mut entries = Vec::new();
+    for (word, count) in counts {
+        entries.push((word.to_string(), count));
+    }
+    // 次数多的在前，次数相同按字母排序，保证输出稳定
+    entries.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    entries.truncate(n);
+    entries
+}
+
+fn top_words_iter(text: &str, n: usize) -> Vec<(String, usize)> {
+    let counts = text.split_whitespace().fold(HashMap::new(), |mut counts, word| {
+        *counts.entry(word).or_insert(0) += 1;
+        counts
+    });
+    let mut entries: Vec<(String, usize)> = counts.into_iter().map(|(word, count)| (word.to_string(), count)).collect();
+    entries.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    entries.into_iter().take(n).collect()
+}
+
+fn sum_loop(input: &[&str]) -> Result<i32, String> {
+    let mut total = 0;
+    for text in input {
+        match text.parse::<i32>() {
+            Ok(n) => total += n,
+            Err(_) => return Err(format!("无法解析 {:?}", text)),
+        }
+    }
+    Ok(total)
+}
+
+// try_fold 在闭包返回 Err 时立即停止，相当于循环中的 return
+fn sum_iter(input: &[&str]) -> Result<i32, String> {
+    input.iter().try_fold(0, |total, text| {
+        text.parse::<i32>().map(|n| total + n).map_err(|_| format!("无法解析 {:?}", text))
+    })
+}
+
+// 返回 (最长连续次数, 对应的值)
+fn longest_run(values: &[i32]) -> (usize, i32) {
+    let mut best = (0, 0);
+    let mut current = (0, 0);
+    for &value in values {
+        if current.0 > 0 && current.1 == value {
+            current.0 += 1;
+        } else {
+            current = (1, value);
+        }
+        if current.0 > best.0 {
+            best = current;
+        }
+    }
+    best
+}
+
+fn run_lengths(values: &[i32]) -> Vec<(i32, usize)> {
+    values
+        .iter()
+        .copied()
+        .dedup()
+        .scan(values, |rest, value| {
+            let len = rest.iter().take_while(|v| **v == value).count();
+            *rest = &rest[len..];
+            Some((value, len))
+        })
+        .collect()
+}
@@ -0,0 +1,118 @@
+//! 迭代器与闭包练习
+//!
+//! 把下面函数中的 `todo!()` 换成实现，然后运行 `rust_study check <练习>` 检查，
+//! 例如 `rust_study check iterators/make_counter`。
+
+use crate::check::{expect_eq, Case, Exercise};
+use crate::iterators::custom_adapters::IteratorExt;
+
+// 练习 1：返回一个闭包，每次调用返回比上一次大 step 的数，第一次返回 start
+// （参考 closures 课中的 move 闭包和 Box<dyn Fn>）
+pub fn make_counter(start: i32, step: i32) -> Box<dyn FnMut() -> i32> {
+    todo!()
+}
+
+// 练习 2：不写循环，用一条迭代器链返回 words 中长度大于 min 的单词的大写形式，
+// 按原来的顺序排列（参考 iterator_basics 课中的常用适配器）
+pub fn shout_long_words(words: &[&str], min: usize) -> Vec<String> {
+    todo!()
+}
+
+// 练习 3：用 custom_adapters 课中的 pairs 适配器，返回相邻两个元素中
+// 后一个比前一个大的次数
+pub fn count_increases(values: &[i32]) -> usize {
+    todo!()
+}
+
+pub static EXERCISES: &[Exercise] = &[
+    Exercise {
+        id: "make_counter",
+        topic: "iterators",
+        lesson: "closures",
+        title: "计数器闭包",
+        title_en: "A counter closure",
+        cases: &[
+            Case {
+                name: "第一次调用",
+                name_en: "First call",
+                hint: "闭包需要修改捕获的状态，写成 let mut current = start; Box::new(move || { ... })",
+                hint_en: "The closure mutates its captured state: let mut current = start; Box::new(move || { ... })",
+                run: || expect_eq(make_counter(5, 1)(), 5),
+            },
+            Case {
+                name: "连续调用",
+                name_en: "Repeated calls",
+                hint: "先保存 current 的值，再加上 step，最后返回保存的值",
+                hint_en: "Save the value of current, add step, then return the saved value",
+                run: || {
+                    let mut counter = make_counter(10, -3);
+                    expect_eq([counter(), counter(), counter()], [10, 7, 4])
+                },
+            },
+            Case {
+                name: "两个计数器互不影响",
+                name_en: "Two counters are independent",
+                hint: "每次调用 make_counter 都创建新的 current，由各自的闭包拥有",
+                hint_en: "Each call to make_counter creates a new current, owned by its own closure",
+                run: || {
+                    let mut a = make_counter(0, 2);
+                    let mut b = make_counter(0, 2);
+                    a();
+                    a();
+                    expect_eq((a(), b()), (4, 0))
+                },
+            },
+        ],
+    },
+    Exercise {
+        id: "shout_long_words",
+        topic: "iterators",
+        lesson: "iterator_basics",
+        title: "迭代器链",
+        title_en: "An iterator chain",
+        cases: &[
+            Case {
+                name: "没有单词",
+                name_en: "No words",
+                hint: "words.iter().filter(...).map(...).collect()",
+                hint_en: "words.iter().filter(...).map(...).collect()",
+                run: || expect_eq(shout_long_words(&[], 3), Vec::<String>::new()),
+            },
+            Case {
+                name: "过滤并转换",
+                name_en: "Filter and transform",
+                hint: "filter 中比较 word.len() > min，map 中调用 word.to_uppercase()",
+                hint_en: "Compare word.len() > min in filter and call word.to_uppercase() in map",
+                run: || {
+                    expect_eq(
+                        shout_long_words(&["iter", "map", "closure", "fn"], 3),
+                        vec![String::from("ITER"), String::from("CLOSURE")],
+                    )
+                },
+            },
+        ],
+    },
+    Exercise {
+        id: "count_increases",
+        topic: "iterators",
+        lesson: "custom_adapters",
+        title: "使用自定义适配器",
+        title_en: "Using a custom adapter",
+        cases: &[
+            Case {
+                name: "少于两个元素",
+                name_en: "Fewer than two elements",
+                hint: "pairs 对少于两个元素的迭代器不产生任何元素，计数自然是 0",
+                hint_en: "pairs yields nothing for an iterator with fewer than two elements, so the count is 0",
+                run: || expect_eq(count_increases(&[7]), 0),
+            },
+            Case {
+                name: "有升有降",
+                name_en: "Ups and downs",
+                hint: "values.iter().pairs().filter(|(a, b)| b > a).count()",
+                hint_en: "values.iter().pairs().filter(|(a, b)| b > a).count()",
+                run: || expect_eq(count_increases(&[199, 200, 208, 210, 200, 207, 240, 269, 260, 263]), 7),
+            },
+        ],
+    },
+];
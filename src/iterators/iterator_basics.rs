@@ -0,0 +1,170 @@
+//! 迭代器（Iterators）
+//!
+//! 实现 `Iterator` 只需要定义 `Item` 类型和 `next` 方法，其余几十个方法都有默认实现。
+//! 迭代器的方法分两类：
+//! - 适配器（`map`、`filter`、`zip`……）返回新的迭代器，本身什么也不做，是惰性的
+//! - 消费者（`sum`、`collect`、`fold`……）反复调用 `next`，真正驱动整条链
+//!
+//! 额外的 trait 提供更多能力：`DoubleEndedIterator` 可以从尾部取元素（`rev`），
+//! `ExactSizeIterator` 知道剩余的确切个数（`len`）。
+//! 本课用 traits 课中的 `Countdown` 和 lifetimes 课中的 `Iter<'a, T>` 演示这些 trait。
+
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashSet};
+use std::io::{self, Write};
+
+use crate::advanced::lifetimes::Iter;
+use crate::advanced::traits::Countdown;
+use crate::context::Ctx;
+
+pub fn main(ctx: &mut Ctx) -> io::Result<()> {
+    writeln!(ctx, "=== {} ===", ctx.text("lesson.iterator_basics"))?;
+
+    ctx.section(1, "手动调用 next", "Calling next by hand")?;
+    let mut countdown = Countdown { count: 3 };
+    writeln!(ctx, "{:?} {:?} {:?} {:?}", countdown.next(), countdown.next(), countdown.next(), countdown.next())?;
+    // for 循环对 IntoIterator 调用 into_iter，然后不断调用 next 直到 None
+    let mut seen = Vec::new();
+    for n in (Countdown { count: 5 }) {
+        seen.push(n);
+    }
+    writeln!(ctx, "for 循环: {:?}", seen)?;
+
+    ctx.section(2, "iter、iter_mut 和 into_iter", "iter, iter_mut and into_iter")?;
+    let mut names = vec![String::from("a"), String::from("b")];
+    let lengths: Vec<usize> = names.iter().map(|name| name.len()).collect();
+    for name in names.iter_mut() {
+        name.push('!');
+    }
+    writeln!(ctx, "iter 借用: {:?}，iter_mut 修改后: {:?}", lengths, names)?;
+    let owned: Vec<String> = names.into_iter().map(|name| name.to_uppercase()).collect();
+    // names 已经被 into_iter 消费
+    writeln!(ctx, "into_iter 拿走所有权: {:?}", owned)?;
+
+    ctx.section(3, "适配器是惰性的", "Adapters are lazy")?;
+    let mut log = Vec::new();
+    let lazy = [1, 2, 3].iter().map(|x| {
+        log.push(format!("map({})", x));
+        x * 10
+    });
+    // 到这里 map 的闭包一次也没有运行
+    let first_two: Vec<i32> = lazy.take(2).collect();
+    writeln!(ctx, "结果: {:?}，闭包调用: {:?}", first_two, log)?;
+    // 每个元素走完整条链，再处理下一个元素；两个闭包都要写入 order，所以放进 RefCell
+    let order = RefCell::new(Vec::new());
+    let evens: Vec<i32> = (1..=4)
+        .inspect(|x| order.borrow_mut().push(format!("取出 {}", x)))
+        .filter(|x| x % 2 == 0)
+        .inspect(|x| order.borrow_mut().push(format!("通过 {}", x)))
+        .collect();
+    writeln!(ctx, "偶数: {:?}", evens)?;
+    writeln!(ctx, "处理顺序: {}", order.borrow().join(", "))?;
+
+    ctx.section(4, "常用适配器", "Common adapters")?;
+    let numbers = [3, 1, 4, 1, 5, 9, 2, 6];
+    let squares: Vec<i32> = numbers.iter().map(|x| x * x).collect();
+    writeln!(ctx, "map: {:?}", squares)?;
+    let big: Vec<&i32> = numbers.iter().filter(|x| **x > 3).collect();
+    writeln!(ctx, "filter: {:?}", big)?;
+    let pairs: Vec<(usize, char)> = "abc".chars().enumerate().collect();
+    writeln!(ctx, "enumerate: {:?}", pairs)?;
+    let zipped: Vec<(i32, char)> = Countdown { count: 3 }.zip("xyz".chars()).collect();
+    writeln!(ctx, "zip: {:?}", zipped)?;
+    let window: Vec<i32> = numbers.iter().copied().skip(2).step_by(2).collect();
+    writeln!(ctx, "skip + step_by: {:?}", window)?;
+    let prefix: Vec<i32> = numbers.iter().copied().take_while(|x| *x < 5).collect();
+    writeln!(ctx, "take_while: {:?}", prefix)?;
+    let words = ["1", "two", "3"];
+    let parsed: Vec<i32> = words.iter().filter_map(|w| w.parse().ok()).collect();
+    writeln!(ctx, "filter_map: {:?}", parsed)?;
+    let letters: String = ["ab", "cd"].iter().flat_map(|s| s.chars()).rev().collect();
+    writeln!(ctx, "flat_map + rev: {}", letters)?;
+    let running: Vec<i32> = numbers.iter().scan(0, |sum, x| {
+        *sum += x;
+        Some(*sum)
+    }).collect();
+    writeln!(ctx, "scan（前缀和）: {:?}", running)?;
+
+    ctx.section(5, "消费者", "Consumers")?;
+    writeln!(ctx, "sum: {}，product: {}", numbers.iter().sum::<i32>(), Countdown { count: 5 }.product::<i32>())?;
+    writeln!(ctx, "max: {:?}，min_by_key: {:?}", numbers.iter().max(), words.iter().min_by_key(|w| w.len()))?;
+    writeln!(ctx, "count: {}，last: {:?}", numbers.iter().filter(|x| **x == 1).count(), numbers.iter().last())?;
+    writeln!(ctx, "any: {}，all: {}", numbers.iter().any(|x| *x > 8), numbers.iter().all(|x| *x > 0))?;
+    writeln!(ctx, "position: {:?}，find: {:?}", numbers.iter().position(|x| *x == 5), numbers.iter().find(|x| **x > 4))?;
+    let sentence = ["迭代器", "很", "好用"].iter().fold(String::new(), |mut acc, word| {
+        acc.push_str(word);
+        acc
+    });
+    writeln!(ctx, "fold: {}", sentence)?;
+
+    ctx.section(6, "collect 到不同的集合", "Collecting into different collections")?;
+    let unique: HashSet<i32> = numbers.iter().copied().collect();
+    let mut unique: Vec<i32> = unique.into_iter().collect();
+    unique.sort();
+    writeln!(ctx, "HashSet 去重: {:?}", unique)?;
+    let counts: BTreeMap<char, usize> = "hello".chars().fold(BTreeMap::new(), |mut map, c| {
+        *map.entry(c).or_insert(0) += 1;
+        map
+    });
+    writeln!(ctx, "BTreeMap 计数: {:?}", counts)?;
+    let (even, odd): (Vec<i32>, Vec<i32>) = numbers.iter().partition(|x| *x % 2 == 0);
+    writeln!(ctx, "partition: {:?} {:?}", even, odd)?;
+    // Result 也实现了 FromIterator：遇到第一个错误就停下
+    let ok: Result<Vec<i32>, _> = ["1", "2", "3"].iter().map(|s| s.parse::<i32>()).collect();
+    let err: Result<Vec<i32>, _> = ["1", "x", "3"].iter().map(|s| s.parse::<i32>()).collect();
+    writeln!(ctx, "collect 成 Result: {:?}，有错误时: {}", ok, err.is_err())?;
+
+    ctx.section(7, "ExactSizeIterator：Countdown 的 len", "ExactSizeIterator: len on Countdown")?;
+    let mut countdown = Countdown { count: 4 };
+    writeln!(ctx, "len = {}，size_hint = {:?}", countdown.len(), countdown.size_hint())?;
+    countdown.next();
+    writeln!(ctx, "取出一个之后 len = {}", countdown.len())?;
+    // filter 不知道会留下几个元素，size_hint 只给出范围
+    let filtered = Countdown { count: 4 }.filter(|x| x % 2 == 0);
+    writeln!(ctx, "filter 之后的 size_hint = {:?}", filtered.size_hint())?;
+    // collect 可以根据 size_hint 预先分配容量
+    let collected: Vec<i32> = Countdown { count: 4 }.collect();
+    writeln!(ctx, "预先分配的容量足够: {}", collected.capacity() >= 4)?;
+
+    ctx.section(8, "DoubleEndedIterator：Iter<'a, T> 的 rev", "DoubleEndedIterator: rev on Iter<'a, T>")?;
+    let data = [1, 2, 3, 4, 5];
+    let backwards: Vec<&i32> = Iter::new(&data).rev().collect();
+    writeln!(ctx, "rev: {:?}", backwards)?;
+    // 两端可以交替取，直到在中间相遇
+    let mut iter = Iter::new(&data);
+    writeln!(ctx, "前 {:?}，后 {:?}，前 {:?}", iter.next(), iter.next_back(), iter.next())?;
+    writeln!(ctx, "剩余 {} 个: {:?}", iter.len(), iter.collect::<Vec<_>>())?;
+    let last_two: Vec<&i32> = Iter::new(&data).rev().take(2).collect();
+    writeln!(ctx, "最后两个（倒序）: {:?}", last_two)?;
+    // Countdown 没有实现 DoubleEndedIterator
+    // Countdown { count: 3 }.rev(); // 错误：不满足 DoubleEndedIterator
+
+    ctx.section(9, "返回 impl Iterator", "Returning impl Iterator")?;
+    let evens: Vec<u32> = evens_up_to(10).collect();
+    writeln!(ctx, "evens_up_to(10): {:?}", evens)?;
+    let text = "one two  three";
+    writeln!(ctx, "长单词: {:?}", long_words(text, 3).collect::<Vec<_>>())?;
+    let boxed = numbers_or_letters(true).chain(numbers_or_letters(false));
+    writeln!(ctx, "Box<dyn Iterator> 拼接: {:?}", boxed.collect::<Vec<_>>())?;
+
+    Ok(())
+}
+
+// 调用者只知道返回值是一个产生 u32 的迭代器，具体类型被隐藏
+pub fn evens_up_to(limit: u32) -> impl Iterator<Item = u32> {
+    (0..=limit).filter(|n| n % 2 == 0)
+}
+
+// 返回的迭代器借用了 text，签名中要用 '_ 写明这一点
+fn long_words(text: &str, min: usize) -> impl Iterator<Item = &str> + '_ {
+    text.split_whitespace().filter(move |word| word.len() > min)
+}
+
+// 两个分支返回不同类型的迭代器，impl Iterator 不行，要装箱成 trait 对象
+fn numbers_or_letters(numbers: bool) -> Box<dyn Iterator<Item = String>> {
+    if numbers {
+        Box::new((1..=2).map(|n| n.to_string()))
+    } else {
+        Box::new(['a', 'b'].into_iter().map(String::from))
+    }
+}
@@ -0,0 +1,10 @@
+//! 迭代器与闭包模块
+//!
+//! 包含闭包、迭代器适配器和自定义适配器等示例。
+
+// 声明子模块
+pub mod closures;
+pub mod iterator_basics;
+pub mod custom_adapters;
+pub mod exercises;
+pub mod quiz;
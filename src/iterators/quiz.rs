@@ -0,0 +1,92 @@
+//! 迭代器与闭包小测验
+//!
+//! 预测输出题运行与 closures 和 iterator_basics 课相同的代码，答案以实际运行结果为准。
+
+use std::io::{self, Write};
+
+use crate::context::Ctx;
+use crate::quiz::{Answer, Question};
+
+pub static QUESTIONS: &[Question] = &[
+    Question {
+        topic: "iterators",
+        lesson: "iterator_basics",
+        prompt: "下面的代码输出什么？",
+        prompt_en: "What does this code print?",
+        code: "let mut calls = 0;\nlet v: Vec<i32> = (1..=5)\n    .map(|x| { calls += 1; x * x })\n    .filter(|x| x % 2 == 1)\n    .take(2)\n    .collect();\nprintln!(\"{:?} {}\", v, calls);",
+        options: &["[1, 9] 3", "[1, 9] 5", "[1, 9, 25] 5", "[1, 4] 2"],
+        options_en: &["[1, 9] 3", "[1, 9] 5", "[1, 9, 25] 5", "[1, 4] 2"],
+        answer: Answer::Output(lazy_calls),
+        explanation: "适配器是惰性的：take(2) 拿到第二个元素后就不再拉取，map 的闭包只对 1、2、3 运行了三次。",
+        explanation_en: "Adapters are lazy: take(2) stops pulling after the second element, so the map closure ran only three times, for 1, 2 and 3.",
+    },
+    Question {
+        topic: "iterators",
+        lesson: "closures",
+        prompt: "闭包 `move || v.len()`（v 是 Vec<i32>）实现了哪些 trait？",
+        prompt_en: "Which traits does the closure `move || v.len()` (v is a Vec<i32>) implement?",
+        code: "",
+        options: &["只有 FnOnce，因为用了 move", "FnOnce 和 FnMut，但不是 Fn", "Fn、FnMut 和 FnOnce", "都没有实现，需要手动实现"],
+        options_en: &["Only FnOnce, because it uses move", "FnOnce and FnMut, but not Fn", "Fn, FnMut and FnOnce", "None of them, they must be implemented by hand"],
+        answer: Answer::Choice(2),
+        explanation: "move 只决定捕获方式；闭包体只读取 v，所以三个 trait 都实现了，可以调用任意多次。",
+        explanation_en: "move only decides how values are captured; the body only reads v, so all three traits are implemented and it can be called any number of times.",
+    },
+    Question {
+        topic: "iterators",
+        lesson: "closures",
+        prompt: "下面的代码输出什么？",
+        prompt_en: "What does this code print?",
+        code: "let mut count = 0;\nlet mut inc = move || { count += 1; count };\ninc();\nprintln!(\"{} {}\", inc(), count);",
+        options: &["2 2", "2 0", "1 0", "编译错误"],
+        options_en: &["2 2", "2 0", "1 0", "Compile error"],
+        answer: Answer::Output(moved_copy),
+        explanation: "i32 是 Copy 类型，move 闭包拿到的是 count 的副本；闭包内部的修改不影响外面的 count。",
+        explanation_en: "i32 is Copy, so the move closure gets a copy of count; changes inside the closure don't affect the count outside.",
+    },
+    Question {
+        topic: "iterators",
+        lesson: "iterator_basics",
+        prompt: "为什么 `(0..10).filter(|n| n % 2 == 0).len()` 不能编译？",
+        prompt_en: "Why doesn't `(0..10).filter(|n| n % 2 == 0).len()` compile?",
+        code: "",
+        options: &[
+            "Range 不是迭代器",
+            "filter 的结果没有实现 ExactSizeIterator，事先不知道会留下几个元素",
+            "len 只能在 Vec 上调用",
+            "闭包参数需要写类型",
+        ],
+        options_en: &[
+            "Range isn't an iterator",
+            "The result of filter doesn't implement ExactSizeIterator; how many elements remain isn't known in advance",
+            "len can only be called on a Vec",
+            "The closure parameter needs a type",
+        ],
+        answer: Answer::Choice(1),
+        explanation: "len 来自 ExactSizeIterator；filter 只能给出 size_hint 的范围，要得到个数需要用 count() 消费迭代器。",
+        explanation_en: "len comes from ExactSizeIterator; filter can only give a size_hint range, so consume the iterator with count() to get the number.",
+    },
+];
+
+fn lazy_calls(ctx: &mut Ctx) -> io::Result<()> {
+    let mut calls = 0;
+    let v: Vec<i32> = (1..=5)
+        .map(|x| {
+            calls += 1;
+            x * x
+        })
+        .filter(|x| x % 2 == 1)
+        .take(2)
+        .collect();
+    writeln!(ctx, "{:?} {}", v, calls)
+}
+
+fn moved_copy(ctx: &mut Ctx) -> io::Result<()> {
+    let mut count = 0;
+    let mut inc = move || {
+        count += 1;
+        count
+    };
+    inc();
+    writeln!(ctx, "{} {}", inc(), count)
+}
@@ -0,0 +1,50 @@
+//! Rust 学习项目
+//!
+//! 这个项目包含了 Rust 的各种语法和功能的示例，按主题组织在子目录中。
+//! 各主题模块作为公共 API 导出，其他 crate 可以直接复用其中的类型，
+//! 例如 `advanced::generics::Statistics` 或 `modules_errors::error_handling::ParseError`。
+
+// 过程宏生成的代码通过 ::rust_study 路径引用本 crate，在 crate 内部也要能这样引用
+extern crate self as rust_study;
+
+// 示例代码里刻意保留了未使用的条目、注释掉的反例和演示性写法
+// （如显式 return、3.14），主题模块不参与未使用和风格类 lint。
+#[allow(dead_code, unused, clippy::style, clippy::complexity, clippy::perf, clippy::approx_constant)]
+pub mod basics;
+#[allow(dead_code, unused, clippy::style, clippy::complexity, clippy::perf, clippy::approx_constant)]
+pub mod control_flow;
+#[allow(dead_code, unused, clippy::style, clippy::complexity, clippy::perf, clippy::approx_constant)]
+pub mod ownership;
+#[allow(dead_code, unused, clippy::style, clippy::complexity, clippy::perf, clippy::approx_constant)]
+pub mod structs_enums;
+#[allow(dead_code, unused, clippy::style, clippy::complexity, clippy::perf, clippy::approx_constant)]
+pub mod collections;
+#[allow(dead_code, unused, clippy::style, clippy::complexity, clippy::perf, clippy::approx_constant)]
+pub mod modules_errors;
+#[allow(dead_code, unused, clippy::style, clippy::complexity, clippy::perf, clippy::approx_constant)]
+pub mod advanced;
+#[allow(dead_code, unused, clippy::style, clippy::complexity, clippy::perf, clippy::approx_constant)]
+pub mod concurrency;
+#[allow(dead_code, unused, clippy::style, clippy::complexity, clippy::perf, clippy::approx_constant)]
+pub mod smart_pointers;
+#[allow(dead_code, unused, clippy::style, clippy::complexity, clippy::perf, clippy::approx_constant)]
+pub mod iterators;
+#[allow(dead_code, unused, clippy::style, clippy::complexity, clippy::perf, clippy::approx_constant)]
+pub mod async_basics;
+#[allow(dead_code, unused, clippy::style, clippy::complexity, clippy::perf, clippy::approx_constant)]
+pub mod macros;
+
+pub mod check;
+pub mod context;
+pub mod export;
+pub mod i18n;
+pub mod menu;
+pub mod panics;
+pub mod progress;
+pub mod quiz;
+pub mod registry;
+pub mod report;
+pub mod runner;
+pub mod search;
+pub mod source;
+pub mod workspace;
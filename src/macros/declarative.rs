@@ -0,0 +1,220 @@
+//! 声明宏（macro_rules!）
+//!
+//! 宏在编译时展开：它接收一段 token，按规则生成新的代码。和函数相比，宏可以：
+//! - 接收任意数量的参数（`vec![1, 2, 3]`、`println!("{} {}", a, b)`）
+//! - 接收表达式以外的东西，例如标识符、类型，甚至一段语句
+//! - 生成新的函数、结构体和 impl 块
+//!
+//! `macro_rules!` 的每条规则是 `(模式) => { 展开结果 }`，从上到下匹配第一条成功的规则。
+//! 模式中的 `$name:片段类型` 捕获一段代码，`$(...),*` 表示重复。
+//! 宏按文本顺序生效，所以本课的宏都定义在 `main` 之前。
+
+use std::collections::HashMap;
+use std::io::{self, Write};
+
+use crate::context::Ctx;
+use crate::{count, hashmap};
+
+// 最简单的宏：没有参数，展开成一个表达式
+macro_rules! greeting {
+    () => {
+        "你好，宏！"
+    };
+}
+
+// 捕获一个表达式；展开结果中的 $x 会被替换成调用处写的代码
+macro_rules! square {
+    ($x:expr) => {
+        $x * $x
+    };
+}
+
+// 多条规则：按调用时的写法选择
+macro_rules! describe {
+    () => {
+        String::from("什么也没有")
+    };
+    ($value:literal) => {
+        format!("字面量 {}", stringify!($value))
+    };
+    ($name:ident = $value:expr) => {
+        format!("{} 被赋值为 {}", stringify!($name), $value)
+    };
+}
+
+// ident 片段：生成一个新函数，函数名由调用者决定
+macro_rules! make_adder {
+    ($name:ident, $amount:expr) => {
+        fn $name(x: i32) -> i32 {
+            x + $amount
+        }
+    };
+}
+
+// ty 片段：把类型名转成字符串，并生成该类型的默认值
+macro_rules! default_of {
+    ($t:ty) => {
+        (stringify!($t), <$t>::default())
+    };
+}
+
+// 重复：$(...),* 匹配零个或多个用逗号分隔的表达式，展开时对每一个重复一次
+macro_rules! my_vec {
+    ($($x:expr),* $(,)?) => {{
+        let mut v = Vec::new();
+        $(v.push($x);)*
+        v
+    }};
+}
+
+// 递归：每次处理第一个参数，剩下的交给自己
+macro_rules! max {
+    ($x:expr) => {
+        $x
+    };
+    ($x:expr, $($rest:expr),+) => {{
+        let first = $x;
+        let rest = max!($($rest),+);
+        if first > rest { first } else { rest }
+    }};
+}
+
+// 为多个类型生成相同的 impl 块
+trait Unit {
+    fn unit(&self) -> &'static str;
+}
+
+struct Meters(f64);
+struct Seconds(f64);
+struct Grams(f64);
+
+macro_rules! impl_unit {
+    ($($t:ident => $unit:literal),+ $(,)?) => {
+        $(
+            impl Unit for $t {
+                fn unit(&self) -> &'static str {
+                    $unit
+                }
+            }
+        )+
+    };
+}
+
+impl_unit! {
+    Meters => "米",
+    Seconds => "秒",
+    Grams => "克",
+}
+
+make_adder!(add_ten, 10);
+
+pub fn main(ctx: &mut Ctx) -> io::Result<()> {
+    writeln!(ctx, "=== {} ===", ctx.text("lesson.declarative"))?;
+
+    ctx.section(1, "第一个宏", "A first macro")?;
+    writeln!(ctx, "{}", greeting!())?;
+    // 圆括号、方括号、花括号都可以用来调用宏
+    writeln!(ctx, "square!(7) = {}，square![1 + 2] = {}，square!{{4}} = {}", square!(7), square![1 + 2], square! {4})?;
+
+    ctx.section(2, "多条规则", "Multiple rules")?;
+    writeln!(ctx, "{}", describe!())?;
+    writeln!(ctx, "{}", describe!(42))?;
+    writeln!(ctx, "{}", describe!(answer = 6 * 7))?;
+
+    ctx.section(3, "片段类型：ident 和 ty", "Fragment types: ident and ty")?;
+    writeln!(ctx, "make_adder! 生成的 add_ten(5) = {}", add_ten(5))?;
+    let (name, value) = default_of!(i32);
+    writeln!(ctx, "{} 的默认值: {:?}", name, value)?;
+    let (name, value) = default_of!(Vec<String>);
+    writeln!(ctx, "{} 的默认值: {:?}", name, value)?;
+
+    ctx.section(4, "重复：$(...),*", "Repetition: $(...),*")?;
+    let v: Vec<i32> = my_vec![1, 2, 3];
+    writeln!(ctx, "my_vec![1, 2, 3] = {:?}", v)?;
+    let empty: Vec<i32> = my_vec![];
+    writeln!(ctx, "my_vec![] = {:?}", empty)?;
+    // 末尾的 $(,)? 允许多写一个逗号
+    let words = my_vec!["宏", "展开",];
+    writeln!(ctx, "带末尾逗号: {:?}", words)?;
+
+    ctx.section(5, "hashmap! 字面量", "A hashmap! literal")?;
+    let scores = hashmap! {
+        "Blue" => 10,
+        "Yellow" => 50,
+    };
+    let mut entries: Vec<_> = scores.iter().collect();
+    entries.sort();
+    writeln!(ctx, "{:?}", entries)?;
+    // 宏按参数个数预先分配容量
+    writeln!(ctx, "容量足够放下所有元素: {}", scores.capacity() >= 2)?;
+    let empty: HashMap<&str, i32> = hashmap! {};
+    writeln!(ctx, "hashmap!{{}} 为空: {}", empty.is_empty())?;
+
+    ctx.section(6, "递归宏", "Recursive macros")?;
+    writeln!(ctx, "max!(3) = {}", max!(3))?;
+    writeln!(ctx, "max!(3, 9, 4, 7) = {}", max!(3, 9, 4, 7))?;
+    // count! 在编译时数出参数个数，展开成 1 + 1 + 1 + 0
+    writeln!(ctx, "count!(a, b, c) = {}", count!("a", "b", "c"))?;
+
+    ctx.section(7, "卫生性（hygiene）", "Hygiene")?;
+    // hashmap! 内部有一个叫 map 的局部变量，但它和调用处的 map 互不干扰
+    let map = 5;
+    let inner = hashmap! { "map" => map };
+    writeln!(ctx, "外面的 map = {}，宏内的 map 不会遮蔽它: {:?}", map, inner.get("map"))?;
+    // 宏展开中定义的变量在调用处也看不到（见 tests/compile_fail/macro_hygiene.rs）
+    // macro_rules! make_x {
+    //     () => {
+    //         let x = 1;
+    //     };
+    // }
+    // make_x!();
+    // println!("{}", x); // 错误：找不到 x
+    // 表达式片段作为一个整体代入，不会因为运算符优先级被拆开
+    writeln!(ctx, "square!(1 + 2) = {}（不是 1 + 2 * 1 + 2 = 5）", square!(1 + 2))?;
+
+    ctx.section(8, "为多个类型生成 impl", "Generating impls for several types")?;
+    let values: [(&dyn Unit, f64); 3] = [(&Meters(1.5), 1.5), (&Seconds(30.0), 30.0), (&Grams(200.0), 200.0)];
+    for (value, amount) in values {
+        writeln!(ctx, "{} {}", amount, value.unit())?;
+    }
+
+    ctx.section(9, "标准库中的宏", "Macros in the standard library")?;
+    // vec! 和上面的 my_vec! 做的是同一件事
+    writeln!(ctx, "vec! 与 my_vec! 结果相同: {}", vec![1, 2, 3] == my_vec![1, 2, 3])?;
+    // format! 把格式字符串交给 format_args!，在编译时检查参数的个数和类型
+    let name = "Rust";
+    writeln!(ctx, "format! 与 fmt::format 结果相同: {}", format!("你好，{}", name) == std::fmt::format(format_args!("你好，{}", name)))?;
+    // stringify! 和 concat! 在编译时生成字符串字面量
+    writeln!(ctx, "stringify!(1 + 2) = {:?}", stringify!(1 + 2))?;
+    writeln!(ctx, "concat!(\"a\", 1, true) = {:?}", concat!("a", 1, true))?;
+    writeln!(ctx, "matches!(Some(3), Some(n) if n > 2) = {}", matches!(Some(3), Some(n) if n > 2))?;
+    writeln!(ctx, "file!() = {}", file!())?;
+
+    Ok(())
+}
+
+// hashmap! 宏：键值对用 => 分隔，可以有末尾逗号
+// #[macro_export] 把宏导出到 crate 根，其他模块用 crate::hashmap 引用（例如 collections::hashmaps）；
+// 展开结果中用 $crate 指向定义宏的 crate，在别的 crate 中调用也能找到 count!
+#[macro_export]
+macro_rules! hashmap {
+    () => {
+        ::std::collections::HashMap::new()
+    };
+    ($($key:expr => $value:expr),+ $(,)?) => {{
+        let mut map = ::std::collections::HashMap::with_capacity($crate::count!($($key),+));
+        $(map.insert($key, $value);)+
+        map
+    }};
+}
+
+// 数出逗号分隔的表达式个数，结果是编译时就能算出的常量表达式
+#[macro_export]
+macro_rules! count {
+    () => {
+        0usize
+    };
+    ($head:expr $(, $tail:expr)*) => {
+        1usize + $crate::count!($($tail),*)
+    };
+}
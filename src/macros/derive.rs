@@ -0,0 +1,153 @@
+//! 过程宏与 #[derive]
+//!
+//! 过程宏是一个在编译时运行的函数：输入一段 `TokenStream`，输出另一段 `TokenStream`。
+//! 它必须放在单独的 `proc-macro` 类型的 crate 中，本项目的 `rust_study_derive` 就是这样一个
+//! 工作区（workspace）成员，主 crate 通过 `[dependencies]` 依赖它。
+//!
+//! 派生宏（derive macro）接收整个类型定义，生成追加在类型后面的代码，通常是一个 impl 块。
+//! `#[derive(Describe)]` 为类型实现 traits 课中的 `Describe` trait：列出类型名和每个字段，
+//! 字段上的 `#[describe(skip)]` 是这个宏声明的辅助属性，用来隐藏字段。
+
+use std::fmt;
+use std::io::{self, Write};
+
+use crate::advanced::traits::{Describe, Greet};
+use crate::context::Ctx;
+
+pub fn main(ctx: &mut Ctx) -> io::Result<()> {
+    writeln!(ctx, "=== {} ===", ctx.text("lesson.derive"))?;
+
+    ctx.section(1, "宏看到的是 token", "Macros see tokens")?;
+    // 派生宏收到的就是类型定义的 token，stringify! 可以把它们原样变回字符串
+    writeln!(ctx, "{}", stringify!(struct Point { x: i32, y: i32 }))?;
+
+    ctx.section(2, "#[derive(Describe)]", "#[derive(Describe)]")?;
+    let point = Point { x: 3, y: -1 };
+    point.greet(ctx)?;
+    point.describe(ctx)?;
+
+    ctx.section(3, "派生宏生成的代码", "The code a derive macro generates")?;
+    // #[derive(Describe)] 为 Point 生成的 impl 块大致如下（格式整理过）：
+    // impl ::rust_study::advanced::traits::Describe for Point {
+    //     fn describe(&self, ctx: &mut ::rust_study::context::Ctx) -> ::std::io::Result<()> {
+    //         match self {
+    //             Point { x: __0, y: __1, .. } => ::rust_study::macros::derive::describe_fields(
+    //                 ctx, "Point", &[("x", __0 as &dyn ::std::fmt::Debug), ("y", __1 as &dyn ::std::fmt::Debug)], 0),
+    //         }
+    //     }
+    // }
+    // 手写同样的实现，输出完全一样
+    let manual = ManualPoint { x: 3, y: -1 };
+    manual.describe(ctx)?;
+
+    ctx.section(4, "元组结构体和单元结构体", "Tuple structs and unit structs")?;
+    Celsius(36.5).describe(ctx)?;
+    Pair("左", 'R').describe(ctx)?;
+    Marker.describe(ctx)?;
+
+    ctx.section(5, "枚举：每个变体一个分支", "Enums: one arm per variant")?;
+    let shapes = [Shape::Circle { radius: 1.5 }, Shape::Rectangle(2, 3), Shape::Empty];
+    for shape in &shapes {
+        shape.describe(ctx)?;
+    }
+
+    ctx.section(6, "辅助属性 #[describe(skip)]", "The #[describe(skip)] helper attribute")?;
+    let user = User { name: String::from("alice"), password: String::from("hunter2"), admin: false };
+    user.describe(ctx)?;
+    Token(String::from("秘密"), 3).describe(ctx)?;
+
+    ctx.section(7, "派生的实现也能当作 trait 对象", "Derived impls work as trait objects too")?;
+    let items: Vec<Box<dyn Describe>> = vec![Box::new(Point { x: 0, y: 0 }), Box::new(Marker), Box::new(Shape::Empty)];
+    for item in &items {
+        item.describe(ctx)?;
+    }
+
+    ctx.section(8, "派生宏的限制", "Limits of derive macros")?;
+    // 生成的代码对每个字段调用 Debug 格式化，字段类型必须实现 Debug
+    // #[derive(Describe)]
+    // struct Wrapper(NotDebug); // 错误：NotDebug 没有实现 Debug
+    //
+    // 为了保持简单，这个派生宏不处理泛型参数，会用 compile_error! 报告：
+    // #[derive(Describe)]
+    // struct Boxed<T>(T); // 错误：Describe 暂不支持带泛型参数的类型 Boxed
+    //
+    // Describe 要求先实现 Greet（父 trait），派生宏不会替你实现它
+    writeln!(ctx, "派生宏只能追加代码，不能修改原来的类型定义")?;
+
+    Ok(())
+}
+
+// 派生的 describe 调用这个函数输出，生成的代码本身保持简短
+pub fn describe_fields(ctx: &mut Ctx, name: &str, fields: &[(&str, &dyn fmt::Debug)], hidden: usize) -> io::Result<()> {
+    let mut line = String::from(name);
+    if !fields.is_empty() {
+        let fields: Vec<String> = fields.iter().map(|(field, value)| format!("{} = {:?}", field, value)).collect();
+        line.push('：');
+        line.push_str(&fields.join("，"));
+    } else if hidden == 0 {
+        line.push_str("（没有字段）");
+    }
+    if hidden > 0 {
+        line.push_str(&format!("（隐藏了 {} 个字段）", hidden));
+    }
+    writeln!(ctx, "{}", line)
+}
+
+// Describe 的父 trait Greet 仍然要手写，用一个声明宏批量实现
+macro_rules! impl_greet {
+    ($($t:ty),+ $(,)?) => {
+        $(
+            impl Greet for $t {
+                fn greet(&self, ctx: &mut Ctx) -> io::Result<()> {
+                    writeln!(ctx, "你好，我是一个 {}", stringify!($t))
+                }
+            }
+        )+
+    };
+}
+
+impl_greet!(Point, ManualPoint, Celsius, Pair, Marker, Shape, User, Token);
+
+#[derive(Describe)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+struct ManualPoint {
+    x: i32,
+    y: i32,
+}
+
+impl Describe for ManualPoint {
+    fn describe(&self, ctx: &mut Ctx) -> io::Result<()> {
+        describe_fields(ctx, "Point", &[("x", &self.x), ("y", &self.y)], 0)
+    }
+}
+
+#[derive(Describe)]
+struct Celsius(f64);
+
+#[derive(Describe)]
+struct Pair(&'static str, char);
+
+#[derive(Describe)]
+struct Marker;
+
+#[derive(Describe)]
+enum Shape {
+    Circle { radius: f64 },
+    Rectangle(u32, u32),
+    Empty,
+}
+
+#[derive(Describe)]
+struct User {
+    name: String,
+    #[describe(skip)]
+    password: String,
+    admin: bool,
+}
+
+#[derive(Describe)]
+struct Token(#[describe(skip)] String, u32);
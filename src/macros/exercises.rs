@@ -0,0 +1,145 @@
+//! 宏练习
+//!
+//! 把下面宏和函数中的 `todo!()` 换成实现，然后运行 `rust_study check <练习>` 检查，
+//! 例如 `rust_study check macros/square_all`。
+
+use std::io::{self, Write};
+
+use crate::advanced::traits::{Describe, Greet};
+use crate::check::{expect_eq, Case, Exercise};
+use crate::context::Ctx;
+
+// 练习 1：square_all![1, 2, 3] 展开成 vec![1 * 1, 2 * 2, 3 * 3]；没有参数时得到空向量
+// （参考 declarative 课中的 my_vec! 和 square!）
+macro_rules! square_all {
+    ($($x:expr),* $(,)?) => {
+        todo!()
+    };
+}
+
+// 练习 2：max_of!(a, b, c, ...) 返回参数中的最大值，用递归规则实现：
+// 只有一个参数时就是它本身，否则比较第一个参数和 max_of!(剩下的参数)
+macro_rules! max_of {
+    ($($x:expr),+) => {
+        todo!()
+    };
+}
+
+// 练习 3：手写 Describe，输出与 #[derive(Describe)] 相同：
+// Book：title = "Rust"，pages = 300
+// （参考 derive 课中的 ManualPoint，用 describe_fields 输出）
+pub struct Book {
+    pub title: String,
+    pub pages: u32,
+}
+
+impl Greet for Book {
+    fn greet(&self, ctx: &mut Ctx) -> io::Result<()> {
+        writeln!(ctx, "你好，我是《{}》", self.title)
+    }
+}
+
+impl Describe for Book {
+    fn describe(&self, ctx: &mut Ctx) -> io::Result<()> {
+        todo!()
+    }
+}
+
+// 把 describe 的输出收集成字符串，去掉末尾的换行
+fn describe_output(value: &dyn Describe) -> String {
+    let mut out = Vec::new();
+    let mut input: &[u8] = &[];
+    let mut ctx = Ctx::new(&mut out, &mut input);
+    if let Err(e) = value.describe(&mut ctx) {
+        return e.to_string();
+    }
+    String::from_utf8_lossy(&out).trim_end().to_string()
+}
+
+pub static EXERCISES: &[Exercise] = &[
+    Exercise {
+        id: "square_all",
+        topic: "macros",
+        lesson: "declarative",
+        title: "对每个参数求平方",
+        title_en: "Squaring every argument",
+        cases: &[
+            Case {
+                name: "三个参数",
+                name_en: "Three arguments",
+                hint: "展开成 vec![$($x * $x),*]",
+                hint_en: "Expand to vec![$($x * $x),*]",
+                run: || expect_eq(square_all![1, 2, 3], vec![1, 4, 9]),
+            },
+            Case {
+                name: "表达式参数",
+                name_en: "Expression arguments",
+                hint: "$x:expr 作为一个整体代入，(1 + 1) * (1 + 1) 而不是 1 + 1 * 1 + 1",
+                hint_en: "$x:expr is substituted as a whole: (1 + 1) * (1 + 1), not 1 + 1 * 1 + 1",
+                run: || expect_eq(square_all![1 + 1, 10 - 7], vec![4, 9]),
+            },
+            Case {
+                name: "没有参数",
+                name_en: "No arguments",
+                hint: "$(...),* 可以重复零次，vec![] 的元素类型由比较的另一边推断",
+                hint_en: "$(...),* may repeat zero times; the element type of vec![] is inferred from the other side of the comparison",
+                run: || expect_eq(square_all![], Vec::<i32>::new()),
+            },
+        ],
+    },
+    Exercise {
+        id: "max_of",
+        topic: "macros",
+        lesson: "declarative",
+        title: "递归的 max_of!",
+        title_en: "A recursive max_of!",
+        cases: &[
+            Case {
+                name: "一个参数",
+                name_en: "One argument",
+                hint: "先写 ($x:expr) => { $x } 这条规则",
+                hint_en: "Start with the rule ($x:expr) => { $x }",
+                run: || expect_eq(max_of!(7), 7),
+            },
+            Case {
+                name: "多个参数",
+                name_en: "Several arguments",
+                hint: "($x:expr, $($rest:expr),+) => {{ let first = $x; let rest = max_of!($($rest),+); ... }}",
+                hint_en: "($x:expr, $($rest:expr),+) => {{ let first = $x; let rest = max_of!($($rest),+); ... }}",
+                run: || expect_eq(max_of!(3, 9, 4, 7), 9),
+            },
+            Case {
+                name: "每个参数只求值一次",
+                name_en: "Each argument is evaluated once",
+                hint: "先把 $x 存进局部变量再比较，不要在展开结果中写两次 $x",
+                hint_en: "Store $x in a local before comparing; don't write $x twice in the expansion",
+                run: || {
+                    let mut calls = 0;
+                    let mut next = |value: i32| {
+                        calls += 1;
+                        value
+                    };
+                    let max = max_of!(next(2), next(5), next(1));
+                    expect_eq((max, calls), (5, 3))
+                },
+            },
+        ],
+    },
+    Exercise {
+        id: "describe_book",
+        topic: "macros",
+        lesson: "derive",
+        title: "手写派生宏生成的代码",
+        title_en: "Writing what the derive generates",
+        cases: &[Case {
+            name: "列出字段",
+            name_en: "Lists the fields",
+            hint: "describe_fields(ctx, \"Book\", &[(\"title\", &self.title), (\"pages\", &self.pages)], 0)",
+            hint_en: "describe_fields(ctx, \"Book\", &[(\"title\", &self.title), (\"pages\", &self.pages)], 0)",
+            run: || {
+                let book = Book { title: String::from("Rust"), pages: 300 };
+                expect_eq(describe_output(&book), String::from("Book：title = \"Rust\"，pages = 300"))
+            },
+        }],
+    },
+];
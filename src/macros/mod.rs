@@ -0,0 +1,9 @@
+//! 宏模块
+//!
+//! 包含声明宏（macro_rules!）和过程宏（派生宏）示例。
+
+// 声明子模块
+pub mod declarative;
+pub mod derive;
+pub mod exercises;
+pub mod quiz;
@@ -0,0 +1,71 @@
+//! 宏小测验
+//!
+//! 预测输出题运行与 declarative 课相同的宏，答案以实际运行结果为准。
+
+use std::io::{self, Write};
+
+use crate::context::Ctx;
+use crate::quiz::{Answer, Question};
+
+pub static QUESTIONS: &[Question] = &[
+    Question {
+        topic: "macros",
+        lesson: "declarative",
+        prompt: "下面的代码输出什么？",
+        prompt_en: "What does this code print?",
+        code: "macro_rules! double {\n    ($e:expr) => {\n        $e * 2\n    };\n}\n\nprintln!(\"{}\", double!(1 + 2));",
+        options: &["6", "5", "3", "编译错误"],
+        options_en: &["6", "5", "3", "Compile error"],
+        answer: Answer::Output(double_expr),
+        explanation: "$e:expr 捕获的是一个完整的表达式，展开时作为整体代入，相当于 (1 + 2) * 2，而不是文本替换出的 1 + 2 * 2。",
+        explanation_en: "$e:expr captures a whole expression and substitutes it as a unit, so this is (1 + 2) * 2 rather than the textual 1 + 2 * 2.",
+    },
+    Question {
+        topic: "macros",
+        lesson: "declarative",
+        prompt: "下面的代码输出什么？",
+        prompt_en: "What does this code print?",
+        code: "macro_rules! count {\n    () => { 0usize };\n    ($head:expr $(, $tail:expr)*) => { 1usize + count!($($tail),*) };\n}\n\nprintln!(\"{}\", count!(\"a\", \"b\", \"c\"));",
+        options: &["1", "3", "\"a\"", "编译错误：宏不能调用自己"],
+        options_en: &["1", "3", "\"a\"", "Compile error: a macro can't call itself"],
+        answer: Answer::Output(count_args),
+        explanation: "每次展开去掉第一个参数，直到匹配空规则，结果是 1usize + 1usize + 1usize + 0usize。",
+        explanation_en: "Each expansion drops the first argument until the empty rule matches, giving 1usize + 1usize + 1usize + 0usize.",
+    },
+    Question {
+        topic: "macros",
+        lesson: "derive",
+        prompt: "#[derive(Describe)] 对原来的结构体做了什么？",
+        prompt_en: "What does #[derive(Describe)] do to the original struct?",
+        code: "",
+        options: &[
+            "把结构体改写成带 describe 方法的新定义",
+            "在结构体后面追加一个 impl Describe 块，结构体本身不变",
+            "在运行时通过反射读取字段",
+            "只检查字段是否实现了 Debug，不生成代码",
+        ],
+        options_en: &[
+            "Rewrites the struct into a new definition with a describe method",
+            "Appends an impl Describe block after the struct and leaves the struct unchanged",
+            "Reads the fields through reflection at run time",
+            "Only checks that the fields implement Debug, generating no code",
+        ],
+        answer: Answer::Choice(1),
+        explanation: "派生宏只能追加代码：它读取类型定义的 token，输出一个 impl 块；字段信息在编译时就写进了生成的代码。",
+        explanation_en: "A derive macro can only append code: it reads the tokens of the type definition and outputs an impl block; the field information is baked into the generated code at compile time.",
+    },
+];
+
+fn double_expr(ctx: &mut Ctx) -> io::Result<()> {
+    macro_rules! double {
+        ($e:expr) => {
+            $e * 2
+        };
+    }
+
+    writeln!(ctx, "{}", double!(1 + 2))
+}
+
+fn count_args(ctx: &mut Ctx) -> io::Result<()> {
+    writeln!(ctx, "{}", crate::count!("a", "b", "c"))
+}
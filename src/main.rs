@@ -1,93 +1,155 @@
-//! Rust 学习项目 - 主文件
+//! Rust 学习项目 - 命令行入口
 //!
-//! 这个项目包含了 Rust 的各种语法和功能的示例。
-//! 按主题组织在子目录中。
+//! 课程和运行逻辑都在库中（见 lib.rs），这里只负责解析参数并分派。
 
-// 导入各个模块
-mod basics;
-mod control_flow;
-mod ownership;
-mod structs_enums;
-mod collections;
-mod modules_errors;
-mod advanced;
+mod cli;
 
-use std::io;
+use std::env;
+use std::fs::File;
+use std::io::{self, BufWriter, IsTerminal, Write};
+use std::process::ExitCode;
 
-fn main() {
-    println!("=== Rust 学习项目 ===");
-    println!("选择要运行的示例：");
-    println!("1. 基础语法（变量、数据类型、函数）");
-    println!("2. 流程控制（if-else、循环）");
-    println!("3. 所有权和借用");
-    println!("4. 结构体、枚举和模式匹配");
-    println!("5. 集合（向量、字符串、哈希映射）");
-    println!("6. 模块和错误处理");
-    println!("7. 高级特性（泛型、Trait、生命周期）");
-    println!("8. 运行所有示例");
-    println!("0. 退出");
+use cli::{CliError, Command, ProgressAction};
+use rust_study::context::Ctx;
+use rust_study::i18n::Locale;
+use rust_study::progress::{self, Progress};
+use rust_study::registry::{self, TOPICS};
+use rust_study::report::{self, Format};
+use rust_study::{check, export, menu, quiz, runner, search, source};
 
-    let mut choice = String::new();
-    io::stdin()
-        .read_line(&mut choice)
-        .expect("读取输入失败");
+fn main() -> ExitCode {
+    let args = match cli::parse(env::args().skip(1)) {
+        Ok(args) => args,
+        Err(e) => return fail(e, Locale::from_env()),
+    };
+    let locale = args.locale.unwrap_or_else(Locale::from_env);
+    let color = use_color(&args);
 
-    match choice.trim() {
-        "1" => run_basics(),
-        "2" => run_control_flow(),
-        "3" => run_ownership(),
-        "4" => run_structs_enums(),
-        "5" => run_collections(),
-        "6" => run_modules_errors(),
-        "7" => run_advanced(),
-        "8" => run_all(),
-        "0" => println!("再见！"),
-        _ => println!("无效选择"),
+    // 输出默认写到终端，--output 时写到文件
+    let mut out: Box<dyn Write> = match &args.output {
+        Some(path) => match File::create(path) {
+            Ok(file) => Box::new(BufWriter::new(file)),
+            Err(e) => {
+                let message = locale.message("cli.create_output_failed", &[&path.display(), &e]);
+                eprintln!("{}", locale.message("cli.error", &[&message]));
+                return ExitCode::FAILURE;
+            }
+        },
+        None => Box::new(io::stdout().lock()),
+    };
+    // JSON 报告模式下丢弃文字输出，运行结束后只输出报告
+    let mut sink = io::sink();
+    let text_out: &mut dyn Write = match args.format {
+        Format::Text => &mut out,
+        Format::Json => &mut sink,
+    };
+    let mut input = io::stdin().lock();
+    let mut ctx = Ctx::new(text_out, &mut input)
+        .with_locale(locale)
+        .keep_workspace(args.keep_workspace)
+        .step_through(args.step && args.format == Format::Text)
+        .with_color(color);
+    if let Some(progress) = load_progress(locale) {
+        ctx = ctx.with_progress(progress);
     }
-}
 
-fn run_basics() {
-    println!("\n=== 运行基础语法示例 ===");
-    basics::run_all();
-}
+    // 命令是否成功（false 表示有课程 panic 或出错、练习没有通过、搜索没有结果、导出时有课程失败），
+    // 以及运行课程的命令的运行结果
+    let finished = match args.command {
+        Command::Menu => menu::run(&mut ctx).map(|()| (true, None)),
+        Command::Help => writeln!(ctx, "{}", ctx.text("cli.usage")).map(|()| (true, None)),
+        Command::List => list_topics(&mut ctx).map(|()| (true, None)),
+        Command::All => runner::run_all(&mut ctx).map(|summary| (summary.is_success(), Some(summary))),
+        Command::Run { query } => match registry::resolve(&query) {
+            Ok(target) => runner::run_target(&mut ctx, target)
+                .map(|summary| (summary.is_success(), Some(summary))),
+            Err(e) => return fail(e.into(), locale),
+        },
+        Command::Quiz { query } => {
+            let questions = match query.as_deref().map(registry::resolve) {
+                None => quiz::questions().collect(),
+                Some(Ok(target)) => quiz::questions_for(target),
+                Some(Err(e)) => return fail(e.into(), locale),
+            };
+            quiz::run(&mut ctx, &questions).map(|_| (true, None))
+        }
+        Command::Progress(action) => match action {
+            ProgressAction::Show => progress::show(&mut ctx),
+            ProgressAction::Reset => progress::reset(&mut ctx),
+            ProgressAction::Export => progress::export(&mut ctx),
+        }
+        .map(|()| (true, None)),
+        Command::Show { query, with_output } => match registry::resolve(&query) {
+            Ok(target) => source::show_target(&mut ctx, target, with_output).map(|()| (true, None)),
+            Err(e) => return fail(e.into(), locale),
+        },
+        Command::Search { term } => search::run(&mut ctx, &term).map(|found| (found, None)),
+        Command::Export { dir, markup } => export::run(&mut ctx, &dir, markup).map(|clean| (clean, None)),
+        Command::Check { query: None } => check::list(&mut ctx).map(|()| (true, None)),
+        Command::Check { query: Some(query) } => match check::resolve(&query) {
+            Ok(exercise) => check::check(&mut ctx, exercise).map(|passed| (passed, None)),
+            Err(e) => return fail(CliError::Exercise(e), locale),
+        },
+    };
+    let finished = finished.and_then(|finished| ctx.flush().map(|()| finished));
+    drop(ctx);
 
-fn run_control_flow() {
-    println!("\n=== 运行流程控制示例 ===");
-    control_flow::run_all();
-}
-
-fn run_ownership() {
-    println!("\n=== 运行所有权示例 ===");
-    ownership::run_all();
-}
+    let result = finished.and_then(|(success, summary)| {
+        if let (Format::Json, Some(summary)) = (args.format, &summary) {
+            writeln!(out, "{}", report::to_json(summary))?;
+            out.flush()?;
+        }
+        Ok(success)
+    });
 
-fn run_structs_enums() {
-    println!("\n=== 运行结构体和枚举示例 ===");
-    structs_enums::run_all();
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(e) => {
+            let message = locale.message("cli.write_failed", &[&e]);
+            eprintln!("{}", locale.message("cli.error", &[&message]));
+            ExitCode::FAILURE
+        }
+    }
 }
 
-fn run_collections() {
-    println!("\n=== 运行集合示例 ===");
-    collections::run_all();
+// 只在直接输出到终端时使用颜色；NO_COLOR 环境变量非空时不用
+fn use_color(args: &cli::Args) -> bool {
+    args.output.is_none()
+        && args.format == Format::Text
+        && env::var_os("NO_COLOR").is_none_or(|value| value.is_empty())
+        && io::stdout().is_terminal()
 }
 
-fn run_modules_errors() {
-    println!("\n=== 运行模块和错误处理示例 ===");
-    modules_errors::run_all();
+// 读取学习进度；读取失败时提示并且本次不记录
+fn load_progress(locale: Locale) -> Option<Progress> {
+    let path = Progress::default_path()?;
+    match Progress::load(path.clone()) {
+        Ok(progress) => Some(progress),
+        Err(e) => {
+            let message = locale.message("progress.load_failed", &[&path.display(), &e]);
+            eprintln!("{}", locale.message("cli.error", &[&message]));
+            None
+        }
+    }
 }
 
-fn run_advanced() {
-    println!("\n=== 运行高级特性示例 ===");
-    advanced::run_all();
+fn fail(error: CliError, locale: Locale) -> ExitCode {
+    eprintln!("{}", locale.message("cli.error", &[&error.message(locale)]));
+    if !matches!(error, CliError::Resolve(_) | CliError::Exercise(_)) {
+        eprintln!("{}", locale.text("cli.usage"));
+    }
+    ExitCode::from(error.exit_code())
 }
 
-fn run_all() {
-    run_basics();
-    run_control_flow();
-    run_ownership();
-    run_structs_enums();
-    run_collections();
-    run_modules_errors();
-    run_advanced();
-    println!("\n=== 所有示例运行完成 ===");
+fn list_topics(ctx: &mut Ctx) -> io::Result<()> {
+    let locale = ctx.locale();
+    for topic in TOPICS {
+        let (title, description) = (topic.title_in(locale), topic.description_in(locale));
+        writeln!(ctx, "{:<16}{} - {}", topic.id, title, description)?;
+        for lesson in registry::lessons_of(topic.id) {
+            writeln!(ctx, "  {:<34}{}", lesson.path(), lesson.description_in(locale))?;
+        }
+    }
+    Ok(())
 }
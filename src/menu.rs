@@ -0,0 +1,232 @@
+//! 交互式菜单
+//!
+//! 不带参数运行时进入的循环菜单：在主题和课程之间来回切换，
+//! 重新运行上一次的选择，查看本次会话的历史，用 s<编号> 或 s <课程> 查看课程源码。
+//! 记录了学习进度时，主题后面显示完成百分比，学过的课程后面打勾。
+//! 标准输入结束（EOF）时正常退出。
+
+use std::io::{self, Write};
+
+use crate::context::Ctx;
+use crate::progress::Kind;
+use crate::registry::{self, Lesson, Target, Topic, TOPICS};
+use crate::runner;
+use crate::source;
+
+// 可以重复执行的一次选择
+#[derive(Clone, Copy)]
+enum Choice {
+    Target(Target),
+    All,
+}
+
+impl Choice {
+    fn label(&self) -> String {
+        match self {
+            Choice::Target(target) => target.path(),
+            Choice::All => String::from("all"),
+        }
+    }
+
+    // 课程失败只体现在运行器打印的信息和汇总表中，不结束会话
+    fn run(&self, ctx: &mut Ctx) -> io::Result<()> {
+        match self {
+            Choice::Target(target) => runner::run_target(ctx, *target)?,
+            Choice::All => runner::run_all(ctx)?,
+        };
+        Ok(())
+    }
+}
+
+// 当前所在的菜单层级
+#[derive(Clone, Copy)]
+enum Level {
+    Main,
+    Topic(&'static Topic),
+}
+
+// 一次菜单会话的状态
+struct Session {
+    level: Level,
+    last: Option<Choice>,
+    history: Vec<Choice>,
+}
+
+pub fn run(ctx: &mut Ctx) -> io::Result<()> {
+    let mut session = Session {
+        level: Level::Main,
+        last: None,
+        history: Vec::new(),
+    };
+
+    loop {
+        session.show(ctx)?;
+
+        let input = match read_input(ctx)? {
+            Some(input) => input,
+            None => return writeln!(ctx, "\n{}", ctx.text("menu.goodbye")),
+        };
+
+        match input.as_str() {
+            "q" | "quit" | "exit" => return writeln!(ctx, "{}", ctx.text("menu.goodbye")),
+            "" => continue,
+            _ => {}
+        }
+
+        if !session.handle(ctx, &input)? {
+            return writeln!(ctx, "{}", ctx.text("menu.goodbye"));
+        }
+    }
+}
+
+// 读取一行输入；输入结束（EOF）时返回 None
+fn read_input(ctx: &mut Ctx) -> io::Result<Option<String>> {
+    write!(ctx, "> ")?;
+
+    let mut line = String::new();
+    match ctx.read_line(&mut line)? {
+        0 => Ok(None),
+        _ => Ok(Some(line.trim().to_string())),
+    }
+}
+
+// "s2"、"s 2"、"s traits" 是查看源码的请求，返回 s 后面的部分；
+// "strings" 这类以 s 开头的课程名不算
+fn source_query(input: &str) -> Option<&str> {
+    let rest = input.strip_prefix('s')?;
+    let number = !rest.is_empty() && rest.bytes().all(|b| b.is_ascii_digit());
+    (number || rest.starts_with(' ')).then(|| rest.trim())
+}
+
+// 菜单中的一行，例如 "基础语法（变量、数据类型、函数）"
+fn menu_entry(ctx: &Ctx, topic: &Topic) -> String {
+    let locale = ctx.locale();
+    let lessons: Vec<&str> = registry::lessons_of(topic.id)
+        .map(|lesson| lesson.title_in(locale))
+        .collect();
+    let lessons = lessons.join(ctx.text("menu.separator"));
+    ctx.message("menu.entry", &[&topic.title_in(locale), &lessons])
+}
+
+impl Session {
+    fn show(&self, ctx: &mut Ctx) -> io::Result<()> {
+        match self.level {
+            Level::Main => {
+                writeln!(ctx, "\n=== {} ===", ctx.text("menu.title"))?;
+                writeln!(ctx, "{}", ctx.text("menu.prompt"))?;
+                for (i, topic) in TOPICS.iter().enumerate() {
+                    let entry = menu_entry(ctx, topic);
+                    match ctx.progress().map(|progress| progress.completion(topic).percent()) {
+                        Some(percent) => writeln!(ctx, "{}. {}  {}%", i + 1, entry, percent)?,
+                        None => writeln!(ctx, "{}. {}", i + 1, entry)?,
+                    }
+                }
+                writeln!(ctx, "{}. {}", TOPICS.len() + 1, ctx.text("menu.run_all"))?;
+                writeln!(ctx, "0. {}", ctx.text("menu.quit"))?;
+            }
+            Level::Topic(topic) => {
+                let locale = ctx.locale();
+                writeln!(ctx, "\n=== {} ===", topic.title_in(locale))?;
+                for (i, lesson) in self.lessons(topic).iter().enumerate() {
+                    let (title, description) = (lesson.title_in(locale), lesson.description_in(locale));
+                    let done = ctx
+                        .progress()
+                        .is_some_and(|progress| progress.is_done(Kind::Lesson, &lesson.path()));
+                    let mark = if done { ctx.text("menu.done") } else { "" };
+                    writeln!(ctx, "{}. {} - {}{}", i + 1, title, description, mark)?;
+                }
+                writeln!(ctx, "a. {}", ctx.text("menu.run_topic"))?;
+                writeln!(ctx, "{}", ctx.text("menu.show_source"))?;
+                writeln!(ctx, "0. {}", ctx.text("menu.back"))?;
+            }
+        }
+        writeln!(ctx, "{}", ctx.text("menu.commands"))
+    }
+
+    fn lessons(&self, topic: &Topic) -> Vec<&'static Lesson> {
+        registry::lessons_of(topic.id).collect()
+    }
+
+    // 处理一条输入；返回 false 表示结束会话
+    fn handle(&mut self, ctx: &mut Ctx, input: &str) -> io::Result<bool> {
+        match (self.level, input) {
+            (_, "r") => match self.last {
+                Some(choice) => self.run(ctx, choice)?,
+                None => writeln!(ctx, "{}", ctx.text("menu.nothing_to_rerun"))?,
+            },
+            (_, "h") => self.print_history(ctx)?,
+            (Level::Main, "0") => return Ok(false),
+            (Level::Main, "b") => {}
+            (Level::Topic(_), "0" | "b") => self.level = Level::Main,
+            (Level::Topic(topic), "a" | "A") => {
+                self.run(ctx, Choice::Target(Target::Topic(topic)))?
+            }
+            (level, _) => match input.parse::<usize>() {
+                Ok(n) => self.select(ctx, level, n)?,
+                Err(_) => match source_query(input) {
+                    Some(query) => self.show_source(ctx, level, query)?,
+                    None => match registry::resolve(input) {
+                        Ok(Target::Topic(topic)) => self.level = Level::Topic(topic),
+                        Ok(target) => self.run(ctx, Choice::Target(target))?,
+                        Err(e) => {
+                            let message = e.message(ctx.locale());
+                            writeln!(ctx, "{}", ctx.message("menu.invalid", &[&message]))?
+                        }
+                    },
+                },
+            },
+        }
+        Ok(true)
+    }
+
+    // 按编号选择
+    fn select(&mut self, ctx: &mut Ctx, level: Level, n: usize) -> io::Result<()> {
+        match level {
+            Level::Main if (1..=TOPICS.len()).contains(&n) => {
+                self.level = Level::Topic(&TOPICS[n - 1]);
+                Ok(())
+            }
+            Level::Main if n == TOPICS.len() + 1 => self.run(ctx, Choice::All),
+            Level::Topic(topic) => match self.lessons(topic).get(n.wrapping_sub(1)) {
+                Some(lesson) => self.run(ctx, Choice::Target(Target::Lesson(lesson))),
+                None => writeln!(ctx, "{}", ctx.message("menu.invalid", &[&n])),
+            },
+            _ => writeln!(ctx, "{}", ctx.message("menu.invalid", &[&n])),
+        }
+    }
+
+    // 查看源码：主题菜单中可以用编号，任何层级都可以用课程名
+    fn show_source(&self, ctx: &mut Ctx, level: Level, query: &str) -> io::Result<()> {
+        let target = match (level, query.parse::<usize>()) {
+            (Level::Topic(topic), Ok(n)) => match self.lessons(topic).get(n.wrapping_sub(1)) {
+                Some(lesson) => Target::Lesson(lesson),
+                None => return writeln!(ctx, "{}", ctx.message("menu.invalid", &[&query])),
+            },
+            _ => match registry::resolve(query) {
+                Ok(target) => target,
+                Err(e) => {
+                    let message = e.message(ctx.locale());
+                    return writeln!(ctx, "{}", ctx.message("menu.invalid", &[&message]));
+                }
+            },
+        };
+        source::show_target(ctx, target, false)
+    }
+
+    fn run(&mut self, ctx: &mut Ctx, choice: Choice) -> io::Result<()> {
+        self.last = Some(choice);
+        self.history.push(choice);
+        choice.run(ctx)
+    }
+
+    fn print_history(&self, ctx: &mut Ctx) -> io::Result<()> {
+        if self.history.is_empty() {
+            return writeln!(ctx, "{}", ctx.text("menu.history_empty"));
+        }
+        writeln!(ctx, "{}", ctx.text("menu.history"))?;
+        for (i, choice) in self.history.iter().enumerate() {
+            writeln!(ctx, "{:>3}. {}", i + 1, choice.label())?;
+        }
+        Ok(())
+    }
+}
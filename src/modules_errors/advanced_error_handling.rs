@@ -0,0 +1,80 @@
+//! 高级错误处理
+//!
+//! 演示 error_handling.rs 中的几个辅助函数：
+//! - 用 `Box<dyn Error>` 统一多种错误类型，再用 `downcast_ref` 区分它们
+//! - Result 与 Option 的相互转换
+//! - 出错时返回默认值
+//! - 自定义 panic 钩子
+//!
+//! 输入文件预先写入运行器准备的临时工作目录，让每条错误路径都真正走一遍。
+
+use std::error::Error;
+use std::fs;
+use std::io::{self, Write};
+use std::num::ParseIntError;
+use std::panic;
+use std::sync::{Arc, Mutex};
+
+use crate::context::Ctx;
+
+use super::error_handling::{divide, parse_and_find_even, process_data, safe_divide, with_panic_hook};
+
+// 预先写入工作目录的输入文件；missing.txt 故意不创建
+const INPUTS: &[(&str, Option<&str>)] = &[
+    ("number.txt", Some("21\n")),
+    ("missing.txt", None),
+    ("not_a_number.txt", Some("二十一\n")),
+    ("too_big.txt", Some("500\n")),
+];
+
+pub fn main(ctx: &mut Ctx) -> io::Result<()> {
+    writeln!(ctx, "=== {} ===", ctx.text("lesson.advanced_error_handling"))?;
+
+    ctx.section(1, "一个函数，多种错误：io::Error、ParseIntError 和自定义的字符串错误", "One function, several errors: io::Error, ParseIntError and a custom string error")?;
+    for (name, content) in INPUTS {
+        let path = ctx.workspace_file(name)?;
+        if let Some(content) = content {
+            fs::write(&path, content)?;
+        }
+
+        match process_data(&path) {
+            Ok(value) => writeln!(ctx, "{}: 处理结果 {}", name, value)?,
+            Err(e) => writeln!(ctx, "{}: 处理失败（{}）: {}", name, error_kind(e.as_ref()), e)?,
+        }
+    }
+
+    ctx.section(2, "Result<Option<T>, E>：解析失败是错误，找不到偶数只是 None", "Result<Option<T>, E>: a parse failure is an error, no even number is just None")?;
+    for strings in [&["1", "2", "3", "4"][..], &["1", "3", "5"], &["1", "two", "3"]] {
+        match parse_and_find_even(strings) {
+            Ok(Some(even)) => writeln!(ctx, "{:?} 中的第一个偶数: {}", strings, even)?,
+            Ok(None) => writeln!(ctx, "{:?} 中没有偶数", strings)?,
+            Err(e) => writeln!(ctx, "{:?} 解析失败: {}", strings, e)?,
+        }
+    }
+
+    ctx.section(3, "出错时返回默认值", "Returning a default on error")?;
+    for (a, b) in [(10.0, 2.0), (10.0, 0.0)] {
+        let value = safe_divide(ctx, a, b)?;
+        writeln!(ctx, "{} / {} = {}", a, b, value)?;
+    }
+
+    ctx.section(4, "自定义 panic 钩子：unwrap 一个 Err 时触发 panic，由钩子记录信息", "A custom panic hook: unwrapping an Err panics and the hook logs it")?;
+    let log = Arc::new(Mutex::new(String::new()));
+    let result = with_panic_hook(Arc::clone(&log), || panic::catch_unwind(|| divide(1.0, 0.0).unwrap()));
+
+    write!(ctx, "{}", log.lock().unwrap_or_else(|e| e.into_inner()))?;
+    writeln!(ctx, "catch_unwind 捕获到 panic: {}", result.is_err())?;
+
+    Ok(())
+}
+
+// 用 downcast_ref 找出 Box<dyn Error> 里装的具体错误类型
+fn error_kind(error: &(dyn Error + 'static)) -> &'static str {
+    if error.downcast_ref::<io::Error>().is_some() {
+        "io::Error"
+    } else if error.downcast_ref::<ParseIntError>().is_some() {
+        "ParseIntError"
+    } else {
+        "自定义错误"
+    }
+}
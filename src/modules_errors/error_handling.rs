@@ -2,114 +2,130 @@
 //!
 //! Rust 将错误分为两大类：可恢复错误（Result<T, E>）和不可恢复错误（panic!）。
 //! Rust 没有异常，而是使用 Result 类型和 panic 宏来处理错误。
+//!
+//! 示例中读写的文件都在运行器准备的临时工作目录里（`ctx.workspace_file`），
+//! 不会在当前目录留下文件。
 
+use std::fmt::Write as _;
 use std::fs::File;
-use std::io::{self, Read, ErrorKind};
+use std::io::{self, ErrorKind, Read, Write};
 use std::num::ParseIntError;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+use crate::context::Ctx;
+use crate::panics;
 
-pub fn main() {
-    println!("=== 错误处理示例 ===");
+pub fn main(ctx: &mut Ctx) -> io::Result<()> {
+    writeln!(ctx, "=== {} ===", ctx.text("lesson.error_handling"))?;
 
-    // 1. panic! 宏 - 不可恢复错误
+    ctx.section(1, "panic! 宏 - 不可恢复错误", "The panic! macro: unrecoverable errors")?;
     // panic!("严重错误!"); // 这会终止程序
 
-    // 2. Result 枚举 - 可恢复错误
-    let file_result = File::open("hello.txt");
+    ctx.section(2, "Result 枚举 - 可恢复错误", "The Result enum: recoverable errors")?;
+    let hello = ctx.workspace_file("hello.txt")?;
+    let file_result = File::open(&hello);
 
     match file_result {
         Ok(file) => {
-            println!("文件打开成功: {:?}", file);
+            writeln!(ctx, "文件打开成功: {:?}", file)?;
         }
         Err(error) => match error.kind() {
             ErrorKind::NotFound => {
-                println!("文件未找到，尝试创建...");
-                match File::create("hello.txt") {
-                    Ok(fc) => println!("文件创建成功: {:?}", fc),
-                    Err(e) => println!("创建文件时出错: {:?}", e),
+                writeln!(ctx, "文件未找到，尝试创建...")?;
+                match File::create(&hello) {
+                    Ok(fc) => writeln!(ctx, "文件创建成功: {:?}", fc)?,
+                    Err(e) => writeln!(ctx, "创建文件时出错: {:?}", e)?,
                 }
             }
             other_error => {
-                println!("打开文件时出错: {:?}", other_error);
+                writeln!(ctx, "打开文件时出错: {:?}", other_error)?;
             }
         },
     }
 
-    // 3. unwrap 方法 - 成功时返回值，错误时 panic
-    let file = File::open("hello.txt").unwrap_or_else(|error| {
+    ctx.section(3, "unwrap 方法 - 成功时返回值，错误时 panic", "The unwrap method: the value on success, a panic on error")?;
+    let file = File::open(&hello).unwrap_or_else(|error| {
         if error.kind() == ErrorKind::NotFound {
-            File::create("hello.txt").unwrap_or_else(|error| {
+            File::create(&hello).unwrap_or_else(|error| {
                 panic!("创建文件失败: {:?}", error);
             })
         } else {
             panic!("打开文件失败: {:?}", error);
         }
     });
-    println!("使用 unwrap_or_else: {:?}", file);
+    writeln!(ctx, "使用 unwrap_or_else: {:?}", file)?;
 
-    // 4. expect 方法 - 类似 unwrap，但可以指定错误信息
-    let file = File::open("hello.txt").expect("打开 hello.txt 文件失败");
-    println!("使用 expect: {:?}", file);
+    ctx.section(4, "expect 方法 - 类似 unwrap，但可以指定错误信息", "The expect method: like unwrap, with your own message")?;
+    let file = File::open(&hello).expect("打开 hello.txt 文件失败");
+    writeln!(ctx, "使用 expect: {:?}", file)?;
 
-    // 5. 传播错误
-    let username_result = read_username_from_file();
+    ctx.section(5, "传播错误", "Propagating errors")?;
+    let username_file = ctx.workspace_file("username.txt")?;
+    let username_result = read_username_from_file(&username_file);
     match username_result {
-        Ok(username) => println!("用户名: {}", username),
-        Err(e) => println!("读取用户名失败: {}", e),
+        Ok(username) => writeln!(ctx, "用户名: {}", username)?,
+        Err(e) => writeln!(ctx, "读取用户名失败: {}", e)?,
     }
 
-    // 6. ? 运算符 - 错误传播的语法糖
-    let username = read_username_from_file_with_question().unwrap_or_else(|e| {
-        println!("读取失败: {}", e);
-        String::from("默认用户")
-    });
-    println!("使用 ? 运算符读取的用户名: {}", username);
+    ctx.section(6, "? 运算符 - 错误传播的语法糖", "The ? operator: shorthand for propagating errors")?;
+    let username = match read_username_from_file_with_question(&username_file) {
+        Ok(username) => username,
+        Err(e) => {
+            writeln!(ctx, "读取失败: {}", e)?;
+            String::from("默认用户")
+        }
+    };
+    writeln!(ctx, "使用 ? 运算符读取的用户名: {}", username)?;
 
-    // 7. 自定义错误类型
+    ctx.section(7, "自定义错误类型", "Custom error types")?;
     let result = parse_positive_number("42");
     match result {
-        Ok(num) => println!("解析的正数: {}", num),
-        Err(e) => println!("解析错误: {}", e),
+        Ok(num) => writeln!(ctx, "解析的正数: {}", num)?,
+        Err(e) => writeln!(ctx, "解析错误: {}", e)?,
     }
 
     let result = parse_positive_number("-5");
     match result {
-        Ok(num) => println!("解析的正数: {}", num),
-        Err(e) => println!("解析错误: {}", e),
+        Ok(num) => writeln!(ctx, "解析的正数: {}", num)?,
+        Err(e) => writeln!(ctx, "解析错误: {}", e)?,
     }
 
-    // 8. 错误类型转换
+    ctx.section(8, "错误类型转换", "Converting error types")?;
     let result = parse_number_then_double("42");
     match result {
-        Ok(num) => println!("解析并加倍: {}", num),
-        Err(e) => println!("错误: {}", e),
+        Ok(num) => writeln!(ctx, "解析并加倍: {}", num)?,
+        Err(e) => writeln!(ctx, "错误: {}", e)?,
     }
 
-    // 9. 错误链
-    let result = read_config_file();
+    ctx.section(9, "错误链", "Error chains")?;
+    let result = read_config_file(&ctx.workspace_file("config.txt")?);
     match result {
-        Ok(config) => println!("配置: {}", config),
-        Err(e) => println!("读取配置失败: {}", e),
+        Ok(config) => writeln!(ctx, "配置: {}", config)?,
+        Err(e) => writeln!(ctx, "读取配置失败: {}", e)?,
     }
 
-    // 10. 组合错误处理
+    ctx.section(10, "组合错误处理", "Combining error handling")?;
     let numbers = vec!["1", "2", "three", "4"];
     let parsed: Result<Vec<i32>, _> = numbers.iter().map(|s| s.parse::<i32>()).collect();
     match parsed {
-        Ok(nums) => println!("解析成功: {:?}", nums),
-        Err(e) => println!("解析失败: {}", e),
+        Ok(nums) => writeln!(ctx, "解析成功: {:?}", nums)?,
+        Err(e) => writeln!(ctx, "解析失败: {}", e)?,
     }
 
-    // 11. 错误处理最佳实践
+    ctx.section(11, "错误处理最佳实践", "Error handling best practices")?;
     // - 使用 Result 处理可恢复错误
     // - 使用 panic! 处理不可恢复错误（bug）
     // - 提供有意义的错误信息
     // - 使用 ? 运算符简化错误传播
     // - 定义自己的错误类型以提高类型安全性
+
+    Ok(())
 }
 
 // 传播错误的函数
-fn read_username_from_file() -> Result<String, io::Error> {
-    let mut username_file = match File::open("username.txt") {
+fn read_username_from_file(path: &Path) -> Result<String, io::Error> {
+    let mut username_file = match File::open(path) {
         Ok(file) => file,
         Err(e) => return Err(e),
     };
@@ -123,23 +139,23 @@ fn read_username_from_file() -> Result<String, io::Error> {
 }
 
 // 使用 ? 运算符简化错误传播
-fn read_username_from_file_with_question() -> Result<String, io::Error> {
-    let mut username_file = File::open("username.txt")?;
+fn read_username_from_file_with_question(path: &Path) -> Result<String, io::Error> {
+    let mut username_file = File::open(path)?;
     let mut username = String::new();
     username_file.read_to_string(&mut username)?;
     Ok(username)
 }
 
 // 更简洁的写法
-fn read_username_from_file_short() -> Result<String, io::Error> {
+fn read_username_from_file_short(path: &Path) -> Result<String, io::Error> {
     let mut username = String::new();
-    File::open("username.txt")?.read_to_string(&mut username)?;
+    File::open(path)?.read_to_string(&mut username)?;
     Ok(username)
 }
 
 // 自定义错误类型
 #[derive(Debug)]
-enum ParseError {
+pub enum ParseError {
     InvalidNumber(ParseIntError),
     NegativeNumber,
 }
@@ -162,7 +178,7 @@ impl std::error::Error for ParseError {
     }
 }
 
-fn parse_positive_number(s: &str) -> Result<i32, ParseError> {
+pub fn parse_positive_number(s: &str) -> Result<i32, ParseError> {
     let num = s.parse::<i32>().map_err(ParseError::InvalidNumber)?;
 
     if num < 0 {
@@ -179,10 +195,9 @@ fn parse_number_then_double(s: &str) -> Result<i32, String> {
 }
 
 // 错误链示例
-fn read_config_file() -> Result<String, Box<dyn std::error::Error>> {
-    let path = "config.txt";
+fn read_config_file(path: &Path) -> Result<String, Box<dyn std::error::Error>> {
     let content = std::fs::read_to_string(path)
-        .map_err(|e| format!("无法读取文件 {}: {}", path, e))?;
+        .map_err(|e| format!("无法读取文件 {}: {}", path.display(), e))?;
 
     let config = parse_config(&content)
         .map_err(|e| format!("解析配置失败: {}", e))?;
@@ -198,10 +213,12 @@ fn parse_config(content: &str) -> Result<String, &str> {
     }
 }
 
+// 以下辅助函数在“高级错误处理”一课（advanced_error_handling.rs）中演示
+
 // 示例：多种错误类型处理
-fn process_data(data: &str) -> Result<i32, Box<dyn std::error::Error>> {
+pub fn process_data(path: &Path) -> Result<i32, Box<dyn std::error::Error>> {
     // 可能产生 io::Error
-    let file_content = std::fs::read_to_string(data)?;
+    let file_content = std::fs::read_to_string(path)?;
 
     // 可能产生 ParseIntError
     let number: i32 = file_content.trim().parse()?;
@@ -215,11 +232,11 @@ fn process_data(data: &str) -> Result<i32, Box<dyn std::error::Error>> {
 }
 
 // 示例：Option 和 Result 的转换
-fn find_first_even(numbers: &[i32]) -> Option<i32> {
+pub fn find_first_even(numbers: &[i32]) -> Option<i32> {
     numbers.iter().find(|&&x| x % 2 == 0).copied()
 }
 
-fn parse_and_find_even(strings: &[&str]) -> Result<Option<i32>, ParseIntError> {
+pub fn parse_and_find_even(strings: &[&str]) -> Result<Option<i32>, ParseIntError> {
     let numbers: Result<Vec<i32>, ParseIntError> =
         strings.iter().map(|s| s.parse()).collect();
 
@@ -227,7 +244,7 @@ fn parse_and_find_even(strings: &[&str]) -> Result<Option<i32>, ParseIntError> {
 }
 
 // 示例：错误处理辅助函数
-fn divide(a: f64, b: f64) -> Result<f64, String> {
+pub fn divide(a: f64, b: f64) -> Result<f64, String> {
     if b == 0.0 {
         Err("除数不能为零".to_string())
     } else {
@@ -235,19 +252,37 @@ fn divide(a: f64, b: f64) -> Result<f64, String> {
     }
 }
 
-fn safe_divide(a: f64, b: f64) -> f64 {
-    divide(a, b).unwrap_or_else(|e| {
-        println!("警告: {}", e);
-        0.0 // 返回默认值
-    })
+pub fn safe_divide(ctx: &mut Ctx, a: f64, b: f64) -> io::Result<f64> {
+    match divide(a, b) {
+        Ok(value) => Ok(value),
+        Err(e) => {
+            writeln!(ctx, "警告: {}", e)?;
+            Ok(0.0) // 返回默认值
+        }
+    }
 }
 
 // 示例：panic 钩子
-fn setup_panic_hook() {
-    std::panic::set_hook(Box::new(|panic_info| {
-        println!("自定义 panic 处理:");
+// 标准库用 std::panic::set_hook 替换 panic 时调用的钩子，take_hook 取出当前的钩子，
+// 两者配合可以在用完后恢复原来的钩子：
+//
+// let previous = std::panic::take_hook();
+// std::panic::set_hook(Box::new(|panic_info| {
+//     eprintln!("自定义 panic 处理: {}", panic_info);
+// }));
+// // ... 可能 panic 的代码 ...
+// std::panic::set_hook(previous);
+//
+// 这个钩子由整个进程共享，多个线程（例如并行运行的测试）同时替换、恢复时会互相覆盖。
+// 下面的 with_panic_hook 通过 crate::panics 只在 f 运行期间、只对当前线程生效，
+// crate::panics 在第一次使用时调用一次 set_hook，之后不再替换全局钩子。
+// 钩子在 panic 发生的线程里运行，拿不到课程的 ctx，所以把信息写进共享的日志
+pub fn with_panic_hook<R>(log: Arc<Mutex<String>>, f: impl FnOnce() -> R) -> R {
+    panics::with_hook(move |panic_info| {
+        let mut log = log.lock().unwrap_or_else(|e| e.into_inner());
+        let _ = writeln!(log, "自定义 panic 处理:");
         if let Some(location) = panic_info.location() {
-            println!("在 {}:{}:{} 发生 panic",
+            let _ = writeln!(log, "在 {}:{}:{} 发生 panic",
                 location.file(),
                 location.line(),
                 location.column()
@@ -255,26 +290,9 @@ fn setup_panic_hook() {
         }
 
         if let Some(s) = panic_info.payload().downcast_ref::<&str>() {
-            println!("panic 信息: {}", s);
+            let _ = writeln!(log, "panic 信息: {}", s);
         } else if let Some(s) = panic_info.payload().downcast_ref::<String>() {
-            println!("panic 信息: {}", s);
+            let _ = writeln!(log, "panic 信息: {}", s);
         }
-    }));
+    }, f)
 }
-
-// 在 main 中测试
-// fn main() {
-//     setup_panic_hook();
-//
-//     println!("10 / 2 = {}", safe_divide(10.0, 2.0));
-//     println!("10 / 0 = {}", safe_divide(10.0, 0.0));
-//
-//     let result = parse_and_find_even(&["1", "2", "3", "4"]);
-//     println!("查找偶数结果: {:?}", result);
-//
-//     let result = process_data("data.txt");
-//     match result {
-//         Ok(value) => println!("处理结果: {}", value),
-//         Err(e) => println!("处理失败: {}", e),
-//     }
-// }
\ No newline at end of file
@@ -0,0 +1,87 @@
+//! 模块和错误处理练习
+//!
+//! 把下面函数中的 `todo!()` 换成实现，然后运行 `rust_study check <练习>` 检查，
+//! 例如 `rust_study check modules_errors/parse_age`。
+
+use std::num::ParseIntError;
+
+use crate::check::{expect_eq, Case, Exercise, Mismatch};
+
+#[derive(Debug, PartialEq)]
+pub enum AgeError {
+    // 不是数字
+    Invalid,
+    // 超过 150 岁
+    TooOld(u32),
+}
+
+// 练习 1：把字符串解析为年龄，不是数字返回 AgeError::Invalid，超过 150 返回 AgeError::TooOld
+// （参考 error_handling 课中的 parse_positive_number）
+pub fn parse_age(s: &str) -> Result<u32, AgeError> {
+    todo!()
+}
+
+// 练习 2：把两个字符串解析为整数并相加，任何一个解析失败都把错误返回给调用者
+// （参考 error_handling 课中的 ? 运算符）
+pub fn add_strings(a: &str, b: &str) -> Result<i32, ParseIntError> {
+    todo!()
+}
+
+pub static EXERCISES: &[Exercise] = &[
+    Exercise {
+        id: "parse_age",
+        topic: "modules_errors",
+        lesson: "error_handling",
+        title: "自定义错误",
+        title_en: "Custom errors",
+        cases: &[
+            Case {
+                name: "合法的年龄",
+                name_en: "A valid age",
+                hint: "s.trim().parse::<u32>() 返回 Result，成功时返回 Ok(age)",
+                hint_en: "s.trim().parse::<u32>() returns a Result; return Ok(age) on success",
+                run: || expect_eq(parse_age(" 30 "), Ok(30)),
+            },
+            Case {
+                name: "不是数字",
+                name_en: "Not a number",
+                hint: "用 map_err(|_| AgeError::Invalid) 把 ParseIntError 转换为自己的错误类型，再用 ?",
+                hint_en: "Turn the ParseIntError into your own error type with map_err(|_| AgeError::Invalid), then use ?",
+                run: || expect_eq(parse_age("三十"), Err(AgeError::Invalid)),
+            },
+            Case {
+                name: "超出范围",
+                name_en: "Out of range",
+                hint: "解析成功后再检查 age > 150，返回 Err(AgeError::TooOld(age))",
+                hint_en: "After parsing, check age > 150 and return Err(AgeError::TooOld(age))",
+                run: || expect_eq(parse_age("200"), Err(AgeError::TooOld(200))),
+            },
+        ],
+    },
+    Exercise {
+        id: "add_strings",
+        topic: "modules_errors",
+        lesson: "error_handling",
+        title: "? 运算符",
+        title_en: "The ? operator",
+        cases: &[
+            Case {
+                name: "两个数字",
+                name_en: "Two numbers",
+                hint: "let a: i32 = a.parse()?; 解析失败时 ? 会直接返回错误",
+                hint_en: "let a: i32 = a.parse()?; when parsing fails, ? returns the error right away",
+                run: || expect_eq(add_strings("2", "40"), Ok(42)),
+            },
+            Case {
+                name: "第二个不是数字",
+                name_en: "Second is not a number",
+                hint: "不要 unwrap，解析失败应当返回 Err 而不是 panic",
+                hint_en: "Don't unwrap; a parse failure should return Err, not panic",
+                run: || match add_strings("2", "forty") {
+                    Err(_) => Ok(()),
+                    Ok(sum) => Err(Mismatch { expected: String::from("Err(..)"), actual: format!("Ok({})", sum) }),
+                },
+            },
+        ],
+    },
+];
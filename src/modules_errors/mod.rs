@@ -5,11 +5,6 @@
 // 声明子模块
 pub mod modules;
 pub mod error_handling;
-
-pub fn run_all() {
-    println!("\n--- 模块系统示例 ---");
-    modules::main();
-
-    println!("\n--- 错误处理示例 ---");
-    error_handling::main();
-}
\ No newline at end of file
+pub mod advanced_error_handling;
+pub mod exercises;
+pub mod quiz;
@@ -6,30 +6,42 @@
 //! - 模块（Modules）和 use：控制作用域和路径的私有性
 //! - 路径（Path）：命名项（如结构体、函数、模块）的方式
 
+use std::io::Write;
+
+use crate::context::Ctx;
+
 // 声明子模块
 mod front_of_house {
     // 模块可以包含其他模块
     pub mod hosting {
-        pub fn add_to_waitlist() {
-            println!("添加到等待列表");
+        use std::io::{self, Write};
+
+        use crate::context::Ctx;
+
+        pub fn add_to_waitlist(ctx: &mut Ctx) -> io::Result<()> {
+            writeln!(ctx, "添加到等待列表")
         }
 
-        fn seat_at_table() {
-            println!("安排座位");
+        fn seat_at_table(ctx: &mut Ctx) -> io::Result<()> {
+            writeln!(ctx, "安排座位")
         }
     }
 
     mod serving {
-        fn take_order() {
-            println!("接受点餐");
+        use std::io::{self, Write};
+
+        use crate::context::Ctx;
+
+        fn take_order(ctx: &mut Ctx) -> io::Result<()> {
+            writeln!(ctx, "接受点餐")
         }
 
-        fn serve_order() {
-            println!("上菜");
+        fn serve_order(ctx: &mut Ctx) -> io::Result<()> {
+            writeln!(ctx, "上菜")
         }
 
-        fn take_payment() {
-            println!("收款");
+        fn take_payment(ctx: &mut Ctx) -> io::Result<()> {
+            writeln!(ctx, "收款")
         }
     }
 }
@@ -42,11 +54,15 @@ use self::front_of_house::hosting;
 // 或者
 // use front_of_house::hosting;
 
-fn deliver_order() {
-    println!("送餐");
+fn deliver_order(ctx: &mut Ctx) -> io::Result<()> {
+    writeln!(ctx, "送餐")
 }
 
 mod back_of_house {
+    use std::io::{self, Write};
+
+    use crate::context::Ctx;
+
     // 结构体字段默认是私有的
     pub struct Breakfast {
         pub toast: String,      // 公共字段
@@ -70,13 +86,13 @@ mod back_of_house {
     }
 
     // 私有函数
-    fn fix_incorrect_order() {
-        cook_order();
-        super::deliver_order(); // 使用 super 访问父模块
+    fn fix_incorrect_order(ctx: &mut Ctx) -> io::Result<()> {
+        cook_order(ctx)?;
+        super::deliver_order(ctx) // 使用 super 访问父模块
     }
 
-    fn cook_order() {
-        println!("烹饪");
+    fn cook_order(ctx: &mut Ctx) -> io::Result<()> {
+        writeln!(ctx, "烹饪")
     }
 }
 
@@ -98,90 +114,104 @@ use std::{cmp::Ordering, io};
 // 通配符导入（谨慎使用）
 // use std::collections::*;
 
-pub fn main() {
-    println!("=== 模块系统示例 ===");
+pub fn main(ctx: &mut Ctx) -> io::Result<()> {
+    writeln!(ctx, "=== {} ===", ctx.text("lesson.modules"))?;
 
-    // 1. 使用模块中的函数
-    hosting::add_to_waitlist();
+    ctx.section(1, "使用模块中的函数", "Using functions from modules")?;
+    hosting::add_to_waitlist(ctx)?;
     // hosting::seat_at_table(); // 错误：私有函数
 
-    // 2. 使用结构体
+    ctx.section(2, "使用结构体", "Using structs")?;
     let mut meal = back_of_house::Breakfast::summer("Rye");
     meal.toast = String::from("Wheat");
-    println!("我要 {} 吐司", meal.toast);
+    writeln!(ctx, "我要 {} 吐司", meal.toast)?;
 
     // meal.seasonal_fruit = String::from("blueberries"); // 错误：私有字段
 
-    // 3. 使用枚举
+    ctx.section(3, "使用枚举", "Using enums")?;
     let order1 = back_of_house::Appetizer::Soup;
     let order2 = back_of_house::Appetizer::Salad;
 
-    // 4. 使用重导出的模块
-    pub_hosting::add_to_waitlist();
+    ctx.section(4, "使用重导出的模块", "Using re-exported modules")?;
+    pub_hosting::add_to_waitlist(ctx)?;
 
-    // 5. 标准库模块使用示例
+    ctx.section(5, "标准库模块使用示例", "Using standard library modules")?;
     let mut guess = String::new();
-    io::stdin()
-        .read_line(&mut guess)
-        .expect("读取行失败");
+    ctx.read_line(&mut guess)?;
 
     match guess.trim().parse::<i32>() {
         Ok(num) => match num.cmp(&42) {
-            Ordering::Less => println!("太小"),
-            Ordering::Greater => println!("太大"),
-            Ordering::Equal => println!("正确!"),
+            Ordering::Less => writeln!(ctx, "太小")?,
+            Ordering::Greater => writeln!(ctx, "太大")?,
+            Ordering::Equal => writeln!(ctx, "正确!")?,
         },
-        Err(_) => println!("请输入数字!"),
+        Err(_) => writeln!(ctx, "请输入数字!")?,
     }
 
-    // 6. 模块组织最佳实践
+    ctx.section(6, "模块组织最佳实践", "Module organization best practices")?;
     // - 相关功能组织在一起
     // - 减少耦合
     // - 提高可重用性
     // - 控制可见性
 
-    // 7. 文件系统模块
+    ctx.section(7, "文件系统模块", "File system modules")?;
     // 当模块变大时，可以拆分成单独的文件
     // mod garden; // 这会查找 src/garden.rs 或 src/garden/mod.rs
+
+    Ok(())
 }
 
 // 示例：模块可见性规则
 mod visibility_example {
+    use std::io::{self, Write};
+
+    use crate::context::Ctx;
+
     mod outer_module {
+        use std::io::{self, Write};
+
+        use crate::context::Ctx;
+
         pub mod inner_module {
-            pub fn public_function() {
-                println!("公共函数");
+            use std::io::{self, Write};
+
+            use crate::context::Ctx;
+
+            pub fn public_function(ctx: &mut Ctx) -> io::Result<()> {
+                writeln!(ctx, "公共函数")
             }
 
-            fn private_function() {
-                println!("私有函数");
+            fn private_function(ctx: &mut Ctx) -> io::Result<()> {
+                writeln!(ctx, "私有函数")
             }
 
-            pub fn indirect_access() {
-                print!("间接访问: ");
-                private_function();
+            pub fn indirect_access(ctx: &mut Ctx) -> io::Result<()> {
+                write!(ctx, "间接访问: ")?;
+                private_function(ctx)
             }
         }
 
-        pub fn call_public_function() {
-            print!("从 outer_module 调用: ");
-            inner_module::public_function();
-            print!("从 outer_module 间接调用: ");
-            inner_module::indirect_access();
+        pub fn call_public_function(ctx: &mut Ctx) -> io::Result<()> {
+            write!(ctx, "从 outer_module 调用: ")?;
+            inner_module::public_function(ctx)?;
+            write!(ctx, "从 outer_module 间接调用: ")?;
+            inner_module::indirect_access(ctx)
         }
     }
 
-    pub fn test_visibility() {
-        println!("\n=== 可见性测试 ===");
+    pub fn test_visibility(ctx: &mut Ctx) -> io::Result<()> {
+        writeln!(ctx, "\n=== {} ===", ctx.text("lesson.modules.visibility"))?;
 
         // 可以访问 outer_module 的公共函数
-        outer_module::call_public_function();
+        outer_module::call_public_function(ctx)?;
 
         // 可以直接访问 inner_module 的公共函数
-        outer_module::inner_module::public_function();
+        outer_module::inner_module::public_function(ctx)?;
 
         // 不能访问私有函数
         // outer_module::inner_module::private_function(); // 错误
+
+        Ok(())
     }
 }
 
@@ -261,7 +291,7 @@ pub mod math {
     /// # 示例
     ///
     /// ```
-    /// use my_crate::math::add;
+    /// use rust_study::modules_errors::modules::math::add;
     /// assert_eq!(add(2, 3), 5);
     /// ```
     pub fn add(a: i32, b: i32) -> i32 {
@@ -0,0 +1,86 @@
+//! 模块和错误处理小测验
+//!
+//! 预测输出题调用 error_handling 课中的函数，答案以实际运行结果为准。
+
+use std::io::{self, Write};
+
+use crate::context::Ctx;
+use crate::quiz::{Answer, Question};
+
+use super::error_handling::{parse_and_find_even, parse_positive_number};
+
+pub static QUESTIONS: &[Question] = &[
+    Question {
+        topic: "modules_errors",
+        lesson: "error_handling",
+        prompt: "下面的代码输出什么？",
+        prompt_en: "What does this code print?",
+        code: "match parse_positive_number(\"-5\") {\n    Ok(n) => println!(\"{}\", n),\n    Err(e) => println!(\"错误: {}\", e),\n}",
+        options: &["-5", "错误: 数字不能为负数", "错误: 无效的数字: -5", "5"],
+        options_en: &["-5", "错误: 数字不能为负数", "错误: 无效的数字: -5", "5"],
+        answer: Answer::Output(negative_number),
+        explanation: "\"-5\" 能解析为 i32，所以不是 InvalidNumber；随后的检查发现它小于 0，返回 NegativeNumber。",
+        explanation_en: "\"-5\" parses as an i32, so it isn't InvalidNumber; the following check finds it below 0 and returns NegativeNumber.",
+    },
+    Question {
+        topic: "modules_errors",
+        lesson: "error_handling",
+        prompt: "下面的代码输出什么？",
+        prompt_en: "What does this code print?",
+        code: "println!(\"{:?}\", parse_and_find_even(&[\"1\", \"3\", \"5\"]));",
+        options: &["None", "Ok(None)", "Err(None)", "Ok(Some(0))"],
+        options_en: &["None", "Ok(None)", "Err(None)", "Ok(Some(0))"],
+        answer: Answer::Output(|ctx| writeln!(ctx, "{:?}", parse_and_find_even(&["1", "3", "5"]))),
+        explanation: "全部解析成功所以是 Ok；没有偶数只是 None，不算错误。Result<Option<T>, E> 区分了这两种情况。",
+        explanation_en: "Everything parsed, so the result is Ok; having no even number is just None, not an error. Result<Option<T>, E> tells the two cases apart.",
+    },
+    Question {
+        topic: "modules_errors",
+        lesson: "error_handling",
+        prompt: "在返回 Result 的函数中，表达式 `s.parse::<i32>()?` 遇到 Err 时会怎样？",
+        prompt_en: "In a function returning Result, what does the expression `s.parse::<i32>()?` do when it meets an Err?",
+        code: "",
+        options: &[
+            "panic 并打印错误",
+            "把错误（必要时经 From 转换）作为函数的返回值提前返回",
+            "返回 0 并继续执行",
+            "忽略错误，跳过这一行",
+        ],
+        options_en: &[
+            "Panics and prints the error",
+            "Returns early with the error (converted through From if needed) as the function's result",
+            "Returns 0 and keeps going",
+            "Ignores the error and skips the line",
+        ],
+        answer: Answer::Choice(1),
+        explanation: "? 在 Ok 时取出值，在 Err 时立刻 return Err(From::from(e))，把错误交给调用者处理。",
+        explanation_en: "? takes the value out of Ok, and on Err it immediately does return Err(From::from(e)), handing the error to the caller.",
+    },
+    Question {
+        topic: "modules_errors",
+        lesson: "modules",
+        prompt: "模块中的函数默认是什么可见性？",
+        prompt_en: "What visibility does a function in a module have by default?",
+        code: "mod front_of_house {\n    fn add_to_waitlist() {}\n}",
+        options: &[
+            "公有，任何地方都能调用",
+            "私有，只有当前模块及其子模块能调用",
+            "只在同一个文件中公有",
+        ],
+        options_en: &[
+            "Public, callable from anywhere",
+            "Private, callable only from the current module and its children",
+            "Public within the same file only",
+        ],
+        answer: Answer::Choice(1),
+        explanation: "Rust 中所有条目默认私有，需要用 pub 公开；子模块可以使用祖先模块中的私有条目。",
+        explanation_en: "Every item in Rust is private by default and must be made public with pub; child modules can use private items of their ancestors.",
+    },
+];
+
+fn negative_number(ctx: &mut Ctx) -> io::Result<()> {
+    match parse_positive_number("-5") {
+        Ok(n) => writeln!(ctx, "{}", n),
+        Err(e) => writeln!(ctx, "错误: {}", e),
+    }
+}
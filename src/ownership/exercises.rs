@@ -0,0 +1,98 @@
+//! 所有权和借用练习
+//!
+//! 把下面函数中的 `todo!()` 换成实现，然后运行 `rust_study check <练习>` 检查，
+//! 例如 `rust_study check ownership/first_word`。
+
+use crate::check::{expect_eq, Case, Exercise};
+
+// 练习 1：返回字符串中的第一个单词（以空格分隔），返回的是原字符串的切片
+// （参考 references_borrowing 课）
+pub fn first_word(s: &str) -> &str {
+    todo!()
+}
+
+// 练习 2：通过可变引用在字符串末尾加上 "!"（参考 references_borrowing 课中的 change）
+pub fn exclaim(s: &mut String) {
+    todo!()
+}
+
+// 练习 3：拿走字符串的所有权，返回它的大写形式和原来的长度
+// （参考 ownership_basics 课中的 calculate_length）
+pub fn shout(s: String) -> (String, usize) {
+    todo!()
+}
+
+pub static EXERCISES: &[Exercise] = &[
+    Exercise {
+        id: "first_word",
+        topic: "ownership",
+        lesson: "references_borrowing",
+        title: "第一个单词",
+        title_en: "First word",
+        cases: &[
+            Case {
+                name: "两个单词",
+                name_en: "Two words",
+                hint: "遍历 s.as_bytes()，遇到 b' ' 时返回 &s[..i]",
+                hint_en: "Walk s.as_bytes() and return &s[..i] at the first b' '",
+                run: || expect_eq(first_word("hello world"), "hello"),
+            },
+            Case {
+                name: "只有一个单词",
+                name_en: "Only one word",
+                hint: "没有空格时整个字符串就是第一个单词，返回 &s[..]",
+                hint_en: "Without a space the whole string is the first word; return &s[..]",
+                run: || expect_eq(first_word("rust"), "rust"),
+            },
+            Case {
+                name: "空字符串",
+                name_en: "Empty string",
+                hint: "空字符串的第一个单词也是空字符串",
+                hint_en: "The first word of an empty string is the empty string",
+                run: || expect_eq(first_word(""), ""),
+            },
+        ],
+    },
+    Exercise {
+        id: "exclaim",
+        topic: "ownership",
+        lesson: "references_borrowing",
+        title: "可变引用",
+        title_en: "Mutable references",
+        cases: &[Case {
+            name: "末尾加上感叹号",
+            name_en: "Appends an exclamation mark",
+            hint: "s 是 &mut String，可以直接调用 s.push('!') 或 s.push_str(\"!\")",
+            hint_en: "s is a &mut String, so you can call s.push('!') or s.push_str(\"!\") directly",
+            run: || {
+                let mut s = String::from("hello");
+                exclaim(&mut s);
+                exclaim(&mut s);
+                expect_eq(s.as_str(), "hello!!")
+            },
+        }],
+    },
+    Exercise {
+        id: "shout",
+        topic: "ownership",
+        lesson: "ownership_basics",
+        title: "转移所有权",
+        title_en: "Taking ownership",
+        cases: &[
+            Case {
+                name: "返回大写和长度",
+                name_en: "Returns the uppercase string and the length",
+                hint: "先用 s.len() 取得长度，再用 s.to_uppercase() 得到新的 String，一起放进元组返回",
+                hint_en: "Take the length with s.len(), get a new String from s.to_uppercase(), and return both in a tuple",
+                run: || expect_eq(shout(String::from("hello")), (String::from("HELLO"), 5)),
+            },
+            Case {
+                name: "长度按字节计算",
+                name_en: "Length counted in bytes",
+                hint: "String::len 返回字节数，一个中文字符占 3 个字节",
+                hint_en: "String::len returns the number of bytes; a Chinese character takes 3 bytes",
+                run: || expect_eq(shout(String::from("你好")), (String::from("你好"), 6)),
+            },
+        ],
+    },
+];
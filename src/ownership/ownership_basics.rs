@@ -6,59 +6,65 @@
 //! 2. 值在任一时刻有且只有一个所有者。
 //! 3. 当所有者离开作用域，这个值将被丢弃。
 
-pub fn main() {
-    println!("=== 所有权基础 ===");
+use std::io::{self, Write};
 
-    // 1. 变量作用域
+use crate::context::Ctx;
+
+pub fn main(ctx: &mut Ctx) -> io::Result<()> {
+    writeln!(ctx, "=== {} ===", ctx.text("lesson.ownership_basics"))?;
+
+    ctx.section(1, "变量作用域", "Variable scope")?;
     {
         let s = "hello"; // s 从这里开始有效
-        println!("s = {}", s);
+        writeln!(ctx, "s = {}", s)?;
     } // s 离开作用域，不再有效
 
-    // 2. String 类型（堆上分配）
+    ctx.section(2, "String 类型（堆上分配）", "The String type (heap allocated)")?;
     let mut s = String::from("hello");
     s.push_str(", world!");
-    println!("{}", s);
+    writeln!(ctx, "{}", s)?;
 
-    // 3. 移动（Move）
+    ctx.section(3, "移动（Move）", "Moves")?;
     let s1 = String::from("hello");
     let s2 = s1; // s1 的所有权移动到 s2，s1 不再有效
     // println!("{}", s1); // 错误：s1 不再有效
-    println!("s2 = {}", s2);
+    writeln!(ctx, "s2 = {}", s2)?;
 
-    // 4. 克隆（Clone） - 深度拷贝
+    ctx.section(4, "克隆（Clone） - 深度拷贝", "Clone: a deep copy")?;
     let s1 = String::from("hello");
     let s2 = s1.clone(); // 创建数据的完整拷贝
-    println!("s1 = {}, s2 = {}", s1, s2);
+    writeln!(ctx, "s1 = {}, s2 = {}", s1, s2)?;
 
-    // 5. 栈上数据的拷贝（Copy trait）
+    ctx.section(5, "栈上数据的拷贝（Copy trait）", "Copying stack data (the Copy trait)")?;
     let x = 5;
     let y = x; // x 是整数，实现了 Copy trait，所以是拷贝而不是移动
-    println!("x = {}, y = {}", x, y);
+    writeln!(ctx, "x = {}, y = {}", x, y)?;
 
-    // 6. 函数与所有权
+    ctx.section(6, "函数与所有权", "Functions and ownership")?;
     let s = String::from("hello");
-    takes_ownership(s); // s 的所有权移动到函数中
+    takes_ownership(ctx, s)?; // s 的所有权移动到函数中
     // println!("{}", s); // 错误：s 不再有效
 
     let x = 5;
-    makes_copy(x); // x 是 Copy 类型，所以是拷贝
-    println!("x 仍然有效: {}", x); // x 仍然有效
+    makes_copy(ctx, x)?; // x 是 Copy 类型，所以是拷贝
+    writeln!(ctx, "x 仍然有效: {}", x)?; // x 仍然有效
 
-    // 7. 返回值与所有权
+    ctx.section(7, "返回值与所有权", "Return values and ownership")?;
     let s1 = gives_ownership(); // 函数返回值所有权转移给 s1
     let s2 = String::from("hello");
     let s3 = takes_and_gives_back(s2); // s2 所有权转移，然后返回
-    println!("s1 = {}, s3 = {}", s1, s3);
+    writeln!(ctx, "s1 = {}, s3 = {}", s1, s3)?;
     // println!("s2 = {}", s2); // 错误：s2 不再有效
+
+    Ok(())
 }
 
-fn takes_ownership(some_string: String) {
-    println!("获取所有权: {}", some_string);
+fn takes_ownership(ctx: &mut Ctx, some_string: String) -> io::Result<()> {
+    writeln!(ctx, "获取所有权: {}", some_string)
 } // some_string 离开作用域，drop 被调用，内存被释放
 
-fn makes_copy(some_integer: i32) {
-    println!("拷贝整数: {}", some_integer);
+fn makes_copy(ctx: &mut Ctx, some_integer: i32) -> io::Result<()> {
+    writeln!(ctx, "拷贝整数: {}", some_integer)
 } // some_integer 离开作用域，没什么特别的事情发生
 
 fn gives_ownership() -> String {
@@ -0,0 +1,87 @@
+//! 所有权和借用小测验
+//!
+//! 预测输出题调用 references_borrowing 课中的函数，答案以实际运行结果为准。
+
+use std::io::{self, Write};
+
+use crate::context::Ctx;
+use crate::quiz::{Answer, Question};
+
+use super::references_borrowing::first_word;
+
+pub static QUESTIONS: &[Question] = &[
+    Question {
+        topic: "ownership",
+        lesson: "ownership_basics",
+        prompt: "这段代码会移动 s1 吗？能否编译？",
+        prompt_en: "Does this code move s1? Does it compile?",
+        code: "let s1 = String::from(\"hello\");\nlet s2 = s1;\nprintln!(\"{}\", s1);",
+        options: &[
+            "不会移动，输出 hello",
+            "会移动：s1 的所有权转给 s2，之后使用 s1 是编译错误",
+            "会复制一份，s1 和 s2 各自拥有数据",
+            "可以编译，但运行时 panic",
+        ],
+        options_en: &[
+            "No move, it prints hello",
+            "It moves: ownership of s1 goes to s2, and using s1 afterwards is a compile error",
+            "It makes a copy, so s1 and s2 each own their data",
+            "It compiles but panics at run time",
+        ],
+        answer: Answer::Choice(1),
+        explanation: "String 没有实现 Copy，let s2 = s1 把所有权移动给 s2，再使用 s1 会得到 E0382（borrow of moved value）。",
+        explanation_en: "String doesn't implement Copy, so let s2 = s1 moves ownership to s2; using s1 again gives E0382 (borrow of moved value).",
+    },
+    Question {
+        topic: "ownership",
+        lesson: "ownership_basics",
+        prompt: "这段代码会移动 x 吗？能否编译？",
+        prompt_en: "Does this code move x? Does it compile?",
+        code: "let x = 5;\nlet y = x;\nprintln!(\"{} {}\", x, y);",
+        options: &[
+            "会移动，使用 x 是编译错误",
+            "不会移动：i32 实现了 Copy，赋值时复制，输出 5 5",
+            "不会移动，但 y 是 x 的引用",
+        ],
+        options_en: &[
+            "It moves, and using x is a compile error",
+            "No move: i32 implements Copy, so the assignment copies and it prints 5 5",
+            "No move, but y is a reference to x",
+        ],
+        answer: Answer::Choice(1),
+        explanation: "整数等只存在栈上的简单类型实现了 Copy，赋值后原变量仍然可用。",
+        explanation_en: "Simple types that live entirely on the stack, such as integers, implement Copy, so the original variable stays usable after assignment.",
+    },
+    Question {
+        topic: "ownership",
+        lesson: "references_borrowing",
+        prompt: "下面的代码输出什么？",
+        prompt_en: "What does this code print?",
+        code: "let s = String::from(\"hello world\");\nprintln!(\"[{}]\", first_word(&s));",
+        options: &["[hello world]", "[hello]", "[world]", "[h]"],
+        options_en: &["[hello world]", "[hello]", "[world]", "[h]"],
+        answer: Answer::Output(|ctx| writeln!(ctx, "[{}]", first_word(&String::from("hello world")))),
+        explanation: "first_word 找到第一个空格，返回它之前的切片 &s[0..i]，切片借用了 s 的一部分。",
+        explanation_en: "first_word finds the first space and returns the slice before it, &s[0..i]; the slice borrows part of s.",
+    },
+    Question {
+        topic: "ownership",
+        lesson: "references_borrowing",
+        prompt: "同一作用域中，下面的代码能否编译？",
+        prompt_en: "In a single scope, does this code compile?",
+        code: "let mut s = String::from(\"hello\");\nlet r1 = &mut s;\nlet r2 = &mut s;\nprintln!(\"{}, {}\", r1, r2);",
+        options: &[
+            "可以，两个可变引用指向同一个字符串",
+            "不可以：同一时间只能有一个可变引用",
+            "可以，但 r1 会自动失效",
+        ],
+        options_en: &[
+            "Yes, two mutable references point to the same string",
+            "No: there can be only one mutable reference at a time",
+            "Yes, but r1 is invalidated automatically",
+        ],
+        answer: Answer::Choice(1),
+        explanation: "借用规则：同一时间要么只有一个可变引用，要么有任意多个不可变引用（E0499）。",
+        explanation_en: "The borrowing rules allow either one mutable reference or any number of immutable references at a time (E0499).",
+    },
+];
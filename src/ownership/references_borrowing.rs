@@ -6,83 +6,89 @@
 //! 1. 在任意给定时间，要么只能有一个可变引用，要么只能有多个不可变引用。
 //! 2. 引用必须总是有效的。
 
-pub fn main() {
-    println!("=== 引用和借用 ===");
+use std::io::{self, Write};
 
-    // 1. 不可变引用
+use crate::context::Ctx;
+
+pub fn main(ctx: &mut Ctx) -> io::Result<()> {
+    writeln!(ctx, "=== {} ===", ctx.text("lesson.references_borrowing"))?;
+
+    ctx.section(1, "不可变引用", "Immutable references")?;
     let s1 = String::from("hello");
     let len = calculate_length(&s1); // 传递引用，不获取所有权
-    println!("'{}' 的长度是 {}", s1, len);
+    writeln!(ctx, "'{}' 的长度是 {}", s1, len)?;
 
-    // 2. 可变引用
+    ctx.section(2, "可变引用", "Mutable references")?;
     let mut s = String::from("hello");
     change(&mut s); // 传递可变引用
-    println!("修改后: {}", s);
+    writeln!(ctx, "修改后: {}", s)?;
 
-    // 3. 引用规则示例
+    ctx.section(3, "引用规则示例", "Examples of the reference rules")?;
     let mut s = String::from("hello");
 
     let r1 = &s; // 没问题：不可变引用
     let r2 = &s; // 没问题：多个不可变引用
-    println!("{} 和 {}", r1, r2);
+    writeln!(ctx, "{} 和 {}", r1, r2)?;
     // r1 和 r2 的作用域在这里结束
 
     let r3 = &mut s; // 没问题：没有不可变引用同时存在
-    println!("{}", r3);
+    writeln!(ctx, "{}", r3)?;
 
-    // 4. 数据竞争预防
+    ctx.section(4, "数据竞争预防", "Preventing data races")?;
     let mut s = String::from("hello");
 
     // let r1 = &mut s;
     // let r2 = &mut s; // 错误：不能同时有两个可变引用
     // println!("{}, {}", r1, r2);
 
-    // 5. 作用域的重要性
+    ctx.section(5, "作用域的重要性", "Why scope matters")?;
     let mut s = String::from("hello");
 
     {
         let r1 = &mut s;
-        println!("在内部作用域中: {}", r1);
+        writeln!(ctx, "在内部作用域中: {}", r1)?;
     } // r1 离开作用域，所以可以创建新的引用
 
     let r2 = &mut s;
-    println!("在外部作用域中: {}", r2);
+    writeln!(ctx, "在外部作用域中: {}", r2)?;
 
-    // 6. 不可变引用和可变引用不能同时存在
+    ctx.section(6, "不可变引用和可变引用不能同时存在", "Immutable and mutable references can't coexist")?;
     let mut s = String::from("hello");
 
     let r1 = &s; // 没问题：不可变引用
     let r2 = &s; // 没问题：不可变引用
     // let r3 = &mut s; // 错误：不能在有不可变引用的同时创建可变引用
-    println!("{} 和 {}", r1, r2);
+    writeln!(ctx, "{} 和 {}", r1, r2)?;
     // r1 和 r2 的作用域在这里结束
 
     let r3 = &mut s; // 没问题：不可变引用已经不再使用
-    println!("{}", r3);
+    writeln!(ctx, "{}", r3)?;
 
-    // 7. 悬垂引用（Dangling References）
+    ctx.section(7, "悬垂引用（Dangling References）", "Dangling references")?;
     // let reference_to_nothing = dangle(); // 错误：返回了悬垂引用
 
     let no_dangle = no_dangle(); // 正确：返回 String，转移所有权
-    println!("没有悬垂: {}", no_dangle);
+    writeln!(ctx, "没有悬垂: {}", no_dangle)?;
 
-    // 8. 切片引用
+    ctx.section(8, "切片引用", "Slice references")?;
     let s = String::from("hello world");
     let hello = &s[0..5];
     let world = &s[6..11];
-    println!("切片: '{}' 和 '{}'", hello, world);
+    writeln!(ctx, "切片: '{}' 和 '{}'", hello, world)?;
 
-    // 9. 结构体引用
+    ctx.section(9, "结构体引用", "Struct references")?;
     let mut point = Point { x: 0, y: 0 };
     let r = &mut point;
     r.x = 5;
     r.y = 10;
-    println!("点: ({}, {})", point.x, point.y);
+    writeln!(ctx, "点: ({}, {})", point.x, point.y)?;
 
-    // 10. 引用作为函数参数和返回值
+    ctx.section(10, "引用作为函数参数和返回值", "References as parameters and return values")?;
     let s = String::from("hello");
     let first_word = first_word(&s);
-    println!("第一个单词: {}", first_word);
+    writeln!(ctx, "第一个单词: {}", first_word)?;
+
+    Ok(())
 }
 
 fn calculate_length(s: &String) -> usize {
@@ -93,7 +99,7 @@ fn change(some_string: &mut String) {
     some_string.push_str(", world");
 }
 
-// 错误示例：悬垂引用
+// 错误示例：悬垂引用（见 tests/compile_fail/dangle.rs）
 // fn dangle() -> &String {
 //     let s = String::from("hello");
 //     &s // 错误：返回局部变量的引用
@@ -109,7 +115,7 @@ struct Point {
     y: i32,
 }
 
-fn first_word(s: &str) -> &str {
+pub fn first_word(s: &str) -> &str {
     let bytes = s.as_bytes();
 
     for (i, &item) in bytes.iter().enumerate() {
@@ -0,0 +1,57 @@
+//! 临时替换 panic 钩子
+//!
+//! panic 钩子是整个进程共享的。课程和练习检查有时预料到会 panic，需要暂时换掉默认钩子；
+//! 但测试会在多个线程中同时运行课程，各自 `take_hook`/`set_hook` 会相互交错：
+//! 一个线程可能恢复成另一个线程装上的静默钩子，或者吞掉别的测试真正的 panic 信息。
+//!
+//! 所以这里只在第一次使用时安装一个转发钩子，之后不再改动全局钩子。
+//! `with_hook` 只为当前线程登记一个临时钩子：当前线程 panic 时调用它，
+//! 其他线程 panic 时仍然调用原来的钩子。
+
+use std::cell::RefCell;
+use std::panic::{self, PanicHookInfo};
+use std::rc::Rc;
+use std::sync::Once;
+
+type Hook = Rc<dyn Fn(&PanicHookInfo<'_>)>;
+
+thread_local! {
+    // 当前线程的临时钩子；为 None 时使用原来的钩子
+    static THREAD_HOOK: RefCell<Option<Hook>> = const { RefCell::new(None) };
+}
+
+static INSTALL: Once = Once::new();
+
+// 在 f 运行期间，当前线程的 panic 交给 hook 处理；结束后（即使 f panic）恢复原样。
+// hook 在 panic 的线程里运行，f 中新建的线程 panic 时不会调用它
+pub fn with_hook<R>(hook: impl Fn(&PanicHookInfo<'_>) + 'static, f: impl FnOnce() -> R) -> R {
+    INSTALL.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            let hook = THREAD_HOOK.with(|hook| hook.try_borrow().ok().and_then(|hook| hook.clone()));
+            match hook {
+                Some(hook) => hook(info),
+                None => previous(info),
+            }
+        }));
+    });
+
+    let outer = THREAD_HOOK.with(|current| current.replace(Some(Rc::new(hook))));
+    let _restore = Restore(outer);
+    f()
+}
+
+// 在 f 运行期间，当前线程 panic 时不输出任何信息
+pub fn quietly<R>(f: impl FnOnce() -> R) -> R {
+    with_hook(|_| {}, f)
+}
+
+// 离开作用域时恢复外层的临时钩子，嵌套调用 with_hook 也能正确还原
+struct Restore(Option<Hook>);
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        let outer = self.0.take();
+        THREAD_HOOK.with(|current| *current.borrow_mut() = outer);
+    }
+}
@@ -0,0 +1,339 @@
+//! 学习进度
+//!
+//! 记录学习者完成了哪些课程、练习和小测验，保存在用户配置目录下的
+//! `rust_study/progress.tsv` 中，菜单据此显示每个主题的完成度。
+//!
+//! 文件每行一条记录，字段用制表符分隔，只追加不改写：
+//!
+//! ```text
+//! lesson    basics/functions   1760000000
+//! exercise  basics/fahrenheit  1760000000
+//! quiz      basics             1760000000  3/4
+//! ```
+//!
+//! 依次是类型、路径、完成时间（Unix 时间戳，秒）和得分（只有小测验有）。
+//! 以 # 开头的行和无法解析的行会被忽略。
+//!
+//! 环境变量 `RUST_STUDY_PROGRESS` 可以指定另一个文件；设为空字符串时不记录进度。
+
+use std::env;
+use std::fmt::Write as _;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::check;
+use crate::context::Ctx;
+use crate::quiz;
+use crate::registry::{self, Topic, TOPICS};
+use crate::runner;
+use crate::report;
+
+pub const SCHEMA: &str = "rust_study.progress";
+pub const SCHEMA_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    Lesson,
+    Exercise,
+    Quiz,
+}
+
+impl Kind {
+    pub fn tag(self) -> &'static str {
+        match self {
+            Kind::Lesson => "lesson",
+            Kind::Exercise => "exercise",
+            Kind::Quiz => "quiz",
+        }
+    }
+
+    fn parse(tag: &str) -> Option<Kind> {
+        match tag {
+            "lesson" => Some(Kind::Lesson),
+            "exercise" => Some(Kind::Exercise),
+            "quiz" => Some(Kind::Quiz),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Record {
+    pub kind: Kind,
+    // 课程和练习是 "主题/名称"，小测验是主题 id
+    pub id: String,
+    // Unix 时间戳，秒
+    pub time: u64,
+    // 小测验的得分：答对的题数和总题数
+    pub score: Option<(usize, usize)>,
+}
+
+impl Record {
+    fn to_line(&self) -> String {
+        let mut line = format!("{}\t{}\t{}", self.kind.tag(), self.id, self.time);
+        if let Some((correct, total)) = self.score {
+            let _ = write!(line, "\t{}/{}", correct, total);
+        }
+        line
+    }
+
+    fn parse(line: &str) -> Option<Record> {
+        let mut fields = line.split('\t');
+        let kind = Kind::parse(fields.next()?)?;
+        let id = fields.next()?.to_string();
+        let time = fields.next()?.parse().ok()?;
+        let score = match fields.next() {
+            Some(score) => {
+                let (correct, total) = score.split_once('/')?;
+                Some((correct.parse().ok()?, total.parse().ok()?))
+            }
+            None => None,
+        };
+        Some(Record {
+            kind,
+            id,
+            time,
+            score,
+        })
+    }
+}
+
+// 一个主题的完成情况，每项是（已完成，总数）
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Completion {
+    pub lessons: (usize, usize),
+    pub exercises: (usize, usize),
+    // 小测验按最好成绩中答对的题数计
+    pub quiz: (usize, usize),
+}
+
+impl Completion {
+    // 完成百分比，向下取整
+    pub fn percent(&self) -> usize {
+        let done = self.lessons.0 + self.exercises.0 + self.quiz.0;
+        let total = self.lessons.1 + self.exercises.1 + self.quiz.1;
+        (done * 100).checked_div(total).unwrap_or(0)
+    }
+}
+
+pub struct Progress {
+    path: PathBuf,
+    records: Vec<Record>,
+}
+
+impl Progress {
+    // 默认的进度文件；找不到配置目录或 RUST_STUDY_PROGRESS 为空时返回 None
+    pub fn default_path() -> Option<PathBuf> {
+        if let Some(path) = env::var_os("RUST_STUDY_PROGRESS") {
+            return if path.is_empty() { None } else { Some(PathBuf::from(path)) };
+        }
+        config_dir().map(|dir| dir.join("rust_study").join("progress.tsv"))
+    }
+
+    // 读取进度文件；文件不存在时从空白开始
+    pub fn load(path: PathBuf) -> io::Result<Progress> {
+        let records = match fs::read_to_string(&path) {
+            Ok(content) => content
+                .lines()
+                .filter(|line| !line.starts_with('#'))
+                .filter_map(Record::parse)
+                .collect(),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Vec::new(),
+            Err(e) => return Err(e),
+        };
+        Ok(Progress { path, records })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn records(&self) -> &[Record] {
+        &self.records
+    }
+
+    // 追加一条记录并立即写入文件
+    pub fn record(&mut self, kind: Kind, id: &str, score: Option<(usize, usize)>) -> io::Result<()> {
+        let record = Record {
+            kind,
+            id: id.to_string(),
+            time: now(),
+            score,
+        };
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let mut file = OpenOptions::new().create(true).append(true).open(&self.path)?;
+        writeln!(file, "{}", record.to_line())?;
+        self.records.push(record);
+        Ok(())
+    }
+
+    // 清空所有记录并删除文件
+    pub fn reset(&mut self) -> io::Result<()> {
+        self.records.clear();
+        match fs::remove_file(&self.path) {
+            Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e),
+            _ => Ok(()),
+        }
+    }
+
+    pub fn is_done(&self, kind: Kind, id: &str) -> bool {
+        self.records.iter().any(|record| record.kind == kind && record.id == id)
+    }
+
+    // 某个小测验的最好成绩
+    pub fn best_score(&self, id: &str) -> Option<(usize, usize)> {
+        self.records
+            .iter()
+            .filter(|record| record.kind == Kind::Quiz && record.id == id)
+            .filter_map(|record| record.score)
+            .max_by_key(|(correct, _)| *correct)
+    }
+
+    pub fn completion(&self, topic: &Topic) -> Completion {
+        let lessons: Vec<String> = registry::lessons_of(topic.id).map(|lesson| lesson.path()).collect();
+        let exercises: Vec<String> = check::exercises()
+            .filter(|exercise| exercise.topic == topic.id)
+            .map(|exercise| exercise.path())
+            .collect();
+        let questions = quiz::questions().filter(|question| question.topic == topic.id).count();
+
+        let done = |kind, paths: &[String]| paths.iter().filter(|path| self.is_done(kind, path)).count();
+        let quiz_done = self.best_score(topic.id).map_or(0, |(correct, _)| correct.min(questions));
+        Completion {
+            lessons: (done(Kind::Lesson, &lessons), lessons.len()),
+            exercises: (done(Kind::Exercise, &exercises), exercises.len()),
+            quiz: (quiz_done, questions),
+        }
+    }
+
+    // 导出为 JSON：{"schema","version","records":[{"kind","id","time","score"}]}，
+    // score 是 {"correct","total"} 或 null
+    pub fn to_json(&self) -> String {
+        let mut json = format!(
+            "{{\"schema\":{},\"version\":{},\"records\":[",
+            report::json_string(SCHEMA),
+            SCHEMA_VERSION
+        );
+        for (i, record) in self.records.iter().enumerate() {
+            if i > 0 {
+                json.push(',');
+            }
+            let score = match record.score {
+                Some((correct, total)) => format!("{{\"correct\":{},\"total\":{}}}", correct, total),
+                None => String::from("null"),
+            };
+            let _ = write!(
+                json,
+                "{{\"kind\":{},\"id\":{},\"time\":{},\"score\":{}}}",
+                report::json_string(record.kind.tag()),
+                report::json_string(&record.id),
+                record.time,
+                score
+            );
+        }
+        json.push_str("]}");
+        json
+    }
+}
+
+// progress：每个主题的完成情况和最近一次学习的时间
+pub fn show(ctx: &mut Ctx) -> io::Result<()> {
+    let Some(progress) = ctx.progress() else {
+        return writeln!(ctx, "{}", ctx.text("progress.disabled"));
+    };
+    let locale = ctx.locale();
+    let path = progress.path().display().to_string();
+    let rows: Vec<(String, Completion)> = TOPICS
+        .iter()
+        .map(|topic| (topic.title_in(locale).to_string(), progress.completion(topic)))
+        .collect();
+    let last = progress.records().iter().map(|record| record.time).max();
+
+    writeln!(ctx, "{}", ctx.message("progress.title", &[&path]))?;
+    for (title, completion) in rows {
+        let line = ctx.message(
+            "progress.topic",
+            &[
+                &runner::pad_right(&title, 36),
+                &format!("{:>3}", completion.percent()),
+                &completion.lessons.0,
+                &completion.lessons.1,
+                &completion.exercises.0,
+                &completion.exercises.1,
+                &completion.quiz.0,
+                &completion.quiz.1,
+            ],
+        );
+        writeln!(ctx, "{}", line)?;
+    }
+    match last {
+        Some(time) => writeln!(ctx, "{}", ctx.message("progress.last", &[&format_time(time)])),
+        None => writeln!(ctx, "{}", ctx.text("progress.empty")),
+    }
+}
+
+// progress reset：清空记录
+pub fn reset(ctx: &mut Ctx) -> io::Result<()> {
+    let Some(progress) = ctx.progress_mut() else {
+        return writeln!(ctx, "{}", ctx.text("progress.disabled"));
+    };
+    progress.reset()?;
+    let path = progress.path().display().to_string();
+    writeln!(ctx, "{}", ctx.message("progress.reset", &[&path]))
+}
+
+// progress export：以 JSON 输出全部记录
+pub fn export(ctx: &mut Ctx) -> io::Result<()> {
+    let Some(progress) = ctx.progress() else {
+        return writeln!(ctx, "{}", ctx.text("progress.disabled"));
+    };
+    let json = progress.to_json();
+    writeln!(ctx, "{}", json)
+}
+
+// 用户配置目录：Windows 上是 %APPDATA%，macOS 上是 ~/Library/Application Support，
+// 其他系统是 $XDG_CONFIG_HOME 或 ~/.config
+fn config_dir() -> Option<PathBuf> {
+    let non_empty = |name| env::var_os(name).filter(|value| !value.is_empty()).map(PathBuf::from);
+    if cfg!(windows) {
+        non_empty("APPDATA")
+    } else if cfg!(target_os = "macos") {
+        non_empty("HOME").map(|home| home.join("Library").join("Application Support"))
+    } else {
+        non_empty("XDG_CONFIG_HOME").or_else(|| non_empty("HOME").map(|home| home.join(".config")))
+    }
+}
+
+fn now() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |elapsed| elapsed.as_secs())
+}
+
+// 把 Unix 时间戳格式化为 "2024-01-31 08:00 UTC"
+pub fn format_time(time: u64) -> String {
+    let days = (time / 86_400) as i64;
+    let seconds = time % 86_400;
+
+    // 公历日期换算（Howard Hinnant 的 civil_from_days）
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02} UTC",
+        year,
+        month,
+        day,
+        seconds / 3600,
+        seconds % 3600 / 60
+    )
+}
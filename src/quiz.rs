@@ -0,0 +1,239 @@
+//! 小测验
+//!
+//! 每个主题目录下的 quiz.rs 中有一组题目：选择题，以及“预测输出”题。
+//! 预测输出题的正确答案不是写死的，而是运行题目附带的代码（调用课程中的函数）
+//! 得到的实际输出，这样课程代码改动后题目也不会悄悄过时。
+//!
+//! 答完每题都显示解释和相关课程，最后给出得分。
+//! 一个主题的题目全部答完时，这个主题的得分记入学习进度。
+
+use std::io::{self, Write};
+
+use crate::context::Ctx;
+use crate::i18n::Locale;
+use crate::progress::Kind;
+use crate::registry::{Target, TOPICS};
+use crate::{advanced, async_basics, basics, collections, concurrency, control_flow, iterators, macros, modules_errors, ownership, smart_pointers, structs_enums};
+
+pub struct Question {
+    pub topic: &'static str,
+    // 讲解相关内容的课程 id
+    pub lesson: &'static str,
+    pub prompt: &'static str,
+    pub prompt_en: &'static str,
+    // 题目中展示的代码，可以为空
+    pub code: &'static str,
+    pub options: &'static [&'static str],
+    // 与 options 一一对应；预测输出题的正确选项是实际输出，两种语言中相同
+    pub options_en: &'static [&'static str],
+    pub answer: Answer,
+    pub explanation: &'static str,
+    pub explanation_en: &'static str,
+}
+
+pub enum Answer {
+    // 选择题：正确选项的下标
+    Choice(usize),
+    // 预测输出：运行这段代码，输出（去掉末尾换行）与之相同的选项就是正确答案
+    Output(fn(&mut Ctx) -> io::Result<()>),
+}
+
+impl Question {
+    pub fn prompt_in(&self, locale: Locale) -> &'static str {
+        match locale {
+            Locale::ZhCn => self.prompt,
+            Locale::EnUs => self.prompt_en,
+        }
+    }
+
+    pub fn options_in(&self, locale: Locale) -> &'static [&'static str] {
+        match locale {
+            Locale::ZhCn => self.options,
+            Locale::EnUs => self.options_en,
+        }
+    }
+
+    pub fn explanation_in(&self, locale: Locale) -> &'static str {
+        match locale {
+            Locale::ZhCn => self.explanation,
+            Locale::EnUs => self.explanation_en,
+        }
+    }
+
+    // 预测输出题的实际输出；选择题返回 None
+    pub fn output(&self) -> Option<String> {
+        let Answer::Output(run) = self.answer else {
+            return None;
+        };
+        let mut out = Vec::new();
+        let mut input: &[u8] = &[];
+        let mut ctx = Ctx::new(&mut out, &mut input);
+        // 写入内存不会失败，代码本身出错时把错误当作输出
+        let output = match run(&mut ctx) {
+            Ok(()) => String::from_utf8_lossy(&out).trim_end().to_string(),
+            Err(e) => e.to_string(),
+        };
+        Some(output)
+    }
+
+    // 正确选项的下标；预测输出题的实际输出与所有选项都不同时返回 None
+    pub fn correct(&self) -> Option<usize> {
+        match self.answer {
+            Answer::Choice(index) => Some(index),
+            Answer::Output(_) => {
+                let output = self.output()?;
+                self.options.iter().position(|option| *option == output)
+            }
+        }
+    }
+}
+
+// 一次测验的得分
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Score {
+    pub correct: usize,
+    pub answered: usize,
+    pub total: usize,
+}
+
+// 所有题目，按主题顺序排列
+pub fn questions() -> impl Iterator<Item = &'static Question> {
+    [
+        basics::quiz::QUESTIONS,
+        control_flow::quiz::QUESTIONS,
+        ownership::quiz::QUESTIONS,
+        structs_enums::quiz::QUESTIONS,
+        collections::quiz::QUESTIONS,
+        modules_errors::quiz::QUESTIONS,
+        advanced::quiz::QUESTIONS,
+        concurrency::quiz::QUESTIONS,
+        smart_pointers::quiz::QUESTIONS,
+        iterators::quiz::QUESTIONS,
+        async_basics::quiz::QUESTIONS,
+        macros::quiz::QUESTIONS,
+    ]
+    .into_iter()
+    .flatten()
+}
+
+// 某个主题或某一课的题目
+pub fn questions_for(target: Target) -> Vec<&'static Question> {
+    questions()
+        .filter(|question| match target {
+            Target::Topic(topic) => question.topic == topic.id,
+            Target::Lesson(lesson) => question.topic == lesson.topic && question.lesson == lesson.id,
+        })
+        .collect()
+}
+
+// 依次提问；输入 q 或输入结束时提前交卷
+pub fn run(ctx: &mut Ctx, questions: &[&Question]) -> io::Result<Score> {
+    let mut score = Score {
+        total: questions.len(),
+        ..Score::default()
+    };
+    if questions.is_empty() {
+        writeln!(ctx, "{}", ctx.text("quiz.empty"))?;
+        return Ok(score);
+    }
+    // 每道已答题目是否答对，与 questions 一一对应
+    let mut results = Vec::new();
+
+    for (i, question) in questions.iter().enumerate() {
+        writeln!(ctx, "\n[{}/{}] {}", i + 1, questions.len(), question.prompt_in(ctx.locale()))?;
+        for line in question.code.lines() {
+            writeln!(ctx, "    {}", line)?;
+        }
+        for (letter, option) in letters().zip(question.options_in(ctx.locale())) {
+            write_option(ctx, letter, option)?;
+        }
+
+        let Some(choice) = ask(ctx, question.options.len())? else {
+            break;
+        };
+        score.answered += 1;
+
+        let correct = question.correct();
+        results.push(correct == Some(choice));
+        if correct == Some(choice) {
+            score.correct += 1;
+            writeln!(ctx, "{}", ctx.text("quiz.correct"))?;
+        } else {
+            let letter = correct.and_then(|index| letters().nth(index)).unwrap_or('?');
+            writeln!(ctx, "{}", ctx.message("quiz.wrong", &[&letter]))?;
+        }
+        if let Some(output) = question.output() {
+            writeln!(ctx, "{}", ctx.text("quiz.actual_output"))?;
+            for line in output.lines() {
+                writeln!(ctx, "    {}", line)?;
+            }
+        }
+        writeln!(ctx, "{}", ctx.message("quiz.explanation", &[&question.explanation_in(ctx.locale())]))?;
+        let lesson = format!("{}/{}", question.topic, question.lesson);
+        writeln!(ctx, "{}", ctx.message("quiz.see_lesson", &[&lesson]))?;
+    }
+
+    let totals = ctx.message("quiz.score", &[&score.correct, &score.total]);
+    writeln!(ctx, "\n{}", totals)?;
+    record_topics(ctx, questions, &results)?;
+    Ok(score)
+}
+
+// 记录题目全部答完的主题的得分
+fn record_topics(ctx: &mut Ctx, questions: &[&Question], results: &[bool]) -> io::Result<()> {
+    for topic in TOPICS {
+        let total = questions_for(Target::Topic(topic)).len();
+        let answered: Vec<bool> = questions
+            .iter()
+            .zip(results)
+            .filter(|(question, _)| question.topic == topic.id)
+            .map(|(_, correct)| *correct)
+            .collect();
+        if total > 0 && answered.len() == total {
+            let correct = answered.iter().filter(|correct| **correct).count();
+            ctx.record_progress(Kind::Quiz, topic.id, Some((correct, total)))?;
+        }
+    }
+    Ok(())
+}
+
+fn letters() -> impl Iterator<Item = char> {
+    'a'..='z'
+}
+
+// 多行选项从第二行起与第一行对齐
+fn write_option(ctx: &mut Ctx, letter: char, option: &str) -> io::Result<()> {
+    for (i, line) in option.lines().enumerate() {
+        if i == 0 {
+            writeln!(ctx, "  {}) {}", letter, line)?;
+        } else {
+            writeln!(ctx, "     {}", line)?;
+        }
+    }
+    Ok(())
+}
+
+// 读取答案，返回选项下标；输入 q 或输入结束时返回 None
+fn ask(ctx: &mut Ctx, count: usize) -> io::Result<Option<usize>> {
+    let last = letters().nth(count - 1).unwrap_or('z');
+    loop {
+        write!(ctx, "{}", ctx.message("quiz.prompt", &[&last]))?;
+        let mut line = String::new();
+        if ctx.read_line(&mut line)? == 0 {
+            writeln!(ctx)?;
+            return Ok(None);
+        }
+
+        let answer = line.trim().to_lowercase();
+        if answer == "q" {
+            return Ok(None);
+        }
+        let mut chars = answer.chars();
+        if let (Some(letter), None) = (chars.next(), chars.next())
+            && let Some(index) = letters().take(count).position(|c| c == letter)
+        {
+            return Ok(Some(index));
+        }
+        writeln!(ctx, "{}", ctx.message("quiz.invalid", &[&line.trim()]))?;
+    }
+}
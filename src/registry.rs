@@ -0,0 +1,702 @@
+//! 示例注册表
+//!
+//! 集中描述每个主题和课程。菜单、运行全部、列表等功能都从这里派生，
+//! 新增一课只需要在 `LESSONS` 中添加一项。
+//! 标题和说明各有中文与英文两份，界面按当前语言选用。
+
+use std::fmt;
+use std::io;
+
+use crate::context::Ctx;
+use crate::i18n::Locale;
+use crate::{advanced, async_basics, basics, collections, concurrency, control_flow, iterators, macros, modules_errors, ownership, smart_pointers, structs_enums};
+
+// 主题：对应 src 下的一个子目录
+pub struct Topic {
+    pub id: &'static str,
+    pub title: &'static str,
+    pub title_en: &'static str,
+    pub description: &'static str,
+    pub description_en: &'static str,
+}
+
+impl Topic {
+    pub fn title_in(&self, locale: Locale) -> &'static str {
+        match locale {
+            Locale::ZhCn => self.title,
+            Locale::EnUs => self.title_en,
+        }
+    }
+
+    pub fn description_in(&self, locale: Locale) -> &'static str {
+        match locale {
+            Locale::ZhCn => self.description,
+            Locale::EnUs => self.description_en,
+        }
+    }
+}
+
+// 课程：对应主题目录中的一个文件及其入口函数
+pub struct Lesson {
+    pub id: &'static str,
+    pub topic: &'static str,
+    pub title: &'static str,
+    pub title_en: &'static str,
+    pub description: &'static str,
+    pub description_en: &'static str,
+    pub run: fn(&mut Ctx) -> io::Result<()>,
+    // 课程文件的内容，编译时嵌入，供 show 命令显示
+    pub source: &'static str,
+}
+
+impl Lesson {
+    pub fn title_in(&self, locale: Locale) -> &'static str {
+        match locale {
+            Locale::ZhCn => self.title,
+            Locale::EnUs => self.title_en,
+        }
+    }
+
+    pub fn description_in(&self, locale: Locale) -> &'static str {
+        match locale {
+            Locale::ZhCn => self.description,
+            Locale::EnUs => self.description_en,
+        }
+    }
+
+    // 课程的完整路径，例如 "advanced/lifetimes"
+    pub fn path(&self) -> String {
+        format!("{}/{}", self.topic, self.id)
+    }
+
+    // 课程文件相对于项目根目录的路径，例如 "src/advanced/lifetimes.rs"
+    pub fn file(&self) -> String {
+        format!("src/{}/{}.rs", self.topic, self.id)
+    }
+}
+
+// 主题按菜单顺序排列
+pub static TOPICS: &[Topic] = &[
+    Topic {
+        id: "basics",
+        title: "基础语法",
+        title_en: "Basics",
+        description: "包含变量、数据类型、函数等基础语法示例。",
+        description_en: "Variables, data types, functions and other basic syntax.",
+    },
+    Topic {
+        id: "control_flow",
+        title: "流程控制",
+        title_en: "Control flow",
+        description: "包含 if-else、循环等流程控制示例。",
+        description_en: "if-else, loops and other control flow.",
+    },
+    Topic {
+        id: "ownership",
+        title: "所有权和借用",
+        title_en: "Ownership and borrowing",
+        description: "包含所有权、引用和借用等示例。",
+        description_en: "Ownership, references and borrowing.",
+    },
+    Topic {
+        id: "structs_enums",
+        title: "结构体、枚举和模式匹配",
+        title_en: "Structs, enums and pattern matching",
+        description: "包含结构体、枚举和模式匹配等示例。",
+        description_en: "Structs, enums and pattern matching.",
+    },
+    Topic {
+        id: "collections",
+        title: "集合",
+        title_en: "Collections",
+        description: "包含向量、字符串、哈希映射等集合类型示例。",
+        description_en: "Vectors, strings, hash maps and other collection types.",
+    },
+    Topic {
+        id: "modules_errors",
+        title: "模块和错误处理",
+        title_en: "Modules and error handling",
+        description: "包含模块系统和错误处理示例。",
+        description_en: "The module system and error handling.",
+    },
+    Topic {
+        id: "advanced",
+        title: "高级特性",
+        title_en: "Advanced features",
+        description: "包含泛型、Trait、生命周期等高级特性示例。",
+        description_en: "Generics, traits, lifetimes and other advanced features.",
+    },
+    Topic {
+        id: "concurrency",
+        title: "并发",
+        title_en: "Concurrency",
+        description: "包含线程、通道、共享状态和原子类型等并发示例。",
+        description_en: "Threads, channels, shared state and atomics.",
+    },
+    Topic {
+        id: "smart_pointers",
+        title: "智能指针",
+        title_en: "Smart pointers",
+        description: "包含 Box、Rc、RefCell 和 Weak 等智能指针示例。",
+        description_en: "Box, Rc, RefCell, Weak and reference cycles.",
+    },
+    Topic {
+        id: "iterators",
+        title: "迭代器与闭包",
+        title_en: "Iterators and closures",
+        description: "包含闭包、迭代器适配器和自定义适配器等示例。",
+        description_en: "Closures, iterator adapters and writing custom adapters.",
+    },
+    Topic {
+        id: "async_basics",
+        title: "异步编程",
+        title_en: "Async programming",
+        description: "包含 Future、执行器、Waker、定时器和 join 等异步示例，只使用标准库。",
+        description_en: "Futures, a tiny executor, wakers, timers and join, using only std.",
+    },
+    Topic {
+        id: "macros",
+        title: "宏",
+        title_en: "Macros",
+        description: "包含 macro_rules! 声明宏和派生宏等示例，派生宏来自工作区中的过程宏 crate。",
+        description_en: "Declarative macros with macro_rules! and a derive macro from a workspace proc-macro crate.",
+    },
+];
+
+// 课程按主题分组，组内按运行顺序排列
+pub static LESSONS: &[Lesson] = &[
+    Lesson {
+        id: "variables",
+        topic: "basics",
+        title: "变量",
+        title_en: "Variables",
+        description: "不可变与可变变量、遮蔽、常量和作用域。",
+        description_en: "Immutable and mutable variables, shadowing, constants and scope.",
+        run: basics::variables::main,
+        source: include_str!("basics/variables.rs"),
+    },
+    Lesson {
+        id: "data_types",
+        topic: "basics",
+        title: "数据类型",
+        title_en: "Data types",
+        description: "标量类型、复合类型、切片和类型转换。",
+        description_en: "Scalar and compound types, slices and type conversion.",
+        run: basics::data_types::main,
+        source: include_str!("basics/data_types.rs"),
+    },
+    Lesson {
+        id: "functions",
+        topic: "basics",
+        title: "函数",
+        title_en: "Functions",
+        description: "参数、返回值、函数指针和高阶函数。",
+        description_en: "Parameters, return values, function pointers and higher-order functions.",
+        run: basics::functions::main,
+        source: include_str!("basics/functions.rs"),
+    },
+    Lesson {
+        id: "if_else",
+        topic: "control_flow",
+        title: "if-else",
+        title_en: "if-else",
+        description: "if 表达式、else if 分支和逻辑运算符。",
+        description_en: "if expressions, else if branches and logical operators.",
+        run: control_flow::if_else::main,
+        source: include_str!("control_flow/if_else.rs"),
+    },
+    Lesson {
+        id: "loops",
+        topic: "control_flow",
+        title: "循环",
+        title_en: "Loops",
+        description: "loop、while、for、循环标签和迭代器方法。",
+        description_en: "loop, while, for, loop labels and iterator methods.",
+        run: control_flow::loops::main,
+        source: include_str!("control_flow/loops.rs"),
+    },
+    Lesson {
+        id: "ownership_basics",
+        topic: "ownership",
+        title: "所有权基础",
+        title_en: "Ownership basics",
+        description: "移动、克隆、Copy 以及函数与所有权。",
+        description_en: "Moves, clones, Copy, and ownership across function calls.",
+        run: ownership::ownership_basics::main,
+        source: include_str!("ownership/ownership_basics.rs"),
+    },
+    Lesson {
+        id: "references_borrowing",
+        topic: "ownership",
+        title: "引用和借用",
+        title_en: "References and borrowing",
+        description: "不可变引用、可变引用、借用规则和切片。",
+        description_en: "Immutable and mutable references, borrowing rules and slices.",
+        run: ownership::references_borrowing::main,
+        source: include_str!("ownership/references_borrowing.rs"),
+    },
+    Lesson {
+        id: "structs",
+        topic: "structs_enums",
+        title: "结构体",
+        title_en: "Structs",
+        description: "定义和实例化结构体、方法与关联函数。",
+        description_en: "Defining and instantiating structs, methods and associated functions.",
+        run: structs_enums::structs::main,
+        source: include_str!("structs_enums/structs.rs"),
+    },
+    Lesson {
+        id: "enums",
+        topic: "structs_enums",
+        title: "枚举",
+        title_en: "Enums",
+        description: "枚举变体、关联数据、Option 和 Result。",
+        description_en: "Enum variants, associated data, Option and Result.",
+        run: structs_enums::enums::main,
+        source: include_str!("structs_enums/enums.rs"),
+    },
+    Lesson {
+        id: "pattern_matching",
+        topic: "structs_enums",
+        title: "模式匹配",
+        title_en: "Pattern matching",
+        description: "match、解构、匹配守卫和 @ 绑定。",
+        description_en: "match, destructuring, match guards and @ bindings.",
+        run: structs_enums::pattern_matching::main,
+        source: include_str!("structs_enums/pattern_matching.rs"),
+    },
+    Lesson {
+        id: "vectors",
+        topic: "collections",
+        title: "向量",
+        title_en: "Vectors",
+        description: "创建、更新、遍历和排序 Vec<T>。",
+        description_en: "Creating, updating, iterating and sorting Vec<T>.",
+        run: collections::vectors::main,
+        source: include_str!("collections/vectors.rs"),
+    },
+    Lesson {
+        id: "strings",
+        topic: "collections",
+        title: "字符串",
+        title_en: "Strings",
+        description: "String 与 &str、UTF-8、切片和格式化。",
+        description_en: "String vs &str, UTF-8, slicing and formatting.",
+        run: collections::strings::main,
+        source: include_str!("collections/strings.rs"),
+    },
+    Lesson {
+        id: "hashmaps",
+        topic: "collections",
+        title: "哈希映射",
+        title_en: "Hash maps",
+        description: "插入、访问、entry API 和所有权。",
+        description_en: "Inserting, reading, the entry API and ownership.",
+        run: collections::hashmaps::main,
+        source: include_str!("collections/hashmaps.rs"),
+    },
+    Lesson {
+        id: "modules",
+        topic: "modules_errors",
+        title: "模块系统",
+        title_en: "Module system",
+        description: "模块、路径、可见性和 use 导入。",
+        description_en: "Modules, paths, visibility and use imports.",
+        run: modules_errors::modules::main,
+        source: include_str!("modules_errors/modules.rs"),
+    },
+    Lesson {
+        id: "error_handling",
+        topic: "modules_errors",
+        title: "错误处理",
+        title_en: "Error handling",
+        description: "Result、panic!、? 运算符和自定义错误类型。",
+        description_en: "Result, panic!, the ? operator and custom error types.",
+        run: modules_errors::error_handling::main,
+        source: include_str!("modules_errors/error_handling.rs"),
+    },
+    Lesson {
+        id: "advanced_error_handling",
+        topic: "modules_errors",
+        title: "高级错误处理",
+        title_en: "Advanced error handling",
+        description: "Box<dyn Error> 与 downcast、Result 和 Option 互转、panic 钩子。",
+        description_en: "Box<dyn Error> and downcasting, Result/Option conversion, panic hooks.",
+        run: modules_errors::advanced_error_handling::main,
+        source: include_str!("modules_errors/advanced_error_handling.rs"),
+    },
+    Lesson {
+        id: "generics",
+        topic: "advanced",
+        title: "泛型",
+        title_en: "Generics",
+        description: "泛型函数、结构体、trait bounds 和常量泛型。",
+        description_en: "Generic functions, structs, trait bounds and const generics.",
+        run: advanced::generics::main,
+        source: include_str!("advanced/generics.rs"),
+    },
+    Lesson {
+        id: "traits",
+        topic: "advanced",
+        title: "Trait",
+        title_en: "Traits",
+        description: "定义共享行为、trait 对象和运算符重载。",
+        description_en: "Defining shared behaviour, trait objects and operator overloading.",
+        run: advanced::traits::main,
+        source: include_str!("advanced/traits.rs"),
+    },
+    Lesson {
+        id: "lifetimes",
+        topic: "advanced",
+        title: "生命周期",
+        title_en: "Lifetimes",
+        description: "生命周期注解、省略规则和结构体中的引用。",
+        description_en: "Lifetime annotations, elision rules and references in structs.",
+        run: advanced::lifetimes::main,
+        source: include_str!("advanced/lifetimes.rs"),
+    },
+    Lesson {
+        id: "threads",
+        topic: "concurrency",
+        title: "线程",
+        title_en: "Threads",
+        description: "spawn 和 JoinHandle、move 闭包、线程 panic 和作用域线程。",
+        description_en: "spawn and JoinHandle, move closures, panicking threads and scoped threads.",
+        run: concurrency::threads::main,
+        source: include_str!("concurrency/threads.rs"),
+    },
+    Lesson {
+        id: "channels",
+        topic: "concurrency",
+        title: "通道",
+        title_en: "Channels",
+        description: "mpsc 通道、多个生产者、同步通道和流水线。",
+        description_en: "mpsc channels, multiple producers, sync channels and pipelines.",
+        run: concurrency::channels::main,
+        source: include_str!("concurrency/channels.rs"),
+    },
+    Lesson {
+        id: "shared_state",
+        topic: "concurrency",
+        title: "共享状态",
+        title_en: "Shared state",
+        description: "Arc<Mutex<T>>、RwLock、锁中毒、Condvar 和加锁顺序。",
+        description_en: "Arc<Mutex<T>>, RwLock, poisoning, Condvar and lock ordering.",
+        run: concurrency::shared_state::main,
+        source: include_str!("concurrency/shared_state.rs"),
+    },
+    Lesson {
+        id: "atomics",
+        topic: "concurrency",
+        title: "原子类型",
+        title_en: "Atomics",
+        description: "原子计数器、compare_exchange、内存顺序和 OnceLock。",
+        description_en: "Atomic counters, compare_exchange, memory ordering and OnceLock.",
+        run: concurrency::atomics::main,
+        source: include_str!("concurrency/atomics.rs"),
+    },
+    Lesson {
+        id: "box_pointers",
+        topic: "smart_pointers",
+        title: "Box<T>",
+        title_en: "Box<T>",
+        description: "堆上的数据、递归类型 cons list、Deref、Drop 和 trait 对象。",
+        description_en: "Heap data, the recursive cons list, Deref, Drop and trait objects.",
+        run: smart_pointers::box_pointers::main,
+        source: include_str!("smart_pointers/box_pointers.rs"),
+    },
+    Lesson {
+        id: "rc",
+        topic: "smart_pointers",
+        title: "Rc<T>",
+        title_en: "Rc<T>",
+        description: "共享所有权、强引用计数、try_unwrap 和 make_mut。",
+        description_en: "Shared ownership, strong counts, try_unwrap and make_mut.",
+        run: smart_pointers::rc::main,
+        source: include_str!("smart_pointers/rc.rs"),
+    },
+    Lesson {
+        id: "refcell",
+        topic: "smart_pointers",
+        title: "RefCell<T>",
+        title_en: "RefCell<T>",
+        description: "内部可变性、mock 对象、运行时借用检查和 Cell。",
+        description_en: "Interior mutability, mock objects, runtime borrow checks and Cell.",
+        run: smart_pointers::refcell::main,
+        source: include_str!("smart_pointers/refcell.rs"),
+    },
+    Lesson {
+        id: "weak",
+        topic: "smart_pointers",
+        title: "Weak<T> 与引用循环",
+        title_en: "Weak<T> and reference cycles",
+        description: "父节点用 Weak 链接的树、发现并打破 Rc 引用循环。",
+        description_en: "Trees with Weak parent links, detecting and breaking Rc cycles.",
+        run: smart_pointers::weak::main,
+        source: include_str!("smart_pointers/weak.rs"),
+    },
+    Lesson {
+        id: "closures",
+        topic: "iterators",
+        title: "闭包",
+        title_en: "Closures",
+        description: "捕获方式、Fn/FnMut/FnOnce、move、返回和组合闭包。",
+        description_en: "Capture modes, Fn/FnMut/FnOnce, move, returning and composing closures.",
+        run: iterators::closures::main,
+        source: include_str!("iterators/closures.rs"),
+    },
+    Lesson {
+        id: "iterator_basics",
+        topic: "iterators",
+        title: "迭代器",
+        title_en: "Iterators",
+        description: "惰性适配器、消费者、collect、ExactSizeIterator、DoubleEndedIterator 和 impl Iterator。",
+        description_en: "Lazy adapters, consumers, collect, ExactSizeIterator, DoubleEndedIterator and impl Iterator.",
+        run: iterators::iterator_basics::main,
+        source: include_str!("iterators/iterator_basics.rs"),
+    },
+    Lesson {
+        id: "custom_adapters",
+        topic: "iterators",
+        title: "自定义适配器",
+        title_en: "Custom adapters",
+        description: "扩展 trait 实现的适配器库，以及迭代器链和手写循环的对比。",
+        description_en: "An adapter library built on an extension trait, compared with hand-written loops.",
+        run: iterators::custom_adapters::main,
+        source: include_str!("iterators/custom_adapters.rs"),
+    },
+    Lesson {
+        id: "futures",
+        topic: "async_basics",
+        title: "Future 与 async/await",
+        title_en: "Futures and async/await",
+        description: "Future 和 Poll、async fn 展开成的状态机、block_on 和 Pin。",
+        description_en: "Future and Poll, the state machine behind async fn, block_on and Pin.",
+        run: async_basics::futures::main,
+        source: include_str!("async_basics/futures.rs"),
+    },
+    Lesson {
+        id: "executor",
+        topic: "async_basics",
+        title: "执行器",
+        title_en: "Executor",
+        description: "单线程执行器、实现 Wake 的任务、协作式调度、oneshot 和 JoinHandle。",
+        description_en: "A single-threaded executor, tasks implementing Wake, cooperative scheduling, oneshot and JoinHandle.",
+        run: async_basics::executor::main,
+        source: include_str!("async_basics/executor.rs"),
+    },
+    Lesson {
+        id: "timers",
+        topic: "async_basics",
+        title: "定时器和 join",
+        title_en: "Timers and join",
+        description: "基于线程的定时器 future、join 和 join_all 组合器。",
+        description_en: "A thread-backed timer future and the join and join_all combinators.",
+        run: async_basics::timers::main,
+        source: include_str!("async_basics/timers.rs"),
+    },
+    Lesson {
+        id: "declarative",
+        topic: "macros",
+        title: "声明宏基础",
+        title_en: "Declarative macros",
+        description: "macro_rules! 的规则、片段类型、重复、递归、卫生性和 hashmap! 字面量。",
+        description_en: "macro_rules! rules, fragment types, repetition, recursion, hygiene and a hashmap! literal.",
+        run: macros::declarative::main,
+        source: include_str!("macros/declarative.rs"),
+    },
+    Lesson {
+        id: "derive",
+        topic: "macros",
+        title: "派生宏基础",
+        title_en: "Derive macros",
+        description: "用工作区中的过程宏 crate 实现 #[derive(Describe)] 和辅助属性。",
+        description_en: "#[derive(Describe)] and a helper attribute from a workspace proc-macro crate.",
+        run: macros::derive::main,
+        source: include_str!("macros/derive.rs"),
+    },
+];
+
+pub fn lessons_of(topic: &str) -> impl Iterator<Item = &'static Lesson> + '_ {
+    LESSONS.iter().filter(move |lesson| lesson.topic == topic)
+}
+
+// 课程和主题的小标题。中文在标题后加“示例”：中文直接相连，
+// 英文单词后加空格（"Trait 示例"）；英文界面直接使用标题
+pub fn heading(title: &str, locale: Locale) -> String {
+    match (locale, title.chars().last()) {
+        (Locale::EnUs, _) => title.to_string(),
+        (Locale::ZhCn, Some(c)) if c.is_ascii() => format!("{} 示例", title),
+        (Locale::ZhCn, _) => format!("{}示例", title),
+    }
+}
+
+// 按名称查找到的目标：整个主题或单个课程
+#[derive(Clone, Copy)]
+pub enum Target {
+    Topic(&'static Topic),
+    Lesson(&'static Lesson),
+}
+
+impl Target {
+    pub fn path(&self) -> String {
+        match self {
+            Target::Topic(topic) => topic.id.to_string(),
+            Target::Lesson(lesson) => lesson.path(),
+        }
+    }
+
+    fn names(&self) -> [&'static str; 2] {
+        match self {
+            Target::Topic(topic) => [topic.id, topic.title],
+            Target::Lesson(lesson) => [lesson.id, lesson.title],
+        }
+    }
+}
+
+// 名称查找失败
+#[derive(Debug, PartialEq)]
+pub enum ResolveError {
+    NotFound { query: String, suggestions: Vec<String> },
+    Ambiguous { query: String, candidates: Vec<String> },
+}
+
+impl ResolveError {
+    pub fn message(&self, locale: Locale) -> String {
+        self.message_with(locale, "resolve.not_found")
+    }
+
+    // 与 message 相同，但找不到时使用指定的消息，例如查找练习时的 "check.not_found"
+    pub fn message_with(&self, locale: Locale, not_found: &'static str) -> String {
+        match self {
+            ResolveError::NotFound { query, suggestions } => {
+                let mut message = locale.message(not_found, &[query]);
+                if !suggestions.is_empty() {
+                    message += &locale.message("resolve.suggestions", &[&suggestions.join(", ")]);
+                }
+                message
+            }
+            ResolveError::Ambiguous { query, candidates } => {
+                locale.message("resolve.ambiguous", &[query, &candidates.join(", ")])
+            }
+        }
+    }
+}
+
+impl fmt::Display for ResolveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.message(Locale::default()))
+    }
+}
+
+impl std::error::Error for ResolveError {}
+
+// 按名称查找主题或课程
+//
+// 支持的写法：
+// - "advanced"、"advanced/lifetimes"、"advanced/life"：主题或 主题/课程 路径
+// - "lifetimes"、"生命周期"：课程 id 或标题
+// - "hash"、"borrow"：唯一的前缀或子串
+// - "hashmpas"、"lifetmes"：拼写相近时在错误中给出建议
+pub fn resolve(query: &str) -> Result<Target, ResolveError> {
+    let query = query.trim().trim_matches('/');
+
+    if let Some((topic_query, lesson_query)) = query.split_once('/') {
+        let topics: Vec<Target> = TOPICS.iter().map(Target::Topic).collect();
+        let topic = match pick(topic_query, &topics)? {
+            Target::Topic(topic) => topic,
+            Target::Lesson(_) => unreachable!("只在主题中查找"),
+        };
+        let lessons: Vec<Target> = lessons_of(topic.id).map(Target::Lesson).collect();
+        return pick(lesson_query, &lessons).map_err(|e| match e {
+            ResolveError::NotFound { suggestions, .. } => ResolveError::NotFound {
+                query: query.to_string(),
+                suggestions,
+            },
+            other => other,
+        });
+    }
+
+    let all: Vec<Target> = TOPICS
+        .iter()
+        .map(Target::Topic)
+        .chain(LESSONS.iter().map(Target::Lesson))
+        .collect();
+    pick(query, &all)
+}
+
+// 在候选中依次尝试：精确匹配、前缀、子串；每一步只要唯一就返回
+fn pick(query: &str, candidates: &[Target]) -> Result<Target, ResolveError> {
+    let query = query.to_lowercase();
+    let not_found = || ResolveError::NotFound {
+        query: query.clone(),
+        suggestions: suggest(&query, candidates),
+    };
+
+    if query.is_empty() {
+        return Err(not_found());
+    }
+
+    let matchers: [fn(&str, &str) -> bool; 3] = [
+        |name, query| name == query,
+        |name, query| name.starts_with(query),
+        |name, query| name.contains(query),
+    ];
+
+    for matches in matchers {
+        let found: Vec<Target> = candidates
+            .iter()
+            .copied()
+            .filter(|target| {
+                target
+                    .names()
+                    .iter()
+                    .any(|name| matches(&name.to_lowercase(), &query))
+            })
+            .collect();
+
+        match found.as_slice() {
+            [] => continue,
+            [target] => return Ok(*target),
+            _ => {
+                return Err(ResolveError::Ambiguous {
+                    query,
+                    candidates: found.iter().map(Target::path).collect(),
+                });
+            }
+        }
+    }
+
+    Err(not_found())
+}
+
+// 找出编辑距离最近的候选作为建议
+fn suggest(query: &str, candidates: &[Target]) -> Vec<String> {
+    let mut scored: Vec<(usize, String)> = candidates
+        .iter()
+        .map(|target| (edit_distance(query, target.names()[0]), target.path()))
+        .filter(|(distance, _)| *distance <= 2)
+        .collect();
+    scored.sort();
+    scored.into_iter().map(|(_, path)| path).collect()
+}
+
+// Levenshtein 编辑距离
+pub(crate) fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let cost = if ca == *cb { 0 } else { 1 };
+            let value = (previous[j] + cost)
+                .min(previous[j + 1] + 1)
+                .min(current[j] + 1);
+            current.push(value);
+        }
+        previous = current;
+    }
+
+    previous[b.len()]
+}
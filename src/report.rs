@@ -0,0 +1,119 @@
+//! 运行报告
+//!
+//! `--format json` 时不输出文字，而是在运行结束后输出一份 JSON 报告，供看板等工具读取。
+//! 报告格式有版本号，字段的增删改都要同时修改 `SCHEMA_VERSION` 和 tests/report.rs：
+//!
+//! ```text
+//! {
+//!   "schema": "rust_study.report",
+//!   "version": 1,
+//!   "lessons": [
+//!     {
+//!       "id": "functions",            课程 id
+//!       "topic": "basics",            所属主题 id（src 下的目录名）
+//!       "status": "passed",           "passed"、"panicked" 或 "failed"
+//!       "duration_us": 42,            运行耗时，微秒
+//!       "output": "...",              课程写出的内容
+//!       "panic": null,                panic 信息，只在 "panicked" 时为字符串
+//!       "error": null                 错误信息，只在 "failed" 时为字符串
+//!     }
+//!   ],
+//!   "summary": { "total": 1, "passed": 1, "failed": 0 }
+//! }
+//! ```
+//!
+//! 实际输出是不带缩进的单行 JSON。
+
+use std::fmt::Write as _;
+
+use crate::runner::{Status, Summary};
+
+pub const SCHEMA: &str = "rust_study.report";
+pub const SCHEMA_VERSION: u32 = 1;
+
+// 输出格式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+}
+
+impl Format {
+    pub fn parse(name: &str) -> Option<Format> {
+        match name {
+            "text" => Some(Format::Text),
+            "json" => Some(Format::Json),
+            _ => None,
+        }
+    }
+}
+
+pub fn to_json(summary: &Summary) -> String {
+    let mut json = String::new();
+    json.push('{');
+    field(&mut json, "schema", &json_string(SCHEMA));
+    json.push(',');
+    field(&mut json, "version", &SCHEMA_VERSION.to_string());
+    json.push_str(",\"lessons\":[");
+
+    for (i, run) in summary.runs.iter().enumerate() {
+        if i > 0 {
+            json.push(',');
+        }
+        let (status, panic, error) = match &run.status {
+            Status::Passed => ("passed", None, None),
+            Status::Panicked(message) => ("panicked", Some(message), None),
+            Status::Failed(message) => ("failed", None, Some(message)),
+        };
+
+        json.push('{');
+        field(&mut json, "id", &json_string(run.lesson.id));
+        json.push(',');
+        field(&mut json, "topic", &json_string(run.lesson.topic));
+        json.push(',');
+        field(&mut json, "status", &json_string(status));
+        json.push(',');
+        field(&mut json, "duration_us", &run.duration.as_micros().to_string());
+        json.push(',');
+        field(&mut json, "output", &json_string(&run.output));
+        json.push(',');
+        field(&mut json, "panic", &panic.map_or(String::from("null"), |m| json_string(m)));
+        json.push(',');
+        field(&mut json, "error", &error.map_or(String::from("null"), |m| json_string(m)));
+        json.push('}');
+    }
+
+    json.push_str("],\"summary\":{");
+    field(&mut json, "total", &summary.runs.len().to_string());
+    json.push(',');
+    field(&mut json, "passed", &summary.passed().to_string());
+    json.push(',');
+    field(&mut json, "failed", &summary.failed().to_string());
+    json.push_str("}}");
+    json
+}
+
+// "key":value，value 已经是 JSON
+fn field(json: &mut String, key: &str, value: &str) {
+    let _ = write!(json, "{}:{}", json_string(key), value);
+}
+
+// JSON 字符串字面量：转义引号、反斜杠和控制字符，其他字符（包括中文）原样保留
+pub(crate) fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => { let _ = write!(out, "\\u{:04x}", c as u32); }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
@@ -0,0 +1,208 @@
+//! 运行课程
+//!
+//! 菜单和命令行共用的运行逻辑：单课、整个主题或全部示例。
+//! 所有输出都写入调用方提供的 `Ctx`；每课的临时工作目录也在这里准备和清理。
+//!
+//! 每课都在 `catch_unwind` 中运行：一课 panic 不会中断后面的课程。
+//! 运行多课时最后打印汇总表，调用方根据 `Summary` 决定退出码。
+//! 单步模式（`--step`）下课程在每个 `ctx.section` 处暂停，见 context.rs。
+
+use std::any::Any;
+use std::io::{self, Write};
+use std::panic::{self, AssertUnwindSafe};
+use std::time::{Duration, Instant};
+
+use crate::context::Ctx;
+use crate::progress::Kind;
+use crate::registry::{self, Lesson, Target, Topic};
+
+// 一课的运行结果
+#[derive(Debug, Clone, PartialEq)]
+pub enum Status {
+    Passed,
+    // panic 时携带的信息
+    Panicked(String),
+    // 课程返回了错误，例如工作目录无法创建
+    Failed(String),
+}
+
+pub struct LessonRun {
+    pub lesson: &'static Lesson,
+    pub status: Status,
+    pub duration: Duration,
+    // 课程自己写出的内容，不含运行器加的标题和提示
+    pub output: String,
+}
+
+// 一次运行中所有课程的结果，按运行顺序排列
+#[derive(Default)]
+pub struct Summary {
+    pub runs: Vec<LessonRun>,
+}
+
+impl Summary {
+    pub fn passed(&self) -> usize {
+        self.runs.iter().filter(|run| run.status == Status::Passed).count()
+    }
+
+    pub fn failed(&self) -> usize {
+        self.runs.len() - self.passed()
+    }
+
+    pub fn is_success(&self) -> bool {
+        self.failed() == 0
+    }
+}
+
+pub fn run_target(ctx: &mut Ctx, target: Target) -> io::Result<Summary> {
+    match target {
+        Target::Topic(topic) => run_topic(ctx, topic),
+        Target::Lesson(lesson) => Ok(Summary {
+            runs: vec![run_lesson(ctx, lesson)?],
+        }),
+    }
+}
+
+pub fn run_lesson(ctx: &mut Ctx, lesson: &'static Lesson) -> io::Result<LessonRun> {
+    let heading = registry::heading(lesson.title_in(ctx.locale()), ctx.locale());
+    writeln!(ctx, "\n--- {} ---", heading)?;
+
+    ctx.start_workspace(format!("{}-{}", lesson.topic, lesson.id));
+    ctx.start_capture();
+    let start = Instant::now();
+    let result = panic::catch_unwind(AssertUnwindSafe(|| (lesson.run)(ctx)));
+    let duration = start.elapsed();
+    // 单步模式下最后一节结束后也暂停；课程失败时直接显示错误
+    ctx.finish_sections(matches!(result, Ok(Ok(()))))?;
+    let output = ctx.finish_capture();
+    if let Some(kept) = ctx.finish_workspace() {
+        writeln!(ctx, "{}", ctx.message("runner.workspace_kept", &[&kept.display()]))?;
+    }
+
+    let status = match result {
+        Ok(Ok(())) => Status::Passed,
+        Ok(Err(e)) => Status::Failed(e.to_string()),
+        Err(payload) => Status::Panicked(
+            panic_message(payload.as_ref())
+                .unwrap_or_else(|| ctx.text("runner.unknown_panic").to_string()),
+        ),
+    };
+    match &status {
+        Status::Passed => ctx.record_progress(Kind::Lesson, &lesson.path(), None)?,
+        Status::Panicked(message) => {
+            writeln!(ctx, "{}", ctx.message("runner.lesson_panicked", &[message]))?
+        }
+        Status::Failed(message) => {
+            writeln!(ctx, "{}", ctx.message("runner.lesson_failed", &[message]))?
+        }
+    }
+
+    Ok(LessonRun {
+        lesson,
+        status,
+        duration,
+        output,
+    })
+}
+
+pub fn run_topic(ctx: &mut Ctx, topic: &Topic) -> io::Result<Summary> {
+    let mut summary = Summary::default();
+    run_topic_into(ctx, topic, &mut summary)?;
+    print_summary(ctx, &summary)?;
+    Ok(summary)
+}
+
+pub fn run_all(ctx: &mut Ctx) -> io::Result<Summary> {
+    let mut summary = Summary::default();
+    for topic in registry::TOPICS {
+        run_topic_into(ctx, topic, &mut summary)?;
+    }
+    writeln!(ctx, "\n=== {} ===", ctx.text("runner.done"))?;
+    print_summary(ctx, &summary)?;
+    Ok(summary)
+}
+
+// 依次运行给定的课程并打印汇总表
+pub fn run_lessons<I>(ctx: &mut Ctx, lessons: I) -> io::Result<Summary>
+where
+    I: IntoIterator<Item = &'static Lesson>,
+{
+    let mut summary = Summary::default();
+    for lesson in lessons {
+        summary.runs.push(run_lesson(ctx, lesson)?);
+    }
+    print_summary(ctx, &summary)?;
+    Ok(summary)
+}
+
+fn run_topic_into(ctx: &mut Ctx, topic: &Topic, summary: &mut Summary) -> io::Result<()> {
+    let heading = registry::heading(topic.title_in(ctx.locale()), ctx.locale());
+    writeln!(ctx, "\n=== {} ===", ctx.message("runner.topic", &[&heading]))?;
+    for lesson in registry::lessons_of(topic.id) {
+        summary.runs.push(run_lesson(ctx, lesson)?);
+    }
+    Ok(())
+}
+
+// 汇总表：每课一行，状态放在最后一列，避免中英文混排时对不齐
+pub fn print_summary(ctx: &mut Ctx, summary: &Summary) -> io::Result<()> {
+    const PATH_WIDTH: usize = 40;
+    const TIME_WIDTH: usize = 10;
+
+    writeln!(ctx, "\n=== {} ===", ctx.text("runner.summary"))?;
+    let (lesson, time, status) = (
+        ctx.text("runner.column_lesson"),
+        ctx.text("runner.column_time"),
+        ctx.text("runner.column_status"),
+    );
+    writeln!(
+        ctx,
+        "{}{}  {}",
+        pad_right(lesson, PATH_WIDTH),
+        pad_left(time, TIME_WIDTH),
+        status
+    )?;
+
+    for run in &summary.runs {
+        let status = match &run.status {
+            Status::Passed => ctx.text("runner.passed").to_string(),
+            Status::Panicked(message) => ctx.message("runner.panicked", &[message]),
+            Status::Failed(message) => ctx.message("runner.failed", &[message]),
+        };
+        writeln!(
+            ctx,
+            "{}{}  {}",
+            pad_right(&run.lesson.path(), PATH_WIDTH),
+            pad_left(&format!("{:.1?}", run.duration), TIME_WIDTH),
+            status
+        )?;
+    }
+
+    let totals = ctx.message(
+        "runner.totals",
+        &[&summary.runs.len(), &summary.passed(), &summary.failed()],
+    );
+    writeln!(ctx, "{}", totals)
+}
+
+// panic 的载荷通常是 &str 或 String，其他类型取不到信息
+pub(crate) fn panic_message(payload: &(dyn Any + Send)) -> Option<String> {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        Some(s.to_string())
+    } else {
+        payload.downcast_ref::<String>().cloned()
+    }
+}
+
+// 终端中中文等全角字符占两列（粗略按 U+1100 以后算全角，"µs" 中的 µ 仍占一列）
+fn display_width(s: &str) -> usize {
+    s.chars().map(|c| if c < '\u{1100}' { 1 } else { 2 }).sum()
+}
+
+pub(crate) fn pad_right(s: &str, width: usize) -> String {
+    format!("{}{}", s, " ".repeat(width.saturating_sub(display_width(s))))
+}
+
+fn pad_left(s: &str, width: usize) -> String {
+    format!("{}{}", " ".repeat(width.saturating_sub(display_width(s))), s)
+}
@@ -0,0 +1,353 @@
+//! 全文搜索
+//!
+//! `rust_study search <term>` 在所有课程中查找一个词：课程标题和说明、小节标题、
+//! 代码中的标识符、输出的讲解文字和注释。索引在搜索时从嵌入的源码（见 registry.rs）
+//! 现场建立，不需要事先生成。
+//!
+//! 结果按相关程度排序：标题和小节标题最靠前，然后是代码，最后是讲解文字和注释；
+//! 同一类中完整匹配一个标识符的排在只匹配一部分的前面，但不会因此越过前一类。
+
+use std::io::{self, Write};
+use std::ptr;
+
+use crate::context::Ctx;
+use crate::registry::{Lesson, LESSONS};
+use crate::source;
+
+// 命中的位置，按排序的先后声明：前面的种类总是排在后面的种类之前
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Kind {
+    // 课程标题或说明
+    Title,
+    // ctx.section 声明的小节标题
+    Section,
+    // 代码中的标识符
+    Code,
+    // 字符串字面量中的讲解文字
+    Narration,
+    Comment,
+}
+
+#[derive(Clone)]
+pub struct Hit {
+    pub lesson: &'static Lesson,
+    // 所在小节的编号；不在编号小节中（例如辅助函数）时为 None
+    pub section: Option<usize>,
+    pub kind: Kind,
+    // 显示给用户的片段，例如 "entry().or_insert()"
+    pub snippet: String,
+    // 第一次出现的行号，从 1 开始；标题为 0
+    pub line: usize,
+    // 同一课同一节中相同片段出现的次数
+    pub count: usize,
+    // 匹配程度，见 quality；同一种类中高的排在前面
+    pub quality: u32,
+}
+
+impl Hit {
+    // 例如 "collections/hashmaps §6 entry().or_insert()"
+    pub fn label(&self) -> String {
+        match self.section {
+            Some(number) => format!("{} §{} {}", self.lesson.path(), number, self.snippet),
+            None => format!("{} {}", self.lesson.path(), self.snippet),
+        }
+    }
+}
+
+// 在所有课程中搜索，结果按相关程度排序
+pub fn search(term: &str) -> Vec<Hit> {
+    search_in(LESSONS, term)
+}
+
+pub fn search_in(lessons: &'static [Lesson], term: &str) -> Vec<Hit> {
+    let term = term.trim().to_lowercase();
+    if term.is_empty() {
+        return Vec::new();
+    }
+
+    let mut hits: Vec<Hit> = Vec::new();
+    for lesson in lessons {
+        let mut add = |hit: Hit| match hits
+            .iter_mut()
+            .find(|old| ptr::eq(old.lesson, hit.lesson) && old.section == hit.section && old.snippet == hit.snippet)
+        {
+            Some(old) => {
+                old.count += 1;
+                old.quality = old.quality.max(hit.quality);
+            }
+            None => hits.push(hit),
+        };
+        // 中英文的标题和简介都参与匹配，片段显示匹配得更好的那种语言；一样好时用中文（max_by_key 取最后一个）
+        let best = [(lesson.title_en, lesson.description_en), (lesson.title, lesson.description)]
+            .into_iter()
+            .filter_map(|(title, description)| Some((quality(title, &term).max(quality(description, &term))?, title, description)))
+            .max_by_key(|(quality, _, _)| *quality);
+        if let Some((quality, title, description)) = best {
+            let snippet = format!("{} - {}", title, description);
+            add(hit(lesson, None, Kind::Title, snippet, 0, quality));
+        }
+        index_source(lesson, &term, &mut add);
+    }
+
+    // 先按种类，再按匹配程度和次数；种类之间不比较匹配程度，代码中的完整匹配也排在标题之后。
+    // 稳定排序：都相同时保持课程和行的顺序
+    hits.sort_by(|a, b| a.kind.cmp(&b.kind).then(b.quality.cmp(&a.quality)).then(b.count.cmp(&a.count)));
+    hits
+}
+
+fn hit(lesson: &'static Lesson, section: Option<usize>, kind: Kind, snippet: String, line: usize, quality: u32) -> Hit {
+    Hit {
+        lesson,
+        section,
+        kind,
+        snippet,
+        line,
+        count: 1,
+        quality,
+    }
+}
+
+// 逐行查找小节标题、代码、字符串和注释中的命中
+fn index_source(lesson: &'static Lesson, term: &str, add: &mut impl FnMut(Hit)) {
+    let sections = source::sections(lesson.source);
+    for (i, line) in lesson.source.lines().enumerate() {
+        let section = sections
+            .iter()
+            .find(|section| section.lines.contains(&i))
+            .and_then(|section| section.number);
+        let heading = sections.iter().find(|section| section.lines.start == i && section.number.is_some());
+        if let Some(heading) = heading {
+            // 中英文标题都参与匹配，片段显示匹配得更好的那个；一样好时用中文（max_by_key 取最后一个）
+            let best = [heading.title_en.as_deref(), heading.title.as_deref()]
+                .into_iter()
+                .flatten()
+                .filter_map(|title| Some((quality(title, term)?, title)))
+                .max_by_key(|(quality, _)| *quality);
+            if let Some((quality, title)) = best {
+                add(hit(lesson, section, Kind::Section, title.to_string(), i + 1, quality));
+            }
+            continue;
+        }
+
+        let parts = split_line(line);
+        // 只转换 ASCII 大小写，保持字节位置与原文一致
+        let code = parts.code.to_ascii_lowercase();
+        for (start, _) in code.match_indices(term) {
+            let snippet = call_chain(&parts.code, start);
+            let quality = quality(&identifier_at(&code, start), term).unwrap_or(1);
+            add(hit(lesson, section, Kind::Code, snippet, i + 1, quality));
+        }
+        for string in &parts.strings {
+            if quality(string, term).is_some() {
+                add(hit(lesson, section, Kind::Narration, format!("\"{}\"", shorten(string)), i + 1, 1));
+            }
+        }
+        if let Some(comment) = &parts.comment
+            && quality(comment, term).is_some()
+        {
+            add(hit(lesson, section, Kind::Comment, format!("// {}", shorten(comment)), i + 1, 1));
+        }
+    }
+}
+
+// 匹配程度：整个词相同 3，开头相同 2，包含 1；不包含时返回 None
+fn quality(text: &str, term: &str) -> Option<u32> {
+    let text = text.to_lowercase();
+    if text == term {
+        Some(3)
+    } else if text.starts_with(term) {
+        Some(2)
+    } else if text.contains(term) {
+        Some(1)
+    } else {
+        None
+    }
+}
+
+// 一行源码拆成代码、字符串字面量和注释；代码中的字符串替换为 ""
+struct Parts {
+    code: String,
+    strings: Vec<String>,
+    comment: Option<String>,
+}
+
+fn split_line(line: &str) -> Parts {
+    let mut parts = Parts {
+        code: String::new(),
+        strings: Vec::new(),
+        comment: None,
+    };
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '/' if chars.peek() == Some(&'/') => {
+                let comment: String = chars.collect();
+                parts.comment = Some(comment.trim_start_matches(['/', '!']).trim().to_string());
+                break;
+            }
+            '"' => {
+                let mut string = String::new();
+                while let Some(c) = chars.next() {
+                    match c {
+                        '\\' => {
+                            chars.next();
+                        }
+                        '"' => break,
+                        c => string.push(c),
+                    }
+                }
+                parts.code.push_str("\"\"");
+                if !string.trim().is_empty() {
+                    parts.strings.push(string);
+                }
+            }
+            c => parts.code.push(c),
+        }
+    }
+    parts
+}
+
+fn is_ident(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+// start 所在标识符的开头
+fn identifier_start(code: &str, start: usize) -> usize {
+    code[..start]
+        .char_indices()
+        .rev()
+        .find(|(_, c)| !is_ident(*c))
+        .map_or(0, |(i, c)| i + c.len_utf8())
+}
+
+// 包含 start 处的标识符
+fn identifier_at(code: &str, start: usize) -> String {
+    let begin = identifier_start(code, start);
+    let end = code[start..].find(|c| !is_ident(c)).map_or(code.len(), |i| start + i);
+    code[begin..end].to_string()
+}
+
+// 向左找到调用链的开头，跳过前面的方法调用和路径，但不包括最前面的变量：
+// "map.entry(word).or_insert(0)" 中 or_insert 的调用链从 entry 开始
+fn chain_start(code: &str, start: usize) -> usize {
+    let mut begin = identifier_start(code, start);
+    loop {
+        let before = &code[..begin];
+        let previous = if let Some(rest) = before.strip_suffix("::") {
+            identifier_start(rest, rest.len())
+        } else if let Some(rest) = before.strip_suffix(").").or_else(|| before.strip_suffix("].")) {
+            match matching_open(rest) {
+                Some(open) => identifier_start(rest, open),
+                None => break,
+            }
+        } else {
+            break;
+        };
+        if previous == begin || !code[previous..].starts_with(|c: char| is_ident(c)) {
+            break;
+        }
+        begin = previous;
+    }
+    begin
+}
+
+// text 以一个括号结束（括号本身已去掉），返回与之配对的左括号的位置
+fn matching_open(text: &str) -> Option<usize> {
+    let mut depth = 1;
+    for (i, c) in text.char_indices().rev() {
+        match c {
+            ')' | ']' => depth += 1,
+            '(' | '[' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+// 从调用链的开头向右取到结尾，省略参数：
+// "map.entry(word).or_insert(0);" 中的 entry 或 or_insert -> "entry().or_insert()"
+fn call_chain(code: &str, start: usize) -> String {
+    let begin = chain_start(code, start);
+    let mut chain = String::new();
+    let mut chars = code[begin..].chars().peekable();
+    loop {
+        while let Some(&c) = chars.peek()
+            && is_ident(c)
+        {
+            chain.push(c);
+            chars.next();
+        }
+        match chars.peek() {
+            Some('(' | '[') => {
+                let (open, close) = if chars.next() == Some('(') { ('(', ')') } else { ('[', ']') };
+                let mut depth = 1;
+                for c in chars.by_ref() {
+                    if c == open {
+                        depth += 1;
+                    } else if c == close {
+                        depth -= 1;
+                        if depth == 0 {
+                            break;
+                        }
+                    }
+                }
+                chain.push(open);
+                chain.push(close);
+            }
+            Some('.') => {
+                chars.next();
+                if !chars.peek().is_some_and(|c| is_ident(*c)) {
+                    break;
+                }
+                chain.push('.');
+            }
+            Some(':') => {
+                chars.next();
+                if chars.next() != Some(':') {
+                    break;
+                }
+                chain.push_str("::");
+            }
+            Some('!') => {
+                chars.next();
+                chain.push('!');
+            }
+            _ => break,
+        }
+    }
+    shorten(&chain)
+}
+
+// 过长的片段截断到 40 个字符
+fn shorten(text: &str) -> String {
+    const MAX: usize = 40;
+    let text = text.trim();
+    if text.chars().count() <= MAX {
+        text.to_string()
+    } else {
+        format!("{}…", text.chars().take(MAX).collect::<String>())
+    }
+}
+
+// search：打印排在前面的结果；没有结果时返回 false
+pub fn run(ctx: &mut Ctx, term: &str) -> io::Result<bool> {
+    const LIMIT: usize = 20;
+
+    let hits = search(term);
+    if hits.is_empty() {
+        writeln!(ctx, "{}", ctx.message("search.none", &[&term]))?;
+        return Ok(false);
+    }
+    for hit in hits.iter().take(LIMIT) {
+        writeln!(ctx, "{}", hit.label())?;
+    }
+    if hits.len() > LIMIT {
+        writeln!(ctx, "{}", ctx.message("search.more", &[&(hits.len() - LIMIT)]))?;
+    }
+    Ok(true)
+}
@@ -0,0 +1,167 @@
+//! Box<T>
+//!
+//! `Box<T>` 把值放在堆上，栈上只留一个指针。常见用途：
+//! - 编译时无法确定大小的类型，例如递归类型（cons list）
+//! - 转移大块数据的所有权时避免复制
+//! - 只关心实现了某个 trait 的 trait 对象（`Box<dyn Trait>`）
+//!
+//! 智能指针的两个核心 trait 是 `Deref` 和 `Drop`，traits 课中的 `MyBox` 和 `Resource`
+//! 分别演示了它们，本课把它们与标准库的 `Box` 放在一起比较。
+
+use std::cell::RefCell;
+use std::fmt;
+use std::io::{self, Write};
+use std::mem;
+use std::rc::Rc;
+
+use crate::advanced::traits::{MyBox, Resource};
+use crate::context::Ctx;
+
+pub fn main(ctx: &mut Ctx) -> io::Result<()> {
+    writeln!(ctx, "=== {} ===", ctx.text("lesson.box_pointers"))?;
+
+    ctx.section(1, "在堆上存储数据", "Storing data on the heap")?;
+    let b = Box::new(5);
+    writeln!(ctx, "b = {}", b)?;
+    // Box 本身只是一个指针，和引用一样大
+    writeln!(ctx, "Box<i32> 和 &i32 一样大: {}", mem::size_of::<Box<i32>>() == mem::size_of::<&i32>())?;
+    // 大数组放进 Box 后，移动所有权只复制指针
+    let big = Box::new([0u8; 1024]);
+    let moved = big;
+    writeln!(
+        ctx,
+        "Box<[u8; 1024]> 也只有一个指针大: {}，数组长度: {}",
+        mem::size_of_val(&moved) == mem::size_of::<usize>(),
+        moved.len()
+    )?;
+
+    ctx.section(2, "递归类型：cons list", "Recursive types: a cons list")?;
+    // 错误：List 直接包含 List，大小无限（见 tests/compile_fail/recursive_type.rs）
+    // enum List {
+    //     Cons(i32, List),
+    //     Nil,
+    // }
+    let list = Cons(1, Box::new(Cons(2, Box::new(Cons(3, Box::new(Nil))))));
+    writeln!(ctx, "list = {}", list)?;
+    writeln!(ctx, "元素之和: {}，长度: {}", list.sum(), list.len())?;
+
+    ctx.section(3, "从切片构建 cons list", "Building a cons list from a slice")?;
+    let list = List::from_slice(&[10, 20, 30, 40]);
+    writeln!(ctx, "from_slice: {}", list)?;
+    // 每一项都是 i32 加一个指针，List 的大小与列表长度无关
+    writeln!(ctx, "List 的大小不超过 i32 加两个指针: {}", mem::size_of::<List>() <= 4 + 2 * mem::size_of::<usize>())?;
+
+    ctx.section(4, "Deref：像引用一样使用智能指针", "Deref: using smart pointers like references")?;
+    let x = 5;
+    let y = Box::new(x);
+    let z = MyBox::new(x);
+    writeln!(ctx, "x == *Box: {}, x == *MyBox: {}", x == *y, x == *z)?;
+    // *z 实际上是 *(z.deref())
+    writeln!(ctx, "*(z.deref()) = {}", *std::ops::Deref::deref(&z))?;
+
+    ctx.section(5, "Deref 强制转换", "Deref coercion")?;
+    let name = MyBox::new(String::from("Rust"));
+    // &MyBox<String> -> &String -> &str，编译器自动插入 deref 调用
+    writeln!(ctx, "{}", hello(&name))?;
+    // 没有强制转换时需要手写：&(*name)[..]
+    writeln!(ctx, "{}", hello(&(*name)[..]))?;
+
+    ctx.section(6, "Drop：离开作用域时自动清理", "Drop: cleanup when leaving scope")?;
+    let log = Rc::new(RefCell::new(Vec::new()));
+    {
+        let _a = Resource::new("a", &log);
+        let _b = Box::new(Resource::new("b（在堆上）", &log));
+        writeln!(ctx, "两个资源都在作用域内")?;
+    } // 变量按声明的相反顺序释放：先 _b 再 _a
+    writeln!(ctx, "释放顺序: {:?}", log.borrow())?;
+
+    ctx.section(7, "用 mem::drop 提前释放", "Freeing early with mem::drop")?;
+    log.borrow_mut().clear();
+    let early = Resource::new("提前释放", &log);
+    let late = Resource::new("最后释放", &log);
+    // 不能直接调用 early.drop()（E0040），要用 std::mem::drop
+    drop(early);
+    writeln!(ctx, "drop 之后: {:?}", log.borrow())?;
+    drop(late);
+    writeln!(ctx, "全部释放后: {:?}", log.borrow())?;
+
+    ctx.section(8, "Box<dyn Trait>：不同类型放进同一个向量", "Box<dyn Trait>: different types in one vector")?;
+    let shapes: Vec<Box<dyn Shape>> = vec![Box::new(Square(2.0)), Box::new(Circle(1.0))];
+    for shape in &shapes {
+        writeln!(ctx, "{} 的面积: {:.2}", shape.name(), shape.area())?;
+    }
+
+    Ok(())
+}
+
+// cons list：每一项包含一个值和指向下一项的 Box
+#[derive(Debug)]
+pub enum List {
+    Cons(i32, Box<List>),
+    Nil,
+}
+
+use List::{Cons, Nil};
+
+impl List {
+    pub fn from_slice(values: &[i32]) -> List {
+        values.iter().rev().fold(Nil, |rest, value| Cons(*value, Box::new(rest)))
+    }
+
+    pub fn sum(&self) -> i32 {
+        match self {
+            Cons(value, rest) => value + rest.sum(),
+            Nil => 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        match self {
+            Cons(_, rest) => 1 + rest.len(),
+            Nil => 0,
+        }
+    }
+}
+
+// 打印为 (1, (2, (3, Nil)))
+impl fmt::Display for List {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Cons(value, rest) => write!(f, "({}, {})", value, rest),
+            Nil => write!(f, "Nil"),
+        }
+    }
+}
+
+fn hello(name: &str) -> String {
+    format!("Hello, {}!", name)
+}
+
+trait Shape {
+    fn name(&self) -> &'static str;
+    fn area(&self) -> f64;
+}
+
+struct Square(f64);
+
+struct Circle(f64);
+
+impl Shape for Square {
+    fn name(&self) -> &'static str {
+        "正方形"
+    }
+
+    fn area(&self) -> f64 {
+        self.0 * self.0
+    }
+}
+
+impl Shape for Circle {
+    fn name(&self) -> &'static str {
+        "圆"
+    }
+
+    fn area(&self) -> f64 {
+        std::f64::consts::PI * self.0 * self.0
+    }
+}
@@ -0,0 +1,132 @@
+//! 智能指针练习
+//!
+//! 把下面函数中的 `todo!()` 换成实现，然后运行 `rust_study check <练习>` 检查，
+//! 例如 `rust_study check smart_pointers/list_max`。
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::check::{expect_eq, Case, Exercise};
+use crate::smart_pointers::box_pointers::List;
+use crate::smart_pointers::weak::Node;
+
+// 练习 1：返回 cons list 中最大的元素，空列表返回 None（参考 box_pointers 课中的 List::sum）
+pub fn list_max(list: &List) -> Option<i32> {
+    todo!()
+}
+
+// 练习 2：每个名字都通过 Rc::clone 拿到同一个日志，把 "名字: 消息" 推入日志；
+// 返回日志的内容（参考 refcell 课中的购物车）
+pub fn shared_log(entries: &[(&str, &str)]) -> Vec<String> {
+    todo!()
+}
+
+// 练习 3：返回节点的深度，根节点为 0；沿 parent 的 Weak 链接向上数（参考 weak 课中的 path_to_root）
+pub fn depth(node: &Rc<Node>) -> usize {
+    todo!()
+}
+
+fn chain(values: &[i32]) -> Vec<Rc<Node>> {
+    let nodes: Vec<Rc<Node>> = values.iter().map(|value| Node::new(*value)).collect();
+    for pair in nodes.windows(2) {
+        Node::add_child(&pair[0], &pair[1]);
+    }
+    nodes
+}
+
+pub static EXERCISES: &[Exercise] = &[
+    Exercise {
+        id: "list_max",
+        topic: "smart_pointers",
+        lesson: "box_pointers",
+        title: "cons list 的最大值",
+        title_en: "Maximum of a cons list",
+        cases: &[
+            Case {
+                name: "空列表",
+                name_en: "Empty list",
+                hint: "match list { List::Nil => None, ... }",
+                hint_en: "match list { List::Nil => None, ... }",
+                run: || expect_eq(list_max(&List::Nil), None),
+            },
+            Case {
+                name: "多个元素",
+                name_en: "Several elements",
+                hint: "对 Cons(value, rest) 递归调用 list_max(rest)，再与 value 比较",
+                hint_en: "For Cons(value, rest), call list_max(rest) recursively and compare with value",
+                run: || expect_eq(list_max(&List::from_slice(&[3, 9, 4])), Some(9)),
+            },
+            Case {
+                name: "全是负数",
+                name_en: "All negative",
+                hint: "不要用 0 作为初始值，rest 为空时最大值就是 value 本身",
+                hint_en: "Don't start from 0; when rest is empty the maximum is value itself",
+                run: || expect_eq(list_max(&List::from_slice(&[-8, -2, -5])), Some(-2)),
+            },
+        ],
+    },
+    Exercise {
+        id: "shared_log",
+        topic: "smart_pointers",
+        lesson: "refcell",
+        title: "共享的日志",
+        title_en: "A shared log",
+        cases: &[
+            Case {
+                name: "没有消息",
+                name_en: "No messages",
+                hint: "创建 Rc::new(RefCell::new(Vec::new()))，没有消息时直接返回空向量",
+                hint_en: "Create Rc::new(RefCell::new(Vec::new())); with no messages return the empty vector",
+                run: || expect_eq(shared_log(&[]), Vec::<String>::new()),
+            },
+            Case {
+                name: "按顺序记录",
+                name_en: "Records in order",
+                hint: "每条消息 log.borrow_mut().push(format!(\"{}: {}\", name, message))",
+                hint_en: "For each message, log.borrow_mut().push(format!(\"{}: {}\", name, message))",
+                run: || {
+                    expect_eq(
+                        shared_log(&[("Alice", "你好"), ("Bob", "再见")]),
+                        vec![String::from("Alice: 你好"), String::from("Bob: 再见")],
+                    )
+                },
+            },
+        ],
+    },
+    Exercise {
+        id: "depth",
+        topic: "smart_pointers",
+        lesson: "weak",
+        title: "节点的深度",
+        title_en: "Depth of a node",
+        cases: &[
+            Case {
+                name: "根节点",
+                name_en: "Root node",
+                hint: "node.parent.borrow().upgrade() 为 None 时深度为 0",
+                hint_en: "The depth is 0 when node.parent.borrow().upgrade() is None",
+                run: || expect_eq(depth(&Node::new(1)), 0),
+            },
+            Case {
+                name: "三层",
+                name_en: "Three levels",
+                hint: "用 while let Some(parent) = current 循环，每次 current = parent.parent.borrow().upgrade()",
+                hint_en: "Loop with while let Some(parent) = current, setting current = parent.parent.borrow().upgrade() each time",
+                run: || {
+                    let nodes = chain(&[1, 2, 3]);
+                    expect_eq(depth(&nodes[2]), 2)
+                },
+            },
+            Case {
+                name: "父节点被释放",
+                name_en: "Parent dropped",
+                hint: "父节点被释放后 upgrade 返回 None，深度就是 0",
+                hint_en: "Once the parent is dropped, upgrade returns None and the depth is 0",
+                run: || {
+                    let leaf = chain(&[1, 2]).pop().unwrap();
+                    expect_eq(depth(&leaf), 0)
+                },
+            },
+        ],
+    },
+];
@@ -0,0 +1,11 @@
+//! 智能指针模块
+//!
+//! 包含 Box、Rc、RefCell 和 Weak 等智能指针示例。
+
+// 声明子模块
+pub mod box_pointers;
+pub mod rc;
+pub mod refcell;
+pub mod weak;
+pub mod exercises;
+pub mod quiz;
@@ -0,0 +1,90 @@
+//! 智能指针小测验
+//!
+//! 预测输出题运行与 rc 和 weak 课相同的代码，答案以实际运行结果为准。
+
+use std::cell::RefCell;
+use std::io::{self, Write};
+use std::rc::Rc;
+
+use crate::context::Ctx;
+use crate::quiz::{Answer, Question};
+
+pub static QUESTIONS: &[Question] = &[
+    Question {
+        topic: "smart_pointers",
+        lesson: "rc",
+        prompt: "下面的代码输出什么？",
+        prompt_en: "What does this code print?",
+        code: "let a = Rc::new(5);\nlet b = Rc::clone(&a);\n{\n    let _c = Rc::clone(&a);\n    print!(\"{} \", Rc::strong_count(&a));\n}\ndrop(b);\nprintln!(\"{}\", Rc::strong_count(&a));",
+        options: &["3 1", "3 2", "2 1", "1 1"],
+        options_en: &["3 1", "3 2", "2 1", "1 1"],
+        answer: Answer::Output(strong_counts),
+        explanation: "a、b、_c 三个所有者时计数为 3；_c 离开作用域、b 被丢弃后只剩 a，计数为 1。",
+        explanation_en: "With the three owners a, b and _c the count is 3; after _c goes out of scope and b is dropped only a is left, so the count is 1.",
+    },
+    Question {
+        topic: "smart_pointers",
+        lesson: "box_pointers",
+        prompt: "为什么 enum List { Cons(i32, List), Nil } 不能编译？",
+        prompt_en: "Why doesn't enum List { Cons(i32, List), Nil } compile?",
+        code: "",
+        options: &[
+            "枚举的变体不能包含元组",
+            "List 直接包含自身，编译器无法计算它的大小",
+            "i32 需要换成 Box<i32>",
+            "Nil 变体必须放在最前面",
+        ],
+        options_en: &[
+            "Enum variants can't contain tuples",
+            "List contains itself directly, so the compiler can't compute its size",
+            "i32 has to become Box<i32>",
+            "The Nil variant must come first",
+        ],
+        answer: Answer::Choice(1),
+        explanation: "递归类型的大小无限（E0072）；把内层的 List 换成 Box<List>，每一项就只包含一个固定大小的指针。",
+        explanation_en: "A recursive type has infinite size (E0072); replace the inner List with Box<List> and each item holds just one fixed-size pointer.",
+    },
+    Question {
+        topic: "smart_pointers",
+        lesson: "refcell",
+        prompt: "下面的代码运行时会发生什么？",
+        prompt_en: "What happens when this code runs?",
+        code: "let cell = RefCell::new(vec![1]);\nlet first = cell.borrow_mut();\nlet second = cell.borrow_mut();",
+        options: &["编译错误", "正常运行", "运行时 panic：already borrowed", "second 得到一份拷贝"],
+        options_en: &["Compile error", "It runs normally", "Panic at run time: already borrowed", "second gets a copy"],
+        answer: Answer::Choice(2),
+        explanation: "RefCell 在运行时检查借用规则；同时存在两个可变借用时 borrow_mut 会 panic，try_borrow_mut 则返回 Err。",
+        explanation_en: "RefCell checks the borrowing rules at run time; borrow_mut panics when two mutable borrows would coexist, while try_borrow_mut returns Err.",
+    },
+    Question {
+        topic: "smart_pointers",
+        lesson: "weak",
+        prompt: "下面的代码输出什么？",
+        prompt_en: "What does this code print?",
+        code: "let strong = Rc::new(RefCell::new(1));\nlet weak = Rc::downgrade(&strong);\nprint!(\"{} {} \", Rc::strong_count(&strong), Rc::weak_count(&strong));\ndrop(strong);\nprintln!(\"{}\", weak.upgrade().is_some());",
+        options: &["1 1 false", "2 1 true", "1 1 true", "2 0 false"],
+        options_en: &["1 1 false", "2 1 true", "1 1 true", "2 0 false"],
+        answer: Answer::Output(weak_upgrade),
+        explanation: "downgrade 只增加弱引用计数；唯一的强引用被丢弃后值就被释放了，upgrade 返回 None。",
+        explanation_en: "downgrade only increases the weak count; once the only strong reference is dropped the value is freed and upgrade returns None.",
+    },
+];
+
+fn strong_counts(ctx: &mut Ctx) -> io::Result<()> {
+    let a = Rc::new(5);
+    let b = Rc::clone(&a);
+    {
+        let _c = Rc::clone(&a);
+        write!(ctx, "{} ", Rc::strong_count(&a))?;
+    }
+    drop(b);
+    writeln!(ctx, "{}", Rc::strong_count(&a))
+}
+
+fn weak_upgrade(ctx: &mut Ctx) -> io::Result<()> {
+    let strong = Rc::new(RefCell::new(1));
+    let weak = Rc::downgrade(&strong);
+    write!(ctx, "{} {} ", Rc::strong_count(&strong), Rc::weak_count(&strong))?;
+    drop(strong);
+    writeln!(ctx, "{}", weak.upgrade().is_some())
+}
@@ -0,0 +1,124 @@
+//! Rc<T>：引用计数
+//!
+//! 有些值需要多个所有者，例如图中被多条边指向的节点。`Rc<T>` 记录一个值有多少个所有者
+//! （强引用计数），计数降到 0 时才释放这个值。
+//!
+//! `Rc::clone` 只增加计数，不深拷贝数据；`Rc<T>` 只能在单线程中使用，
+//! 多线程时换成 `Arc<T>`（见 concurrency 主题）。通过 `Rc<T>` 只能得到不可变引用，
+//! 需要修改时配合 `RefCell<T>`（见 refcell 课）。
+
+use std::cell::RefCell;
+use std::fmt;
+use std::io::{self, Write};
+use std::rc::Rc;
+
+use crate::advanced::traits::Resource;
+use crate::context::Ctx;
+
+pub fn main(ctx: &mut Ctx) -> io::Result<()> {
+    writeln!(ctx, "=== {} ===", ctx.text("lesson.rc"))?;
+
+    ctx.section(1, "共享同一个列表的尾部", "Sharing the tail of a list")?;
+    // b 和 c 都以 a 为尾部；用 Box 的话 a 只能被移动进其中一个
+    let a = Rc::new(Cons(5, Rc::new(Cons(10, Rc::new(Nil)))));
+    let b = Cons(3, Rc::clone(&a));
+    let c = Cons(4, Rc::clone(&a));
+    writeln!(ctx, "a = {}", a)?;
+    writeln!(ctx, "b = {}", b)?;
+    writeln!(ctx, "c = {}", c)?;
+
+    ctx.section(2, "观察强引用计数", "Watching the strong count")?;
+    let a = Rc::new(Cons(5, Rc::new(Cons(10, Rc::new(Nil)))));
+    writeln!(ctx, "创建 a 之后: {}", Rc::strong_count(&a))?;
+    let _b = Cons(3, Rc::clone(&a));
+    writeln!(ctx, "创建 b 之后: {}", Rc::strong_count(&a))?;
+    {
+        let _c = Cons(4, Rc::clone(&a));
+        writeln!(ctx, "创建 c 之后: {}", Rc::strong_count(&a))?;
+    }
+    writeln!(ctx, "c 离开作用域之后: {}", Rc::strong_count(&a))?;
+
+    ctx.section(3, "最后一个所有者离开时才释放", "Freed only when the last owner goes away")?;
+    let log = Rc::new(RefCell::new(Vec::new()));
+    let first = Rc::new(Resource::new("共享资源", &log));
+    let second = Rc::clone(&first);
+    drop(first);
+    writeln!(ctx, "丢弃一个所有者后: 计数 {}，日志 {:?}", Rc::strong_count(&second), log.borrow())?;
+    drop(second);
+    writeln!(ctx, "丢弃最后一个所有者后: 日志 {:?}", log.borrow())?;
+
+    ctx.section(4, "Rc::ptr_eq 比较是否指向同一个值", "Rc::ptr_eq: do two Rcs point to the same value")?;
+    let x = Rc::new(String::from("hello"));
+    let y = Rc::clone(&x);
+    let z = Rc::new(String::from("hello"));
+    writeln!(ctx, "x == z（比较内容）: {}", x == z)?;
+    writeln!(ctx, "ptr_eq(x, y): {}, ptr_eq(x, z): {}", Rc::ptr_eq(&x, &y), Rc::ptr_eq(&x, &z))?;
+
+    ctx.section(5, "try_unwrap：只有一个所有者时取回值", "try_unwrap: taking the value back from the only owner")?;
+    let shared = Rc::new(String::from("数据"));
+    let other = Rc::clone(&shared);
+    let shared = match Rc::try_unwrap(shared) {
+        Ok(value) => {
+            writeln!(ctx, "取回: {}", value)?;
+            Rc::new(value)
+        }
+        Err(shared) => {
+            writeln!(ctx, "还有 {} 个所有者，try_unwrap 把 Rc 原样还回来", Rc::strong_count(&shared))?;
+            shared
+        }
+    };
+    drop(other);
+    writeln!(ctx, "只剩一个所有者时: {:?}", Rc::try_unwrap(shared))?;
+
+    ctx.section(6, "make_mut：写时复制", "make_mut: copy on write")?;
+    let mut original = Rc::new(vec![1, 2, 3]);
+    let snapshot = Rc::clone(&original);
+    // 有其他所有者时 make_mut 先克隆一份，再修改自己的那份
+    Rc::make_mut(&mut original).push(4);
+    writeln!(ctx, "修改后的: {:?}，快照: {:?}", original, snapshot)?;
+    writeln!(ctx, "两者仍然共享吗: {}", Rc::ptr_eq(&original, &snapshot))?;
+    // 只有一个所有者时直接修改，不再复制
+    Rc::make_mut(&mut original).push(5);
+    writeln!(ctx, "再次修改: {:?}，强引用计数 {}", original, Rc::strong_count(&original))?;
+
+    ctx.section(7, "共享的只读配置", "Shared read-only configuration")?;
+    let config = Rc::new(Config { name: String::from("study"), level: 3 });
+    let services: Vec<Service> = ["解析器", "运行器", "报告"]
+        .into_iter()
+        .map(|name| Service { name, config: Rc::clone(&config) })
+        .collect();
+    for service in &services {
+        writeln!(ctx, "{} 使用配置 {}（级别 {}）", service.name, service.config.name, service.config.level)?;
+    }
+    writeln!(ctx, "配置的所有者数量: {}", Rc::strong_count(&config))?;
+
+    Ok(())
+}
+
+// 尾部可以共享的 cons list
+#[derive(Debug)]
+pub enum List {
+    Cons(i32, Rc<List>),
+    Nil,
+}
+
+use List::{Cons, Nil};
+
+impl fmt::Display for List {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Cons(value, rest) => write!(f, "({}, {})", value, rest),
+            Nil => write!(f, "Nil"),
+        }
+    }
+}
+
+struct Config {
+    name: String,
+    level: u32,
+}
+
+struct Service {
+    name: &'static str,
+    config: Rc<Config>,
+}
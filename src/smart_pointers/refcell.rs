@@ -0,0 +1,202 @@
+//! RefCell<T> 与内部可变性
+//!
+//! 借用规则通常在编译时检查；`RefCell<T>` 把检查推迟到运行时：
+//! 通过不可变的 `&RefCell<T>` 也能调用 `borrow_mut` 修改内部的值，
+//! 违反“一个可变借用或多个不可变借用”的规则时不是编译错误，而是 panic。
+//!
+//! 典型用法是测试替身（mock object）：trait 方法只接收 `&self`，
+//! mock 仍然需要记录每次调用。`Rc<RefCell<T>>` 则可以让多个所有者共同修改一个值。
+//! 对于 `Copy` 类型，更轻量的 `Cell<T>` 直接整体替换值，不需要借用。
+
+use std::cell::{Cell, RefCell};
+use std::io::{self, Write};
+use std::rc::Rc;
+
+use crate::context::Ctx;
+
+pub fn main(ctx: &mut Ctx) -> io::Result<()> {
+    writeln!(ctx, "=== {} ===", ctx.text("lesson.refcell"))?;
+
+    ctx.section(1, "通过不可变引用修改内部的值", "Mutating through an immutable reference")?;
+    let cell = RefCell::new(vec![1, 2, 3]);
+    let shared: &RefCell<Vec<i32>> = &cell;
+    shared.borrow_mut().push(4);
+    writeln!(ctx, "修改后: {:?}", cell.borrow())?;
+    // let x = 5;
+    // let y = &mut x; // 错误：不能对不可变变量取可变引用
+
+    ctx.section(2, "mock 对象记录收到的消息", "A mock object records the messages it receives")?;
+    let messenger = MockMessenger::new();
+    let mut tracker = LimitTracker::new(&messenger, 100);
+    for value in [50, 80, 95, 120] {
+        tracker.set_value(value);
+    }
+    writeln!(ctx, "mock 收到 {} 条消息:", messenger.sent_messages.borrow().len())?;
+    for message in messenger.sent_messages.borrow().iter() {
+        writeln!(ctx, "  {}", message)?;
+    }
+
+    ctx.section(3, "运行时的借用检查", "Borrow checking at runtime")?;
+    let cell = RefCell::new(String::from("hello"));
+    {
+        let first = cell.borrow();
+        let second = cell.borrow();
+        writeln!(ctx, "两个不可变借用: {} {}", *first, *second)?;
+        // 已有不可变借用时再可变借用会 panic；try_borrow_mut 把它变成 Err
+        writeln!(ctx, "同时可变借用: {}", describe(cell.try_borrow_mut().map(|_| ())))?;
+    }
+    writeln!(ctx, "借用结束后可变借用: {}", describe(cell.try_borrow_mut().map(|_| ())))?;
+    {
+        let _writer = cell.borrow_mut();
+        writeln!(ctx, "可变借用期间不可变借用: {}", describe(cell.try_borrow().map(|_| ())))?;
+    }
+
+    ctx.section(4, "Rc<RefCell<T>>：多个所有者共同修改", "Rc<RefCell<T>>: shared mutation by several owners")?;
+    let value = Rc::new(RefCell::new(5));
+    let a = Cons(Rc::clone(&value), Rc::new(Nil));
+    let b = Cons(Rc::new(RefCell::new(3)), Rc::new(a));
+    *value.borrow_mut() += 10;
+    writeln!(ctx, "修改 value 之后 b = {}", b.describe())?;
+
+    ctx.section(5, "共享的购物车", "A shared shopping cart")?;
+    let cart = Rc::new(RefCell::new(Vec::new()));
+    let alice = Shopper { name: "Alice", cart: Rc::clone(&cart) };
+    let bob = Shopper { name: "Bob", cart: Rc::clone(&cart) };
+    alice.add("苹果");
+    bob.add("面包");
+    alice.add("牛奶");
+    writeln!(ctx, "购物车: {:?}", cart.borrow())?;
+
+    ctx.section(6, "Cell<T>：整体替换 Copy 值", "Cell<T>: replacing Copy values wholesale")?;
+    let counter = Counter { hits: Cell::new(0) };
+    for _ in 0..3 {
+        counter.hit();
+    }
+    writeln!(ctx, "通过 &self 计数: {}", counter.hits.get())?;
+    let flag = Cell::new(false);
+    let old = flag.replace(true);
+    writeln!(ctx, "replace 返回旧值 {}，现在是 {}", old, flag.get())?;
+
+    ctx.section(7, "缓存：在 &self 方法中保存计算结果", "Caching: storing a result inside a &self method")?;
+    let fib = Fibonacci::new();
+    writeln!(ctx, "fib(30) = {}", fib.get(30))?;
+    writeln!(ctx, "缓存了 {} 个值", fib.cache.borrow().len())?;
+    writeln!(ctx, "再次计算 fib(25) = {}，缓存仍然是 {} 个", fib.get(25), fib.cache.borrow().len())?;
+
+    Ok(())
+}
+
+fn describe<E>(result: Result<(), E>) -> &'static str {
+    match result {
+        Ok(()) => "成功",
+        Err(_) => "失败（会 panic 的借用）",
+    }
+}
+
+// 需要测试的代码只依赖这个 trait
+pub trait Messenger {
+    fn send(&self, msg: &str);
+}
+
+// 跟踪数值相对上限的比例，超过阈值时发送提醒
+pub struct LimitTracker<'a, T: Messenger> {
+    messenger: &'a T,
+    value: usize,
+    max: usize,
+}
+
+impl<'a, T: Messenger> LimitTracker<'a, T> {
+    pub fn new(messenger: &'a T, max: usize) -> LimitTracker<'a, T> {
+        LimitTracker { messenger, value: 0, max }
+    }
+
+    pub fn set_value(&mut self, value: usize) {
+        self.value = value;
+        let percentage = self.value as f64 / self.max as f64;
+
+        if percentage >= 1.0 {
+            self.messenger.send("错误：已超出配额！");
+        } else if percentage >= 0.9 {
+            self.messenger.send("紧急警告：已使用超过 90% 的配额！");
+        } else if percentage >= 0.75 {
+            self.messenger.send("警告：已使用超过 75% 的配额");
+        }
+    }
+}
+
+// send 只拿到 &self，用 RefCell 记录消息
+pub struct MockMessenger {
+    pub sent_messages: RefCell<Vec<String>>,
+}
+
+impl MockMessenger {
+    pub fn new() -> MockMessenger {
+        MockMessenger { sent_messages: RefCell::new(Vec::new()) }
+    }
+}
+
+impl Messenger for MockMessenger {
+    fn send(&self, message: &str) {
+        self.sent_messages.borrow_mut().push(String::from(message));
+    }
+}
+
+// 值可以被修改的 cons list
+#[derive(Debug)]
+enum List {
+    Cons(Rc<RefCell<i32>>, Rc<List>),
+    Nil,
+}
+
+use List::{Cons, Nil};
+
+impl List {
+    fn describe(&self) -> String {
+        match self {
+            Cons(value, rest) => format!("({}, {})", value.borrow(), rest.describe()),
+            Nil => String::from("Nil"),
+        }
+    }
+}
+
+struct Shopper {
+    name: &'static str,
+    cart: Rc<RefCell<Vec<String>>>,
+}
+
+impl Shopper {
+    fn add(&self, item: &str) {
+        self.cart.borrow_mut().push(format!("{}: {}", self.name, item));
+    }
+}
+
+struct Counter {
+    hits: Cell<u32>,
+}
+
+impl Counter {
+    fn hit(&self) {
+        self.hits.set(self.hits.get() + 1);
+    }
+}
+
+// 带缓存的斐波那契数，对外只暴露 &self 方法
+struct Fibonacci {
+    cache: RefCell<Vec<u64>>,
+}
+
+impl Fibonacci {
+    fn new() -> Fibonacci {
+        Fibonacci { cache: RefCell::new(vec![0, 1]) }
+    }
+
+    fn get(&self, n: usize) -> u64 {
+        // 先在一个短的借用中查缓存，计算时不能一直借用着，否则递归调用会 panic
+        if let Some(value) = self.cache.borrow().get(n) {
+            return *value;
+        }
+        let value = self.get(n - 1) + self.get(n - 2);
+        self.cache.borrow_mut().push(value);
+        value
+    }
+}
@@ -0,0 +1,202 @@
+//! Weak<T> 与引用循环
+//!
+//! 两个 `Rc` 互相指向时，它们的强引用计数永远不会降到 0，内存就泄漏了。
+//! Rust 的内存安全保证并不排除这种泄漏。
+//!
+//! `Rc::downgrade` 得到一个 `Weak<T>`：它只增加弱引用计数，不阻止值被释放，
+//! 使用前要先 `upgrade` 成 `Option<Rc<T>>`。树结构里父节点拥有子节点（强引用），
+//! 子节点指回父节点用弱引用，就不会形成循环。
+
+use std::cell::RefCell;
+use std::io::{self, Write};
+use std::rc::{Rc, Weak};
+
+use crate::advanced::traits::Resource;
+use crate::context::Ctx;
+
+pub fn main(ctx: &mut Ctx) -> io::Result<()> {
+    writeln!(ctx, "=== {} ===", ctx.text("lesson.weak"))?;
+
+    ctx.section(1, "Weak 不阻止值被释放", "Weak doesn't keep the value alive")?;
+    let strong = Rc::new(String::from("数据"));
+    let weak: Weak<String> = Rc::downgrade(&strong);
+    writeln!(ctx, "强引用 {}，弱引用 {}", Rc::strong_count(&strong), Rc::weak_count(&strong))?;
+    writeln!(ctx, "upgrade: {:?}", weak.upgrade())?;
+    drop(strong);
+    writeln!(ctx, "释放后 upgrade: {:?}", weak.upgrade())?;
+
+    ctx.section(2, "树：子节点用 Weak 指向父节点", "Trees: children point to their parent with Weak")?;
+    let leaf = Node::new(3);
+    writeln!(ctx, "leaf 的父节点 = {:?}", leaf.parent_value())?;
+    let branch = Node::new(5);
+    Node::add_child(&branch, &leaf);
+    writeln!(ctx, "leaf 的父节点 = {:?}", leaf.parent_value())?;
+    writeln!(ctx, "branch 的子节点 = {:?}", branch.child_values())?;
+
+    ctx.section(3, "观察强引用和弱引用计数", "Watching strong and weak counts")?;
+    let leaf = Node::new(3);
+    writeln!(ctx, "leaf: {}", counts(&leaf))?;
+    {
+        let branch = Node::new(5);
+        Node::add_child(&branch, &leaf);
+        // branch 拥有 leaf（leaf 强引用 +1），leaf 弱引用指向 branch
+        writeln!(ctx, "branch: {}", counts(&branch))?;
+        writeln!(ctx, "leaf: {}", counts(&leaf))?;
+    }
+    // branch 被释放，leaf 的父节点链接自动失效
+    writeln!(ctx, "branch 离开作用域后 leaf 的父节点 = {:?}", leaf.parent_value())?;
+    writeln!(ctx, "leaf: {}", counts(&leaf))?;
+
+    ctx.section(4, "从叶子走到根", "Walking from a leaf to the root")?;
+    let root = Node::new(1);
+    let middle = Node::new(2);
+    let leaf = Node::new(3);
+    Node::add_child(&root, &middle);
+    Node::add_child(&middle, &leaf);
+    writeln!(ctx, "从 leaf 到根的路径: {:?}", leaf.path_to_root())?;
+
+    ctx.section(5, "制造一个引用循环", "Creating a reference cycle")?;
+    let log = Rc::new(RefCell::new(Vec::new()));
+    let a = Link::new("a", &log);
+    let b = Link::new("b", &log);
+    *a.next.borrow_mut() = Some(Rc::clone(&b));
+    *b.next.borrow_mut() = Some(Rc::clone(&a));
+    writeln!(ctx, "a 的强引用 {}，b 的强引用 {}", Rc::strong_count(&a), Rc::strong_count(&b))?;
+    writeln!(ctx, "沿 next 走 5 步: {:?}", walk(&a, 5))?;
+    writeln!(ctx, "从 a 出发能回到 a: {}", has_cycle(&a))?;
+
+    ctx.section(6, "用计数发现泄漏", "Spotting the leak with counts")?;
+    // 只留下弱引用，再丢弃所有外部的强引用
+    let watch_a = Rc::downgrade(&a);
+    let watch_b = Rc::downgrade(&b);
+    drop(a);
+    drop(b);
+    // 如果没有循环，值已经被释放，upgrade 应该返回 None
+    let leaked = watch_a.upgrade().is_some() && watch_b.upgrade().is_some();
+    writeln!(ctx, "外部引用都丢弃后，a 和 b 仍然存活: {}", leaked)?;
+    writeln!(ctx, "此时 a 的强引用计数: {}", watch_a.strong_count())?;
+    writeln!(ctx, "释放日志: {:?}", log.borrow())?;
+
+    ctx.section(7, "打破循环", "Breaking the cycle")?;
+    if let Some(a) = watch_a.upgrade() {
+        // 拿走 a.next，b 的强引用少 1；a 离开作用域后 b 持有的引用是最后一个
+        a.next.borrow_mut().take();
+    }
+    writeln!(ctx, "a 存活: {}，b 存活: {}", watch_a.upgrade().is_some(), watch_b.upgrade().is_some())?;
+    writeln!(ctx, "释放日志: {:?}", log.borrow())?;
+
+    ctx.section(8, "用 Weak 代替其中一个方向", "Using Weak for one direction")?;
+    log.borrow_mut().clear();
+    {
+        let owner = Link::new("owner", &log);
+        let child = Link::new("child", &log);
+        *owner.next.borrow_mut() = Some(Rc::clone(&child));
+        // 反方向只保存弱引用
+        *child.back.borrow_mut() = Rc::downgrade(&owner);
+        let back = child.back.borrow().upgrade().map(|link| link.name());
+        writeln!(ctx, "child 通过 Weak 找到: {:?}", back)?;
+        writeln!(ctx, "有循环吗: {}", has_cycle(&owner))?;
+    }
+    writeln!(ctx, "离开作用域后的释放日志: {:?}", log.borrow())?;
+
+    Ok(())
+}
+
+// 树节点：children 是强引用，parent 是弱引用
+#[derive(Debug)]
+pub struct Node {
+    pub value: i32,
+    pub parent: RefCell<Weak<Node>>,
+    pub children: RefCell<Vec<Rc<Node>>>,
+}
+
+impl Node {
+    pub fn new(value: i32) -> Rc<Node> {
+        Rc::new(Node {
+            value,
+            parent: RefCell::new(Weak::new()),
+            children: RefCell::new(Vec::new()),
+        })
+    }
+
+    // 父节点强引用子节点，子节点弱引用父节点
+    pub fn add_child(parent: &Rc<Node>, child: &Rc<Node>) {
+        *child.parent.borrow_mut() = Rc::downgrade(parent);
+        parent.children.borrow_mut().push(Rc::clone(child));
+    }
+
+    pub fn parent_value(&self) -> Option<i32> {
+        self.parent.borrow().upgrade().map(|parent| parent.value)
+    }
+
+    fn child_values(&self) -> Vec<i32> {
+        self.children.borrow().iter().map(|child| child.value).collect()
+    }
+
+    // 沿 parent 一直向上，直到根节点
+    pub fn path_to_root(&self) -> Vec<i32> {
+        let mut path = vec![self.value];
+        let mut current = self.parent.borrow().upgrade();
+        while let Some(node) = current {
+            path.push(node.value);
+            current = node.parent.borrow().upgrade();
+        }
+        path
+    }
+}
+
+fn counts<T>(rc: &Rc<T>) -> String {
+    format!("强引用 = {}，弱引用 = {}", Rc::strong_count(rc), Rc::weak_count(rc))
+}
+
+// 链表节点：next 是强引用，back 是弱引用；释放时记到日志里
+struct Link {
+    resource: Resource,
+    next: RefCell<Option<Rc<Link>>>,
+    back: RefCell<Weak<Link>>,
+}
+
+impl Link {
+    fn new(name: &str, log: &Rc<RefCell<Vec<String>>>) -> Rc<Link> {
+        Rc::new(Link {
+            resource: Resource::new(name, log),
+            next: RefCell::new(None),
+            back: RefCell::new(Weak::new()),
+        })
+    }
+
+    fn name(&self) -> String {
+        self.resource.name.clone()
+    }
+}
+
+// 沿 next 最多走 steps 步，记录经过的节点名
+fn walk(start: &Rc<Link>, steps: usize) -> Vec<String> {
+    let mut names = Vec::new();
+    let mut current = Some(Rc::clone(start));
+    while let Some(link) = current {
+        if names.len() == steps {
+            break;
+        }
+        names.push(link.name());
+        current = link.next.borrow().clone();
+    }
+    names
+}
+
+// 沿 next 前进，回到起点就说明有循环；用 ptr_eq 比较是不是同一个节点
+fn has_cycle(start: &Rc<Link>) -> bool {
+    let mut visited: Vec<Rc<Link>> = Vec::new();
+    let mut current = start.next.borrow().clone();
+    while let Some(link) = current {
+        if Rc::ptr_eq(&link, start) {
+            return true;
+        }
+        if visited.iter().any(|seen| Rc::ptr_eq(seen, &link)) {
+            return false;
+        }
+        current = link.next.borrow().clone();
+        visited.push(link);
+    }
+    false
+}
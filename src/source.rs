@@ -0,0 +1,368 @@
+//! 课程源码
+//!
+//! show 命令显示课程文件（编译时用 include_str! 嵌入，见 registry.rs），带行号，
+//! 输出到终端时加上简单的语法高亮。
+//!
+//! 加上 --with-output 时还会运行这一课，把输出插到产生它的编号小节
+//! （`ctx.section(1, "...", "...")?`）后面：Ctx 记录每次 writeln! 和 ctx.section 的调用位置，
+//! 执行到哪一节的 ctx.section，之后的输出（包括辅助函数写出的）就归哪一节。
+
+use std::io::{self, Write};
+use std::ops::Range;
+use std::panic::{self, AssertUnwindSafe};
+
+use crate::context::Ctx;
+use crate::i18n::Locale;
+use crate::registry::{self, Lesson, Target};
+use crate::runner;
+
+// 课程文件中的一段：main 中的一个编号小节，或者第一个小节之前、main 之后的部分
+#[derive(Debug, Clone, PartialEq)]
+pub struct Section {
+    // 小节编号和中英文标题，例如 1、"创建哈希映射" 和 "Creating hash maps"；不是编号小节时为 None
+    pub number: Option<usize>,
+    pub title: Option<String>,
+    pub title_en: Option<String>,
+    // 这一段的行，从 0 开始
+    pub lines: Range<usize>,
+    // 在这些行中写出的输出归这一段
+    pub owns: Range<usize>,
+}
+
+// 按 main 中声明的编号小节把课程文件分段；各段首尾相接，覆盖整个文件
+pub fn sections(source: &str) -> Vec<Section> {
+    let lines: Vec<&str> = source.lines().collect();
+    let whole = |owns| Section {
+        number: None,
+        title: None,
+        title_en: None,
+        lines: 0..lines.len(),
+        owns,
+    };
+    let Some(main_start) = lines.iter().position(|line| line.starts_with("pub fn main(")) else {
+        return vec![whole(0..0)];
+    };
+    let main_end = lines[main_start..]
+        .iter()
+        .position(|line| *line == "}")
+        .map_or(lines.len(), |offset| main_start + offset + 1);
+
+    let heads: Vec<(usize, usize, &str, &str)> = (main_start..main_end)
+        .filter_map(|i| section_heading(lines[i]).map(|(number, title, title_en)| (i, number, title, title_en)))
+        .collect();
+    let first = heads.first().map_or(main_end, |(i, _, _, _)| *i);
+
+    let mut sections = vec![Section {
+        number: None,
+        title: None,
+        title_en: None,
+        lines: 0..first,
+        owns: main_start..first,
+    }];
+    for (k, (start, number, title, title_en)) in heads.iter().enumerate() {
+        let end = heads.get(k + 1).map_or(main_end, |(next, _, _, _)| *next);
+        sections.push(Section {
+            number: Some(*number),
+            title: Some(title.to_string()),
+            title_en: Some(title_en.to_string()),
+            lines: *start..end,
+            owns: *start..end,
+        });
+    }
+    if main_end < lines.len() {
+        sections.push(Section {
+            number: None,
+            title: None,
+            title_en: None,
+            lines: main_end..lines.len(),
+            owns: main_end..main_end,
+        });
+    }
+    sections
+}
+
+impl Section {
+    // 当前语言的小节标题
+    pub fn title_in(&self, locale: Locale) -> Option<&str> {
+        match locale {
+            Locale::ZhCn => self.title.as_deref(),
+            Locale::EnUs => self.title_en.as_deref(),
+        }
+    }
+}
+
+// "    ctx.section(3, \"访问值\", \"Accessing values\")?;" -> (3, "访问值", "Accessing values")
+fn section_heading(line: &str) -> Option<(usize, &str, &str)> {
+    let rest = line.trim_start().strip_prefix("ctx.section(")?;
+    let (number, rest) = rest.split_once(", \"")?;
+    let (title, rest) = rest.split_once("\", \"")?;
+    let (title_en, _) = rest.split_once("\")")?;
+    Some((number.parse().ok()?, title, title_en))
+}
+
+// show：显示一课，或者一个主题中每一课的源码
+pub fn show_target(ctx: &mut Ctx, target: Target, with_output: bool) -> io::Result<()> {
+    match target {
+        Target::Topic(topic) => {
+            for lesson in registry::lessons_of(topic.id) {
+                show(ctx, lesson, with_output)?;
+            }
+            Ok(())
+        }
+        Target::Lesson(lesson) => show(ctx, lesson, with_output),
+    }
+}
+
+pub fn show(ctx: &mut Ctx, lesson: &'static Lesson, with_output: bool) -> io::Result<()> {
+    let title = lesson.title_in(ctx.locale());
+    writeln!(ctx, "\n=== {} ===", ctx.message("show.title", &[&title, &lesson.file()]))?;
+
+    let lines: Vec<&str> = lesson.source.lines().collect();
+    let width = lines.len().to_string().len();
+    let mut highlighter = Highlighter::default();
+    if !with_output {
+        for (i, line) in lines.iter().enumerate() {
+            write_line(ctx, &mut highlighter, width, i, line)?;
+        }
+        return Ok(());
+    }
+
+    let sections = sections(lesson.source);
+    let (outputs, failure) = run_traced(ctx, lesson, &sections);
+    for (section, output) in sections.iter().zip(&outputs) {
+        for i in section.lines.clone() {
+            write_line(ctx, &mut highlighter, width, i, lines[i])?;
+        }
+        if !output.is_empty() {
+            writeln!(ctx, "{:>width$} ┆ {}", "", ctx.text("show.output"))?;
+            for line in output.lines() {
+                writeln!(ctx, "{:>width$} ┆ {}", "", line)?;
+            }
+        }
+    }
+    if let Some(message) = failure {
+        writeln!(ctx, "{}", message)?;
+    }
+    Ok(())
+}
+
+// 一行源码：行号从 1 开始，开启颜色时加上高亮
+fn write_line(ctx: &mut Ctx, highlighter: &mut Highlighter, width: usize, i: usize, line: &str) -> io::Result<()> {
+    if ctx.color() {
+        let line = highlighter.line(line);
+        writeln!(ctx, "{}{:>width$} │{} {}", COMMENT, i + 1, RESET, line)
+    } else {
+        writeln!(ctx, "{:>width$} │ {}", i + 1, line)
+    }
+}
+
+// 在单独的 Ctx 中运行一课，把输出按小节分组；课程 panic 或出错时同时返回提示。
+// export 用它生成课程页面
+pub(crate) fn run_traced(ctx: &Ctx, lesson: &'static Lesson, sections: &[Section]) -> (Vec<String>, Option<String>) {
+    let mut out = Vec::new();
+    let mut input: &[u8] = &[];
+    // 工作目录的位置和是否保留跟随外层的 Ctx
+    let mut inner = Ctx::new(&mut out, &mut input)
+        .with_locale(ctx.locale())
+        .with_workspace_root(ctx.workspace_root().to_path_buf())
+        .keep_workspace(ctx.keeps_workspace());
+    inner.start_workspace(format!("{}-{}", lesson.topic, lesson.id));
+    inner.start_trace();
+    let result = panic::catch_unwind(AssertUnwindSafe(|| (lesson.run)(&mut inner)));
+    let trace = inner.finish_trace();
+    let kept = inner.finish_workspace();
+
+    let file = lesson.file();
+    let mut outputs = vec![String::new(); sections.len()];
+    let mut current = 0;
+    for (location, text) in trace {
+        // Windows 上 Location::file() 用反斜杠分隔
+        if location.file().replace('\\', "/") == file {
+            let line = location.line() as usize - 1;
+            if let Some(index) = sections.iter().position(|section| section.owns.contains(&line)) {
+                current = index;
+            }
+        }
+        outputs[current].push_str(&text);
+    }
+    if let (Some(kept), Some(last)) = (kept, outputs.last_mut()) {
+        last.push_str(&ctx.message("runner.workspace_kept", &[&kept.display()]));
+        last.push('\n');
+    }
+
+    let failure = match result {
+        Ok(Ok(())) => None,
+        Ok(Err(e)) => Some(ctx.message("runner.lesson_failed", &[&e])),
+        Err(payload) => {
+            let message = runner::panic_message(payload.as_ref())
+                .unwrap_or_else(|| ctx.text("runner.unknown_panic").to_string());
+            Some(ctx.message("runner.lesson_panicked", &[&message]))
+        }
+    };
+    (outputs, failure)
+}
+
+const RESET: &str = "\x1b[0m";
+const KEYWORD: &str = "\x1b[35m";
+const STRING: &str = "\x1b[32m";
+const COMMENT: &str = "\x1b[90m";
+const NUMBER: &str = "\x1b[33m";
+const MACRO: &str = "\x1b[34m";
+const TYPE: &str = "\x1b[36m";
+
+const KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern", "false",
+    "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref", "return",
+    "self", "Self", "static", "struct", "super", "trait", "true", "type", "unsafe", "use", "where", "while",
+];
+
+// 逐行的语法高亮，只区分关键字、字符串、注释、数字、宏和类型名。
+// 跨行的字符串和块注释要记住上一行结束时的状态，所以同一个文件要用同一个 Highlighter
+#[derive(Debug, Default)]
+pub struct Highlighter {
+    state: State,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+enum State {
+    #[default]
+    Code,
+    // 普通字符串，或者带有若干个 # 的原始字符串
+    Str,
+    RawStr(usize),
+    BlockComment,
+}
+
+impl Highlighter {
+    // 返回加上 ANSI 颜色的一行
+    pub fn line(&mut self, line: &str) -> String {
+        let chars: Vec<char> = line.chars().collect();
+        let mut out = String::new();
+        let mut i = 0;
+        while i < chars.len() {
+            let (end, color) = match self.state {
+                State::Str => (self.close(string_end(&chars, i, None)), STRING),
+                State::RawStr(hashes) => (self.close(string_end(&chars, i, Some(hashes))), STRING),
+                State::BlockComment => (self.close(comment_end(&chars, i)), COMMENT),
+                State::Code => match self.token(&chars, i) {
+                    Some(token) => token,
+                    None => {
+                        out.push(chars[i]);
+                        i += 1;
+                        continue;
+                    }
+                },
+            };
+            let end = end.unwrap_or(chars.len());
+            paint(&mut out, color, &chars[i..end]);
+            i = end;
+        }
+        out
+    }
+
+    // 字符串或注释在本行结束时回到代码状态；没有结束时保持状态，本行剩下的部分同色
+    fn close(&mut self, end: Option<usize>) -> Option<usize> {
+        if end.is_some() {
+            self.state = State::Code;
+        }
+        end
+    }
+
+    // 从 i 开始的一个需要着色的记号：返回结束位置和颜色；普通字符返回 None
+    fn token(&mut self, chars: &[char], i: usize) -> Option<(Option<usize>, &'static str)> {
+        let at = |k: usize| chars.get(k).copied();
+        let c = chars[i];
+
+        if c == '/' && at(i + 1) == Some('/') {
+            return Some((None, COMMENT));
+        }
+        if c == '/' && at(i + 1) == Some('*') {
+            self.state = State::BlockComment;
+            return Some((self.close(comment_end(chars, i + 2)), COMMENT));
+        }
+        if c == '"' {
+            self.state = State::Str;
+            return Some((self.close(string_end(chars, i + 1, None)), STRING));
+        }
+        if c == 'r' && !is_ident(at(i.wrapping_sub(1))) {
+            let hashes = chars[i + 1..].iter().take_while(|c| **c == '#').count();
+            if at(i + 1 + hashes) == Some('"') {
+                self.state = State::RawStr(hashes);
+                return Some((self.close(string_end(chars, i + 2 + hashes, Some(hashes))), STRING));
+            }
+        }
+        if c == '\'' {
+            // 字符字面量 'a'、'\n'，否则是生命周期 'a
+            if at(i + 1) == Some('\\') {
+                let end = chars[i + 2..].iter().position(|c| *c == '\'').map(|k| i + 2 + k + 1);
+                return Some((end, STRING));
+            }
+            if at(i + 2) == Some('\'') {
+                return Some((Some(i + 3), STRING));
+            }
+            let end = i + 1 + chars[i + 1..].iter().take_while(|c| is_ident(Some(**c))).count();
+            return Some((Some(end), NUMBER));
+        }
+        if c.is_ascii_digit() {
+            let mut end = i;
+            while end < chars.len()
+                && (is_ident(at(end)) || (chars[end] == '.' && at(end + 1).is_some_and(|c| c.is_ascii_digit())))
+            {
+                end += 1;
+            }
+            return Some((Some(end), NUMBER));
+        }
+        if c.is_alphabetic() || c == '_' {
+            let end = i + chars[i..].iter().take_while(|c| is_ident(Some(**c))).count();
+            let word: String = chars[i..end].iter().collect();
+            if at(end) == Some('!') && at(end + 1) != Some('=') {
+                return Some((Some(end + 1), MACRO));
+            }
+            if KEYWORDS.contains(&word.as_str()) {
+                return Some((Some(end), KEYWORD));
+            }
+            if c.is_uppercase() {
+                return Some((Some(end), TYPE));
+            }
+            // 普通标识符整个跳过，避免把其中的 r 或数字当成别的记号
+            return Some((Some(end), ""));
+        }
+        None
+    }
+}
+
+fn is_ident(c: Option<char>) -> bool {
+    c.is_some_and(|c| c.is_alphanumeric() || c == '_')
+}
+
+// 从 from 开始找字符串的结束引号，返回引号之后的位置；原始字符串没有转义，以 "# 结束
+fn string_end(chars: &[char], from: usize, raw: Option<usize>) -> Option<usize> {
+    let mut i = from;
+    while i < chars.len() {
+        match (chars[i], raw) {
+            ('\\', None) => i += 2,
+            ('"', None) => return Some(i + 1),
+            ('"', Some(hashes)) if chars[i + 1..].iter().take(hashes).filter(|c| **c == '#').count() == hashes => {
+                return Some(i + 1 + hashes);
+            }
+            _ => i += 1,
+        }
+    }
+    None
+}
+
+fn comment_end(chars: &[char], from: usize) -> Option<usize> {
+    (from..chars.len().saturating_sub(1))
+        .find(|&i| chars[i] == '*' && chars[i + 1] == '/')
+        .map(|i| i + 2)
+}
+
+fn paint(out: &mut String, color: &str, text: &[char]) {
+    if color.is_empty() {
+        out.extend(text);
+    } else {
+        out.push_str(color);
+        out.extend(text);
+        out.push_str(RESET);
+    }
+}
@@ -3,81 +3,87 @@
 //! 枚举允许你定义一个类型，该类型可以是多个变体之一。
 //! Rust 的枚举非常强大，每个变体可以关联不同类型和数量的数据。
 
-pub fn main() {
-    println!("=== 枚举基础 ===");
+use std::io::{self, Write};
 
-    // 1. 基本枚举
+use crate::context::Ctx;
+
+pub fn main(ctx: &mut Ctx) -> io::Result<()> {
+    writeln!(ctx, "=== {} ===", ctx.text("lesson.enums"))?;
+
+    ctx.section(1, "基本枚举", "Basic enums")?;
     let four = IpAddrKind::V4;
     let six = IpAddrKind::V6;
-    println!("IP 类型: {:?} 和 {:?}", four, six);
+    writeln!(ctx, "IP 类型: {:?} 和 {:?}", four, six)?;
 
-    // 2. 将数据与枚举变体关联
+    ctx.section(2, "将数据与枚举变体关联", "Attaching data to enum variants")?;
     let home = IpAddr::V4(String::from("127.0.0.1"));
     let loopback = IpAddr::V6(String::from("::1"));
-    println!("IP 地址: {:?} 和 {:?}", home, loopback);
+    writeln!(ctx, "IP 地址: {:?} 和 {:?}", home, loopback)?;
 
-    // 3. 枚举变体可以关联不同类型的数据
+    ctx.section(3, "枚举变体可以关联不同类型的数据", "Enum variants can hold different kinds of data")?;
     let msg1 = Message::Quit;
     let msg2 = Message::Move { x: 10, y: 20 };
     let msg3 = Message::Write(String::from("hello"));
     let msg4 = Message::ChangeColor(255, 0, 0);
 
-    // 4. 枚举方法
-    msg1.call();
-    msg2.call();
+    ctx.section(4, "枚举方法", "Enum methods")?;
+    msg1.call(ctx)?;
+    msg2.call(ctx)?;
 
-    // 5. Option 枚举（Rust 没有 null）
+    ctx.section(5, "Option 枚举（Rust 没有 null）", "The Option enum (Rust has no null)")?;
     let some_number = Some(5);
     let some_string = Some("a string");
     let absent_number: Option<i32> = None;
 
-    println!("Option 值: {:?}, {:?}, {:?}", some_number, some_string, absent_number);
+    writeln!(ctx, "Option 值: {:?}, {:?}, {:?}", some_number, some_string, absent_number)?;
 
-    // 6. 使用 match 处理 Option
+    ctx.section(6, "使用 match 处理 Option", "Handling Option with match")?;
     let x: Option<i32> = Some(5);
     match x {
-        Some(i) => println!("有值: {}", i),
-        None => println!("没有值"),
+        Some(i) => writeln!(ctx, "有值: {}", i)?,
+        None => writeln!(ctx, "没有值")?,
     }
 
-    // 7. Result 枚举（用于错误处理）
+    ctx.section(7, "Result 枚举（用于错误处理）", "The Result enum (for error handling)")?;
     let result: Result<i32, String> = Ok(42);
     let error: Result<i32, String> = Err(String::from("出错了"));
 
     match result {
-        Ok(value) => println!("成功: {}", value),
-        Err(e) => println!("错误: {}", e),
+        Ok(value) => writeln!(ctx, "成功: {}", value)?,
+        Err(e) => writeln!(ctx, "错误: {}", e)?,
     }
 
-    // 8. 复杂枚举
+    ctx.section(8, "复杂枚举", "Complex enums")?;
     let shape = Shape::Circle(Point { x: 0.0, y: 0.0 }, 10.0);
     let area = shape.area();
-    println!("形状面积: {}", area);
+    writeln!(ctx, "形状面积: {}", area)?;
 
-    // 9. 枚举中的模式匹配
+    ctx.section(9, "枚举中的模式匹配", "Pattern matching on enums")?;
     let coin = Coin::Quarter(UsState::Alabama);
-    let value = value_in_cents(&coin);
-    println!("硬币价值: {} 美分", value);
+    let value = value_in_cents(ctx, &coin)?;
+    writeln!(ctx, "硬币价值: {} 美分", value)?;
 
-    // 10. if let 语法糖
+    ctx.section(10, "if let 语法糖", "if let shorthand")?;
     let config_max = Some(3u8);
     if let Some(max) = config_max {
-        println!("最大值是: {}", max);
+        writeln!(ctx, "最大值是: {}", max)?;
     }
 
-    // 11. while let 循环
+    ctx.section(11, "while let 循环", "while let loops")?;
     let mut stack = Vec::new();
     stack.push(1);
     stack.push(2);
     stack.push(3);
 
     while let Some(top) = stack.pop() {
-        println!("弹出: {}", top);
+        writeln!(ctx, "弹出: {}", top)?;
     }
 
-    // 12. 枚举作为函数参数
-    route(IpAddrKind::V4);
-    route(IpAddrKind::V6);
+    ctx.section(12, "枚举作为函数参数", "Enums as function parameters")?;
+    route(ctx, IpAddrKind::V4)?;
+    route(ctx, IpAddrKind::V6)?;
+
+    Ok(())
 }
 
 // 基本枚举
@@ -104,13 +110,13 @@ enum Message {
 
 // 为枚举实现方法
 impl Message {
-    fn call(&self) {
+    fn call(&self, ctx: &mut Ctx) -> io::Result<()> {
         // 方法实现
         match self {
-            Message::Quit => println!("退出消息"),
-            Message::Move { x, y } => println!("移动到 ({}, {})", x, y),
-            Message::Write(text) => println!("写入: {}", text),
-            Message::ChangeColor(r, g, b) => println!("改变颜色到 RGB({}, {}, {})", r, g, b),
+            Message::Quit => writeln!(ctx, "退出消息"),
+            Message::Move { x, y } => writeln!(ctx, "移动到 ({}, {})", x, y),
+            Message::Write(text) => writeln!(ctx, "写入: {}", text),
+            Message::ChangeColor(r, g, b) => writeln!(ctx, "改变颜色到 RGB({}, {}, {})", r, g, b),
         }
     }
 }
@@ -167,26 +173,27 @@ enum Coin {
     Quarter(UsState),
 }
 
-fn value_in_cents(coin: &Coin) -> u8 {
-    match coin {
+fn value_in_cents(ctx: &mut Ctx, coin: &Coin) -> io::Result<u8> {
+    let cents = match coin {
         Coin::Penny => {
-            println!("幸运硬币!");
+            writeln!(ctx, "幸运硬币!")?;
             1
         }
         Coin::Nickel => 5,
         Coin::Dime => 10,
         Coin::Quarter(state) => {
-            println!("来自 {:?} 州的 25 美分硬币", state);
+            writeln!(ctx, "来自 {:?} 州的 25 美分硬币", state)?;
             25
         }
-    }
+    };
+    Ok(cents)
 }
 
 // 枚举作为函数参数
-fn route(ip_kind: IpAddrKind) {
+fn route(ctx: &mut Ctx, ip_kind: IpAddrKind) -> io::Result<()> {
     match ip_kind {
-        IpAddrKind::V4 => println!("路由 IPv4"),
-        IpAddrKind::V6 => println!("路由 IPv6"),
+        IpAddrKind::V4 => writeln!(ctx, "路由 IPv4"),
+        IpAddrKind::V6 => writeln!(ctx, "路由 IPv6"),
     }
 }
 
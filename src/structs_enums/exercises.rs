@@ -0,0 +1,146 @@
+//! 结构体、枚举和模式匹配练习
+//!
+//! 把下面方法和函数中的 `todo!()` 换成实现，然后运行 `rust_study check <练习>` 检查，
+//! 例如 `rust_study check structs_enums/rectangle`。
+
+use crate::check::{expect_eq, Case, Exercise};
+
+#[derive(Debug)]
+pub struct Rectangle {
+    pub width: u32,
+    pub height: u32,
+}
+
+// 练习 1：实现矩形的方法（参考 structs 课）
+impl Rectangle {
+    // 面积
+    pub fn area(&self) -> u32 {
+        todo!()
+    }
+
+    // self 能否完全容纳 other（宽和高都严格更大）
+    pub fn can_hold(&self, other: &Rectangle) -> bool {
+        todo!()
+    }
+
+    // 关联函数：边长为 size 的正方形
+    pub fn square(size: u32) -> Rectangle {
+        todo!()
+    }
+}
+
+#[derive(Debug)]
+pub enum Coin {
+    Penny,
+    Nickel,
+    Dime,
+    Quarter,
+}
+
+// 练习 2：用 match 返回硬币的面值（美分）（参考 pattern_matching 课）
+pub fn value_in_cents(coin: Coin) -> u8 {
+    todo!()
+}
+
+// 练习 3：Some(x) 返回 Some(x 的两倍)，None 返回 None（参考 pattern_matching 课中的 plus_one）
+pub fn double(x: Option<i32>) -> Option<i32> {
+    todo!()
+}
+
+pub static EXERCISES: &[Exercise] = &[
+    Exercise {
+        id: "rectangle",
+        topic: "structs_enums",
+        lesson: "structs",
+        title: "矩形的方法",
+        title_en: "Rectangle methods",
+        cases: &[
+            Case {
+                name: "面积",
+                name_en: "Area",
+                hint: "方法通过 self.width 和 self.height 访问字段",
+                hint_en: "Methods reach the fields through self.width and self.height",
+                run: || expect_eq(Rectangle { width: 30, height: 50 }.area(), 1500),
+            },
+            Case {
+                name: "能容纳更小的矩形",
+                name_en: "Holds a smaller rectangle",
+                hint: "宽和高都要比较：self.width > other.width && self.height > other.height",
+                hint_en: "Compare both sides: self.width > other.width && self.height > other.height",
+                run: || {
+                    let big = Rectangle { width: 30, height: 50 };
+                    expect_eq(big.can_hold(&Rectangle { width: 10, height: 40 }), true)
+                },
+            },
+            Case {
+                name: "不能容纳更宽的矩形",
+                name_en: "Can't hold a wider rectangle",
+                hint: "只要有一边不够大就不能容纳",
+                hint_en: "If either side is too small, it can't hold the other",
+                run: || {
+                    let big = Rectangle { width: 30, height: 50 };
+                    expect_eq(big.can_hold(&Rectangle { width: 60, height: 45 }), false)
+                },
+            },
+            Case {
+                name: "正方形",
+                name_en: "Square",
+                hint: "关联函数没有 self 参数，返回 Rectangle { width: size, height: size }",
+                hint_en: "An associated function has no self parameter; return Rectangle { width: size, height: size }",
+                run: || expect_eq(Rectangle::square(4).area(), 16),
+            },
+        ],
+    },
+    Exercise {
+        id: "coins",
+        topic: "structs_enums",
+        lesson: "pattern_matching",
+        title: "硬币面值",
+        title_en: "Coin values",
+        cases: &[
+            Case {
+                name: "一美分和五美分",
+                name_en: "Penny and nickel",
+                hint: "match coin { Coin::Penny => 1, ... }，每个分支都要覆盖",
+                hint_en: "match coin { Coin::Penny => 1, ... }, covering every arm",
+                run: || {
+                    expect_eq(value_in_cents(Coin::Penny), 1)?;
+                    expect_eq(value_in_cents(Coin::Nickel), 5)
+                },
+            },
+            Case {
+                name: "十美分和二十五美分",
+                name_en: "Dime and quarter",
+                hint: "Dime 是 10 美分，Quarter 是 25 美分",
+                hint_en: "A Dime is 10 cents, a Quarter is 25 cents",
+                run: || {
+                    expect_eq(value_in_cents(Coin::Dime), 10)?;
+                    expect_eq(value_in_cents(Coin::Quarter), 25)
+                },
+            },
+        ],
+    },
+    Exercise {
+        id: "double",
+        topic: "structs_enums",
+        lesson: "pattern_matching",
+        title: "匹配 Option",
+        title_en: "Matching Option",
+        cases: &[
+            Case {
+                name: "Some",
+                name_en: "Some",
+                hint: "在 Some(i) 分支中返回 Some(i * 2)",
+                hint_en: "Return Some(i * 2) in the Some(i) arm",
+                run: || expect_eq(double(Some(21)), Some(42)),
+            },
+            Case {
+                name: "None",
+                name_en: "None",
+                hint: "match 必须穷尽所有情况，None 分支返回 None",
+                hint_en: "match must be exhaustive; the None arm returns None",
+                run: || expect_eq(double(None), None),
+            },
+        ],
+    },
+];
@@ -6,14 +6,5 @@
 pub mod structs;
 pub mod enums;
 pub mod pattern_matching;
-
-pub fn run_all() {
-    println!("\n--- 结构体示例 ---");
-    structs::main();
-
-    println!("\n--- 枚举示例 ---");
-    enums::main();
-
-    println!("\n--- 模式匹配示例 ---");
-    pattern_matching::main();
-}
\ No newline at end of file
+pub mod exercises;
+pub mod quiz;
@@ -3,19 +3,23 @@
 //! Rust 的模式匹配非常强大，可以用于解构各种数据类型。
 //! match 表达式是 Rust 中最强大的控制流运算符之一。
 
-pub fn main() {
-    println!("=== 模式匹配基础 ===");
+use std::io::{self, Write};
 
-    // 1. 基本 match 表达式
+use crate::context::Ctx;
+
+pub fn main(ctx: &mut Ctx) -> io::Result<()> {
+    writeln!(ctx, "=== {} ===", ctx.text("lesson.pattern_matching"))?;
+
+    ctx.section(1, "基本 match 表达式", "Basic match expressions")?;
     let number = 13;
     match number {
-        1 => println!("一"),
-        2 | 3 | 5 | 7 | 11 => println!("质数"),
-        13..=19 => println!("十几"),
-        _ => println!("其他数字"),
+        1 => writeln!(ctx, "一")?,
+        2 | 3 | 5 | 7 | 11 => writeln!(ctx, "质数")?,
+        13..=19 => writeln!(ctx, "十几")?,
+        _ => writeln!(ctx, "其他数字")?,
     }
 
-    // 2. match 返回值
+    ctx.section(2, "match 返回值", "match returns a value")?;
     let number = 5;
     let description = match number {
         1 => "一",
@@ -23,53 +27,53 @@ pub fn main() {
         3 => "三",
         _ => "其他",
     };
-    println!("数字 {} 是 {}", number, description);
+    writeln!(ctx, "数字 {} 是 {}", number, description)?;
 
-    // 3. 匹配枚举
+    ctx.section(3, "匹配枚举", "Matching enums")?;
     let coin = Coin::Quarter(UsState::California);
-    let value = value_in_cents(&coin);
-    println!("硬币价值: {} 美分", value);
+    let value = value_in_cents(ctx, &coin)?;
+    writeln!(ctx, "硬币价值: {} 美分", value)?;
 
-    // 4. 匹配 Option
+    ctx.section(4, "匹配 Option", "Matching Option")?;
     let five = Some(5);
     let six = plus_one(five);
     let none = plus_one(None);
-    println!("加一: {:?}, {:?}", six, none);
+    writeln!(ctx, "加一: {:?}, {:?}", six, none)?;
 
-    // 5. 通配模式和 _ 占位符
+    ctx.section(5, "通配模式和 _ 占位符", "Wildcard patterns and the _ placeholder")?;
     let dice_roll = 9;
     match dice_roll {
-        3 => println!("前进 3 步"),
-        7 => println!("后退 7 步"),
-        other => println!("前进 {} 步", other), // 捕获所有其他值
+        3 => writeln!(ctx, "前进 3 步")?,
+        7 => writeln!(ctx, "后退 7 步")?,
+        other => writeln!(ctx, "前进 {} 步", other)?, // 捕获所有其他值
     }
 
-    // 6. 匹配范围模式
+    ctx.section(6, "匹配范围模式", "Matching range patterns")?;
     let x = 5;
     match x {
-        1..=5 => println!("一到五"),
-        6..=10 => println!("六到十"),
-        _ => println!("其他"),
+        1..=5 => writeln!(ctx, "一到五")?,
+        6..=10 => writeln!(ctx, "六到十")?,
+        _ => writeln!(ctx, "其他")?,
     }
 
-    // 7. 解构结构体
+    ctx.section(7, "解构结构体", "Destructuring structs")?;
     let point = Point { x: 0, y: 7 };
     match point {
-        Point { x: 0, y } => println!("在 y 轴上，y = {}", y),
-        Point { x, y: 0 } => println!("在 x 轴上，x = {}", x),
-        Point { x, y } => println!("在 ({}, {})", x, y),
+        Point { x: 0, y } => writeln!(ctx, "在 y 轴上，y = {}", y)?,
+        Point { x, y: 0 } => writeln!(ctx, "在 x 轴上，x = {}", x)?,
+        Point { x, y } => writeln!(ctx, "在 ({}, {})", x, y)?,
     }
 
-    // 8. 解构枚举
+    ctx.section(8, "解构枚举", "Destructuring enums")?;
     let msg = Message::ChangeColor(255, 0, 0);
     match msg {
-        Message::Quit => println!("退出"),
-        Message::Move { x, y } => println!("移动到 ({}, {})", x, y),
-        Message::Write(text) => println!("文本消息: {}", text),
-        Message::ChangeColor(r, g, b) => println!("颜色: RGB({}, {}, {})", r, g, b),
+        Message::Quit => writeln!(ctx, "退出")?,
+        Message::Move { x, y } => writeln!(ctx, "移动到 ({}, {})", x, y)?,
+        Message::Write(text) => writeln!(ctx, "文本消息: {}", text)?,
+        Message::ChangeColor(r, g, b) => writeln!(ctx, "颜色: RGB({}, {}, {})", r, g, b)?,
     }
 
-    // 9. 解构嵌套结构
+    ctx.section(9, "解构嵌套结构", "Destructuring nested structures")?;
     // let shape = Shape::Circle(Point { x: 0.0, y: 0.0 }, 10.0); // 类型不匹配，注释掉
     // match shape {
     //     Shape::Circle(center, radius) => println!("圆心: ({}, {}), 半径: {}", center.x, center.y, radius),
@@ -77,99 +81,102 @@ pub fn main() {
     //     Shape::Triangle(p1, p2, p3) => println!("三角形: 三个点"),
     // }
 
-    // 10. 匹配守卫（match guards）
+    ctx.section(10, "匹配守卫（match guards）", "Match guards")?;
     let num = Some(4);
     match num {
-        Some(x) if x < 5 => println!("小于 5: {}", x),
-        Some(x) => println!("{}", x),
+        Some(x) if x < 5 => writeln!(ctx, "小于 5: {}", x)?,
+        Some(x) => writeln!(ctx, "{}", x)?,
         None => (),
     }
 
-    // 11. @ 绑定
+    ctx.section(11, "@ 绑定", "@ bindings")?;
     let msg = MessageWithId::Hello { id: 5 };
     match msg {
         MessageWithId::Hello { id: id_variable @ 3..=7 } => {
-            println!("在范围 3-7 内找到 id: {}", id_variable)
+            writeln!(ctx, "在范围 3-7 内找到 id: {}", id_variable)?
         }
         MessageWithId::Hello { id: 10..=12 } => {
-            println!("在另一个范围 10-12 内找到 id")
+            writeln!(ctx, "在另一个范围 10-12 内找到 id")?
         }
-        MessageWithId::Hello { id } => println!("找到其他 id: {}", id),
+        MessageWithId::Hello { id } => writeln!(ctx, "找到其他 id: {}", id)?,
     }
 
-    // 12. if let 语法糖
+    ctx.section(12, "if let 语法糖", "if let shorthand")?;
     let favorite_color: Option<&str> = None;
     let is_tuesday = false;
     let age: Result<u8, _> = "34".parse();
 
     if let Some(color) = favorite_color {
-        println!("使用最喜欢的颜色: {}", color);
+        writeln!(ctx, "使用最喜欢的颜色: {}", color)?;
     } else if is_tuesday {
-        println!("星期二是绿色日!");
+        writeln!(ctx, "星期二是绿色日!")?;
     } else if let Ok(age) = age {
         if age > 30 {
-            println!("使用紫色");
+            writeln!(ctx, "使用紫色")?;
         } else {
-            println!("使用橙色");
+            writeln!(ctx, "使用橙色")?;
         }
     } else {
-        println!("使用蓝色");
+        writeln!(ctx, "使用蓝色")?;
     }
 
-    // 13. while let 循环
+    ctx.section(13, "while let 循环", "while let loops")?;
     let mut stack = Vec::new();
     stack.push(1);
     stack.push(2);
     stack.push(3);
 
     while let Some(top) = stack.pop() {
-        println!("{}", top);
+        writeln!(ctx, "{}", top)?;
     }
 
-    // 14. for 循环中的模式匹配
+    ctx.section(14, "for 循环中的模式匹配", "Patterns in for loops")?;
     let v = vec!['a', 'b', 'c'];
     for (index, value) in v.iter().enumerate() {
-        println!("{} 在索引 {}", value, index);
+        writeln!(ctx, "{} 在索引 {}", value, index)?;
     }
 
-    // 15. let 语句中的模式匹配
+    ctx.section(15, "let 语句中的模式匹配", "Patterns in let statements")?;
     let (x, y, z) = (1, 2, 3);
-    println!("x={}, y={}, z={}", x, y, z);
+    writeln!(ctx, "x={}, y={}, z={}", x, y, z)?;
 
-    // 16. 函数参数中的模式匹配
+    ctx.section(16, "函数参数中的模式匹配", "Patterns in function parameters")?;
     let point = (3, 5);
-    print_coordinates(&point);
+    print_coordinates(ctx, &point)?;
+
+    Ok(())
 }
 
 // 枚举定义
 #[derive(Debug)]
-enum UsState {
+pub enum UsState {
     Alabama,
     Alaska,
     California,
     // ...
 }
 
-enum Coin {
+pub enum Coin {
     Penny,
     Nickel,
     Dime,
     Quarter(UsState),
 }
 
-fn value_in_cents(coin: &Coin) -> u8 {
-    match coin {
+pub fn value_in_cents(ctx: &mut Ctx, coin: &Coin) -> io::Result<u8> {
+    let cents = match coin {
         Coin::Penny => 1,
         Coin::Nickel => 5,
         Coin::Dime => 10,
         Coin::Quarter(state) => {
-            println!("来自 {:?} 州的硬币", state);
+            writeln!(ctx, "来自 {:?} 州的硬币", state)?;
             25
         }
-    }
+    };
+    Ok(cents)
 }
 
-fn plus_one(x: Option<i32>) -> Option<i32> {
+pub fn plus_one(x: Option<i32>) -> Option<i32> {
     match x {
         None => None,
         Some(i) => Some(i + 1),
@@ -199,43 +206,47 @@ enum MessageWithId {
     Hello { id: i32 },
 }
 
-fn print_coordinates(&(x, y): &(i32, i32)) {
-    println!("当前位置: ({}, {})", x, y);
+fn print_coordinates(ctx: &mut Ctx, &(x, y): &(i32, i32)) -> io::Result<()> {
+    writeln!(ctx, "当前位置: ({}, {})", x, y)
 }
 
 // 忽略值的模式
-fn ignore_patterns() {
+fn ignore_patterns(ctx: &mut Ctx) -> io::Result<()> {
     let numbers = (2, 4, 8, 16, 32);
 
     // 忽略部分值
     match numbers {
         (first, _, third, _, fifth) => {
-            println!("一些数字: {}, {}, {}", first, third, fifth)
+            writeln!(ctx, "一些数字: {}, {}, {}", first, third, fifth)?
         }
     }
 
     // 忽略剩余值
     match numbers {
         (first, ..) => {
-            println!("第一个数字是: {}", first)
+            writeln!(ctx, "第一个数字是: {}", first)?
         }
     }
 
     // 忽略单个值
     let _x = 5; // 使用下划线忽略未使用的变量
     let y = 10; // 会产生警告
+
+    Ok(())
 }
 
 // 匹配引用和解引用
-fn match_references() {
+fn match_references(ctx: &mut Ctx) -> io::Result<()> {
     let reference = &4;
 
     match reference {
-        &val => println!("通过解引用得到: {}", val),
+        &val => writeln!(ctx, "通过解引用得到: {}", val)?,
     }
 
     // 更好的方式：使用 ref 模式
     match *reference {
-        val => println!("直接解引用: {}", val),
+        val => writeln!(ctx, "直接解引用: {}", val)?,
     }
+
+    Ok(())
 }
\ No newline at end of file
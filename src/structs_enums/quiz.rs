@@ -0,0 +1,70 @@
+//! 结构体、枚举和模式匹配小测验
+//!
+//! 预测输出题调用 structs 和 pattern_matching 课中的函数，答案以实际运行结果为准。
+
+use std::io::{self, Write};
+
+use crate::context::Ctx;
+use crate::quiz::{Answer, Question};
+
+use super::pattern_matching::{plus_one, value_in_cents, Coin, UsState};
+use super::structs::Rectangle;
+
+pub static QUESTIONS: &[Question] = &[
+    Question {
+        topic: "structs_enums",
+        lesson: "pattern_matching",
+        prompt: "下面的代码输出什么？",
+        prompt_en: "What does this code print?",
+        code: "let cents = value_in_cents(&Coin::Quarter(UsState::Alaska));\nprintln!(\"{}\", cents);",
+        options: &[
+            "25",
+            "来自 Alaska 州的硬币\n25",
+            "来自 Quarter 州的硬币\n25",
+            "Alaska\n25",
+        ],
+        options_en: &[
+            "25",
+            "来自 Alaska 州的硬币\n25",
+            "来自 Quarter 州的硬币\n25",
+            "Alaska\n25",
+        ],
+        answer: Answer::Output(alaska_quarter),
+        explanation: "Coin::Quarter(state) 分支绑定了内部的 UsState，先用 {:?} 打印州名，再返回 25。",
+        explanation_en: "The Coin::Quarter(state) arm binds the inner UsState, prints the state with {:?} and then returns 25.",
+    },
+    Question {
+        topic: "structs_enums",
+        lesson: "pattern_matching",
+        prompt: "下面的代码输出什么？",
+        prompt_en: "What does this code print?",
+        code: "println!(\"{:?} {:?}\", plus_one(Some(5)), plus_one(None));",
+        options: &["6 None", "Some(6) None", "Some(6) Some(1)", "Some(6) 0"],
+        options_en: &["6 None", "Some(6) None", "Some(6) Some(1)", "Some(6) 0"],
+        answer: Answer::Output(|ctx| writeln!(ctx, "{:?} {:?}", plus_one(Some(5)), plus_one(None))),
+        explanation: "match 对 None 原样返回 None，对 Some(i) 返回 Some(i + 1)；值始终包在 Option 里。",
+        explanation_en: "The match returns None unchanged for None and Some(i + 1) for Some(i); the value always stays wrapped in an Option.",
+    },
+    Question {
+        topic: "structs_enums",
+        lesson: "structs",
+        prompt: "下面的代码输出什么？",
+        prompt_en: "What does this code print?",
+        code: "let rect = Rectangle { width: 30, height: 50 };\nprintln!(\"{}\", rect.can_hold(&Rectangle::square(40)));",
+        options: &["true", "false"],
+        options_en: &["true", "false"],
+        answer: Answer::Output(can_hold_square),
+        explanation: "can_hold 要求宽和高都严格更大：高 50 > 40，但宽 30 < 40。",
+        explanation_en: "can_hold requires both the width and the height to be strictly larger: the height 50 > 40, but the width 30 < 40.",
+    },
+];
+
+fn alaska_quarter(ctx: &mut Ctx) -> io::Result<()> {
+    let cents = value_in_cents(ctx, &Coin::Quarter(UsState::Alaska))?;
+    writeln!(ctx, "{}", cents)
+}
+
+fn can_hold_square(ctx: &mut Ctx) -> io::Result<()> {
+    let rect = Rectangle { width: 30, height: 50 };
+    writeln!(ctx, "{}", rect.can_hold(&Rectangle::square(40)))
+}
@@ -0,0 +1,76 @@
+//! 临时工作目录
+//!
+//! 会读写文件的课程不碰当前目录，而是在运行器为每次运行准备的临时目录里操作。
+//! 每个 `Ctx` 有一个目录 "rust_study-<进程号>-<序号>"，每课在其中有自己的子目录。
+//! 同一进程中可以同时有多个 `Ctx`（show、export、并行的测试），序号保证它们互不干扰。
+//! 目录在课程第一次用到时才创建，课程结束后删除子目录，运行结束后删除整个目录；
+//! 需要查看课程留下的文件时可以全部保留。已经存在的目录不会被清空或删除。
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// 本进程中下一个运行目录的序号
+static NEXT_RUN: AtomicUsize = AtomicUsize::new(1);
+
+pub struct Workspace {
+    path: PathBuf,
+    keep: bool,
+}
+
+impl Workspace {
+    // 在 root 下创建本次运行的目录 "rust_study-<进程号>-<序号>"；keep 为 true 时 drop 后也不删除。
+    // 进程号被复用时可能撞上旧进程保留的目录，这时跳过它换下一个序号
+    pub fn for_run(root: &Path, keep: bool) -> io::Result<Workspace> {
+        fs::create_dir_all(root)?;
+        loop {
+            let n = NEXT_RUN.fetch_add(1, Ordering::Relaxed);
+            let path = root.join(format!("rust_study-{}-{}", process::id(), n));
+            if let Some(workspace) = Workspace::create(path, keep)? {
+                return Ok(workspace);
+            }
+        }
+    }
+
+    // 在运行目录下为一课创建子目录，是否保留与运行目录相同。
+    // 同一课在一次运行中出现多次时，后面的子目录加上 "-2"、"-3" 等后缀
+    pub fn for_lesson(&self, name: &str) -> io::Result<Workspace> {
+        if let Some(workspace) = Workspace::create(self.path.join(name), self.keep)? {
+            return Ok(workspace);
+        }
+        for n in 2.. {
+            if let Some(workspace) = Workspace::create(self.path.join(format!("{}-{}", name, n)), self.keep)? {
+                return Ok(workspace);
+            }
+        }
+        unreachable!()
+    }
+
+    // 只创建新目录：目录已经存在时返回 None，不碰别人的文件
+    fn create(path: PathBuf, keep: bool) -> io::Result<Option<Workspace>> {
+        match fs::create_dir(&path) {
+            Ok(()) => Ok(Some(Workspace { path, keep })),
+            Err(e) if e.kind() == io::ErrorKind::AlreadyExists => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn is_kept(&self) -> bool {
+        self.keep
+    }
+}
+
+// 只有 create 能构造 Workspace，drop 时删除的总是自己创建的目录
+impl Drop for Workspace {
+    fn drop(&mut self) {
+        if !self.keep {
+            let _ = fs::remove_dir_all(&self.path);
+        }
+    }
+}
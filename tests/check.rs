@@ -0,0 +1,164 @@
+//! 练习检查测试
+//!
+//! 用测试中定义的练习检查逐项报告，再检查各主题的练习是否都登记完整。
+
+use std::collections::HashSet;
+
+use rust_study::check::{self, expect_eq, Case, Exercise, Mismatch, Outcome};
+use rust_study::context::Ctx;
+use rust_study::i18n::Locale;
+use rust_study::registry::{self, ResolveError, Target};
+
+fn unfinished() -> i32 {
+    todo!()
+}
+
+static MIXED: Exercise = Exercise {
+    id: "mixed",
+    topic: "basics",
+    lesson: "functions",
+    title: "混合结果",
+    title_en: "Mixed results",
+    cases: &[
+        Case {
+            name: "相等",
+            name_en: "Equal",
+            hint: "不会显示",
+            hint_en: "not shown",
+            run: || expect_eq(1 + 1, 2),
+        },
+        Case {
+            name: "不相等",
+            name_en: "Not equal",
+            hint: "检查加法",
+            hint_en: "check the addition",
+            run: || expect_eq(2 + 2, 5),
+        },
+        Case {
+            name: "还没写",
+            name_en: "Not written yet",
+            hint: "把 todo!() 换成实现",
+            hint_en: "replace todo!() with an implementation",
+            run: || expect_eq(unfinished(), 0),
+        },
+        Case {
+            name: "越界",
+            name_en: "Out of bounds",
+            hint: "先检查长度",
+            hint_en: "check the length first",
+            run: || {
+                let numbers: Vec<i32> = Vec::new();
+                expect_eq(numbers[0], 0)
+            },
+        },
+    ],
+};
+
+static DONE: Exercise = Exercise {
+    id: "done",
+    topic: "basics",
+    lesson: "functions",
+    title: "全部通过",
+    title_en: "All passing",
+    cases: &[Case {
+        name: "相等",
+        name_en: "Equal",
+        hint: "",
+        hint_en: "",
+        run: || expect_eq("a", "a"),
+    }],
+};
+
+fn check_in_memory(exercise: &Exercise) -> (bool, String) {
+    check_in(exercise, Locale::ZhCn)
+}
+
+fn check_in(exercise: &Exercise, locale: Locale) -> (bool, String) {
+    let mut out = Vec::new();
+    let mut input: &[u8] = &[];
+    let mut ctx = Ctx::new(&mut out, &mut input).with_locale(locale);
+    let passed = check::check(&mut ctx, exercise).unwrap();
+    (passed, String::from_utf8(out).unwrap())
+}
+
+#[test]
+fn each_case_gets_its_own_outcome() {
+    let outcomes: Vec<Outcome> = MIXED.cases.iter().map(check::run_case).collect();
+
+    assert_eq!(outcomes[0], Outcome::Passed);
+    let mismatch = Mismatch { expected: String::from("5"), actual: String::from("4") };
+    assert_eq!(outcomes[1], Outcome::Failed(mismatch.clone()));
+    assert_eq!(mismatch.message(Locale::ZhCn), "期望 5，实际得到 4");
+    assert_eq!(mismatch.message(Locale::EnUs), "expected 5, got 4");
+    assert_eq!(outcomes[2], Outcome::Todo);
+    assert!(matches!(&outcomes[3], Outcome::Panicked(Some(message)) if message.contains("index out of bounds")));
+}
+
+#[test]
+fn report_lists_every_case_with_hints_for_failures() {
+    let (passed, output) = check_in_memory(&MIXED);
+
+    assert!(!passed);
+    assert!(output.contains("  [通过]   相等\n"));
+    assert!(output.contains("  [失败]   不相等：期望 5，实际得到 4\n           提示：检查加法\n"));
+    assert!(output.contains("  [未完成] 还没写：函数体还是 todo!()\n           提示：把 todo!() 换成实现\n"));
+    assert!(output.contains("  [panic]  越界："));
+    assert!(!output.contains("不会显示"));
+    assert!(output.contains("共 4 项：1 通过，3 未通过\n复习相关课程：rust_study run basics/functions\n"));
+
+    let (passed, output) = check_in_memory(&DONE);
+    assert!(passed);
+    assert!(output.ends_with("共 1 项：1 通过，0 未通过\n"));
+}
+
+// 英文界面下用例名称、失败说明和提示都是英文
+#[test]
+fn report_follows_the_locale() {
+    let (_, output) = check_in(&MIXED, Locale::EnUs);
+
+    assert!(output.contains("\n--- Exercise basics/mixed: Mixed results ---\n"));
+    assert!(output.contains("  [pass]   Equal\n"));
+    assert!(output.contains("  [fail]   Not equal: expected 5, got 4\n           hint: check the addition\n"));
+    assert!(output.contains("           hint: replace todo!() with an implementation\n"));
+    assert!(!output.chars().any(|c| ('\u{4e00}'..='\u{9fff}').contains(&c)), "英文报告中有中文: {}", output);
+}
+
+#[test]
+fn every_exercise_is_registered_completely() {
+    let mut paths = HashSet::new();
+    for exercise in check::exercises() {
+        assert!(paths.insert(exercise.path()), "练习重复: {}", exercise.path());
+        assert!(!exercise.cases.is_empty(), "{} 没有用例", exercise.path());
+        assert!(
+            exercise.cases.iter().all(|case| [case.name, case.name_en, case.hint, case.hint_en].iter().all(|text| !text.is_empty())),
+            "{} 的用例缺少名称或提示",
+            exercise.path()
+        );
+
+        let lesson = format!("{}/{}", exercise.topic, exercise.lesson);
+        assert!(
+            matches!(registry::resolve(&lesson), Ok(Target::Lesson(found)) if found.path() == lesson),
+            "{} 指向不存在的课程 {}",
+            exercise.path(),
+            lesson
+        );
+    }
+
+    // 每个主题都有练习
+    for topic in registry::TOPICS {
+        assert!(check::exercises().any(|exercise| exercise.topic == topic.id), "{} 没有练习", topic.id);
+    }
+}
+
+#[test]
+fn exercises_resolve_by_path_id_or_prefix() {
+    assert_eq!(check::resolve("ownership/first_word").unwrap().path(), "ownership/first_word");
+    assert_eq!(check::resolve("fizzbuzz").unwrap().path(), "control_flow/fizzbuzz");
+    assert_eq!(check::resolve("pig").unwrap().path(), "collections/pig_latin");
+
+    assert!(matches!(
+        check::resolve("fibonaci"),
+        Err(ResolveError::NotFound { suggestions, .. }) if suggestions == ["control_flow/fibonacci"]
+    ));
+    assert!(matches!(check::resolve("basics/s"), Err(ResolveError::Ambiguous { .. })));
+}
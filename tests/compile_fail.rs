@@ -0,0 +1,117 @@
+//! 编译失败示例测试
+//!
+//! 课程中注释掉的错误代码也保存在 tests/compile_fail 下，每个文件开头写明来自哪一课
+//! 和预期的错误码：
+//!
+//! ```text
+//! //! 课程：ownership/references_borrowing
+//! //! 错误：E0106
+//! ```
+//!
+//! 测试用本机的 rustc（或环境变量 RUSTC 指定的编译器）编译每个文件，
+//! 确认它确实编译失败，并且只报出预期的错误码。
+
+use std::collections::BTreeSet;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use rust_study::registry::{self, Target};
+
+struct Case {
+    path: PathBuf,
+    lesson: String,
+    code: String,
+}
+
+fn case_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("compile_fail")
+}
+
+// 读取所有示例，按文件名排序
+fn cases() -> Vec<Case> {
+    let mut paths: Vec<PathBuf> = fs::read_dir(case_dir())
+        .expect("读取 tests/compile_fail 失败")
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "rs"))
+        .collect();
+    paths.sort();
+
+    paths
+        .into_iter()
+        .map(|path| {
+            let source = fs::read_to_string(&path).unwrap();
+            let header = |prefix: &str| {
+                source
+                    .lines()
+                    .find_map(|line| line.strip_prefix(prefix))
+                    .map(|value| value.trim().to_string())
+                    .unwrap_or_else(|| panic!("{} 缺少 {}", path.display(), prefix))
+            };
+            let lesson = header("//! 课程：");
+            let code = header("//! 错误：");
+            Case { path, lesson, code }
+        })
+        .collect()
+}
+
+// 编译一个示例，返回编译器是否成功和报出的错误码
+fn compile(case: &Case, out_dir: &Path) -> (bool, BTreeSet<String>) {
+    let rustc = env::var_os("RUSTC").unwrap_or_else(|| "rustc".into());
+    let output = Command::new(rustc)
+        .args(["--edition", "2024", "--crate-type", "lib", "--emit", "metadata", "-A", "warnings"])
+        .arg("--out-dir")
+        .arg(out_dir)
+        .arg(&case.path)
+        .output()
+        .expect("运行 rustc 失败");
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let codes = stderr
+        .match_indices("error[")
+        .filter_map(|(start, _)| {
+            let rest = &stderr[start + "error[".len()..];
+            rest.split_once(']').map(|(code, _)| code.to_string())
+        })
+        .collect();
+    (output.status.success(), codes)
+}
+
+#[test]
+fn every_case_points_to_a_lesson() {
+    let cases = cases();
+    assert!(!cases.is_empty(), "tests/compile_fail 中没有示例");
+    for case in &cases {
+        assert!(
+            matches!(registry::resolve(&case.lesson), Ok(Target::Lesson(lesson)) if lesson.path() == case.lesson),
+            "{} 指向不存在的课程 {}",
+            case.path.display(),
+            case.lesson
+        );
+    }
+}
+
+#[test]
+fn every_case_fails_with_the_expected_error_code() {
+    let out_dir = env::temp_dir().join(format!("rust_study-compile_fail-{}", std::process::id()));
+    fs::create_dir_all(&out_dir).expect("创建临时目录失败");
+
+    let mut failures = Vec::new();
+    for case in cases() {
+        let (success, codes) = compile(&case, &out_dir);
+        let expected = BTreeSet::from([case.code.clone()]);
+        if success || codes != expected {
+            failures.push(format!(
+                "{}：预期只有 {}，实际{}，错误码 {:?}",
+                case.path.display(),
+                case.code,
+                if success { "编译成功" } else { "编译失败" },
+                codes
+            ));
+        }
+    }
+    let _ = fs::remove_dir_all(&out_dir);
+
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
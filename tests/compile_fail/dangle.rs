@@ -0,0 +1,13 @@
+//! 悬垂引用：返回局部变量的引用
+//!
+//! 课程：ownership/references_borrowing
+//! 错误：E0106
+
+fn dangle() -> &String {
+    let s = String::from("hello");
+    &s // 错误：返回局部变量的引用
+} // s 离开作用域并被丢弃，引用指向无效内存
+
+pub fn main() {
+    let reference_to_nothing = dangle();
+}
@@ -0,0 +1,16 @@
+//! 调用两次 FnOnce 闭包
+//!
+//! 课程：iterators/closures
+//! 错误：E0382
+
+fn call_once<F: FnOnce() -> String>(f: F) -> String {
+    f()
+}
+
+pub fn main() {
+    let message = String::from("只能用一次");
+    let take = move || message;
+    call_once(take);
+    // 错误：take 在第一次调用时交出了 message，闭包已经被移动
+    call_once(take);
+}
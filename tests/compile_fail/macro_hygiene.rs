@@ -0,0 +1,16 @@
+//! 使用宏展开中定义的变量
+//!
+//! 课程：macros/declarative
+//! 错误：E0425
+
+macro_rules! make_x {
+    () => {
+        let x = 1;
+    };
+}
+
+pub fn main() {
+    make_x!();
+    // 错误：宏中的 x 带有定义处的语法上下文，调用处找不到它
+    println!("{}", x);
+}
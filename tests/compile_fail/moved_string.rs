@@ -0,0 +1,10 @@
+//! 使用已经移动的字符串
+//!
+//! 课程：collections/strings
+//! 错误：E0382
+
+pub fn main() {
+    let s1 = String::from("hello");
+    let s2 = s1; // 所有权转移
+    println!("{}", s1); // 错误：s1 不再有效
+}
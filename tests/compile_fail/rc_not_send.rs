@@ -0,0 +1,17 @@
+//! 把 Rc 移动到另一个线程
+//!
+//! 课程：concurrency/shared_state
+//! 错误：E0277
+
+use std::rc::Rc;
+use std::sync::Mutex;
+use std::thread;
+
+pub fn share_counter() {
+    let counter = Rc::new(Mutex::new(0));
+
+    // 错误：Rc<T> 的引用计数不是原子操作，没有实现 Send，需要改用 Arc<T>
+    thread::spawn(move || {
+        *counter.lock().unwrap() += 1;
+    });
+}
@@ -0,0 +1,10 @@
+//! 直接包含自身的递归类型
+//!
+//! 课程：smart_pointers/box_pointers
+//! 错误：E0072
+
+// 错误：List 的大小无限，需要用 Box<List> 之类的间接层
+pub enum List {
+    Cons(i32, List),
+    Nil,
+}
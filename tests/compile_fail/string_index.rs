@@ -0,0 +1,9 @@
+//! 直接索引字符串
+//!
+//! 课程：collections/strings
+//! 错误：E0277
+
+pub fn main() {
+    let s = String::from("hello");
+    let h = s[0]; // 错误：不能直接索引字符串
+}
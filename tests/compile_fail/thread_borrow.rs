@@ -0,0 +1,15 @@
+//! 线程闭包借用局部变量
+//!
+//! 课程：advanced/lifetimes
+//! 错误：E0373
+
+use std::thread;
+
+pub fn spawn_thread() {
+    let s = String::from("hello");
+
+    // 错误：s 的生命周期不够长，需要 move 闭包
+    thread::spawn(|| {
+        println!("{}", s);
+    });
+}
@@ -0,0 +1,126 @@
+//! 课程上下文测试
+//!
+//! 课程的输入输出都经过 `Ctx`，可以直接在进程内用内存缓冲区运行。
+
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::sync::{Arc, Barrier};
+use std::thread;
+
+use rust_study::context::Ctx;
+use rust_study::registry::{self, Target};
+use rust_study::runner;
+
+// 在进程内运行一课，返回写入缓冲区的输出
+fn run_in_memory(path: &str, input: &str) -> String {
+    let lesson = match registry::resolve(path) {
+        Ok(Target::Lesson(lesson)) => lesson,
+        _ => panic!("找不到课程: {}", path),
+    };
+
+    let mut out = Vec::new();
+    let mut input = input.as_bytes();
+    let mut ctx = Ctx::new(&mut out, &mut input);
+    runner::run_lesson(&mut ctx, lesson).expect("写入内存缓冲区不应失败");
+
+    String::from_utf8(out).expect("输出不是 UTF-8")
+}
+
+#[test]
+fn lesson_output_goes_to_the_given_writer() {
+    let output = run_in_memory("basics/functions", "");
+
+    assert!(output.starts_with("\n--- 函数示例 ---\n=== 函数示例 ===\n"));
+    assert!(output.contains("Hello, Rust!\n"));
+    assert!(output.contains("10 + 20 = 30\n"));
+}
+
+#[test]
+fn lesson_input_comes_from_the_given_reader() {
+    assert!(run_in_memory("modules_errors/modules", "42\n").contains("正确!\n"));
+    assert!(run_in_memory("modules_errors/modules", "7\n").contains("太小\n"));
+    assert!(run_in_memory("modules_errors/modules", "").contains("请输入数字!\n"));
+}
+
+// 在 root 下的工作目录中依次运行会读写文件的几课，返回输出
+fn run_with_workspace(root: &Path, keep: bool, paths: &[&str]) -> String {
+    let mut out = Vec::new();
+    let mut input: &[u8] = &[];
+    let mut ctx = Ctx::new(&mut out, &mut input)
+        .with_workspace_root(root.to_path_buf())
+        .keep_workspace(keep);
+    for path in paths {
+        let lesson = match registry::resolve(path) {
+            Ok(Target::Lesson(lesson)) => lesson,
+            _ => panic!("找不到课程: {}", path),
+        };
+        runner::run_lesson(&mut ctx, lesson).unwrap();
+    }
+    drop(ctx);
+
+    String::from_utf8(out).unwrap()
+}
+
+#[test]
+fn lesson_files_live_in_a_temporary_workspace() {
+    let root = env::temp_dir().join(format!("rust_study-context-{}", std::process::id()));
+    fs::create_dir_all(&root).unwrap();
+
+    let output = run_with_workspace(&root, false, &["modules_errors/error_handling"]);
+    assert!(output.contains(&root.display().to_string()));
+    assert_eq!(fs::read_dir(&root).unwrap().count(), 0, "工作目录没有被清理");
+
+    // 保留时整次运行只有一个目录，每课一个子目录
+    let lessons = ["modules_errors/error_handling", "modules_errors/advanced_error_handling"];
+    let output = run_with_workspace(&root, true, &lessons);
+    let kept: Vec<_> = fs::read_dir(&root).unwrap().map(|e| e.unwrap().path()).collect();
+    assert_eq!(kept.len(), 1);
+    assert!(kept[0].file_name().unwrap().to_str().unwrap().starts_with(&format!("rust_study-{}-", std::process::id())));
+    for lesson in lessons {
+        let dir = kept[0].join(lesson.replace('/', "-"));
+        assert!(dir.is_dir(), "缺少 {} 的工作目录", lesson);
+        assert!(output.contains(&format!("（工作目录已保留: {}）", dir.display())));
+    }
+    assert!(kept[0].join("modules_errors-error_handling").join("hello.txt").exists());
+
+    fs::remove_dir_all(&root).unwrap();
+}
+
+// 同一进程中同时存在的 Ctx 各有自己的运行目录，一个结束时不会删掉另一个正在用的文件
+#[test]
+fn concurrent_contexts_do_not_share_a_workspace() {
+    let root = env::temp_dir().join(format!("rust_study-context-concurrent-{}", std::process::id()));
+    fs::create_dir_all(&root).unwrap();
+
+    let barrier = Arc::new(Barrier::new(2));
+    let workers: Vec<_> = (0..2)
+        .map(|i| {
+            let root = root.clone();
+            let barrier = Arc::clone(&barrier);
+            thread::spawn(move || {
+                let mut out = Vec::new();
+                let mut input: &[u8] = &[];
+                let mut ctx = Ctx::new(&mut out, &mut input).with_workspace_root(root);
+                ctx.start_workspace(String::from("lesson"));
+                let file = ctx.workspace_file("data.txt").unwrap();
+                fs::write(&file, format!("第 {} 个", i)).unwrap();
+                // 两个 Ctx 都建好文件后，先结束的一个删除自己的目录
+                barrier.wait();
+                if i == 0 {
+                    drop(ctx);
+                    barrier.wait();
+                    return file;
+                }
+                barrier.wait();
+                assert_eq!(fs::read_to_string(&file).unwrap(), "第 1 个", "另一个 Ctx 删掉了这里的文件");
+                file
+            })
+        })
+        .collect();
+    let files: Vec<_> = workers.into_iter().map(|worker| worker.join().unwrap()).collect();
+
+    assert_ne!(files[0].parent(), files[1].parent());
+    assert_eq!(fs::read_dir(&root).unwrap().count(), 0, "工作目录没有被清理");
+    fs::remove_dir_all(&root).unwrap();
+}
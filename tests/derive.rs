@@ -0,0 +1,112 @@
+//! 派生宏测试
+//!
+//! 在 rust_study 之外使用 `#[derive(Describe)]`：生成的代码通过 `::rust_study` 路径
+//! 引用 trait 和辅助函数，在依赖它的 crate 中也能编译。
+
+// 被 #[describe(skip)] 跳过的字段只写不读，空枚举 Empty 只用来检查生成的代码能否编译
+#![allow(dead_code)]
+
+use std::collections::HashMap;
+use std::io;
+
+use rust_study::advanced::traits::{Describe, Greet};
+use rust_study::context::Ctx;
+use rust_study::hashmap;
+
+// 测试只关心 describe 的输出，Greet 用同一个空实现
+macro_rules! greet_silently {
+    ($($t:ty),+) => {
+        $(
+            impl Greet for $t {
+                fn greet(&self, _ctx: &mut Ctx) -> io::Result<()> {
+                    Ok(())
+                }
+            }
+        )+
+    };
+}
+
+greet_silently!(Config, Rgb, Nothing, Event, Empty);
+
+#[derive(Describe)]
+pub struct Config {
+    pub name: &'static str,
+    // 尖括号中的逗号不是字段分隔符
+    limits: HashMap<String, u32>,
+    #[describe(skip)]
+    secret: u64,
+    /// 文档注释也是属性，不能被当作 skip
+    r#type: char,
+}
+
+#[derive(Describe)]
+struct Rgb(u8, #[describe(skip)] u8, u8);
+
+#[derive(Describe)]
+struct Nothing;
+
+// 判别值和 repr 属性都不影响生成的代码
+#[derive(Describe)]
+#[repr(u8)]
+enum Event {
+    Click { x: i32, y: i32 },
+    Key(char),
+    Hidden(#[describe(skip)] String),
+    Quit = 3,
+}
+
+#[derive(Describe)]
+enum Empty {}
+
+fn describe(value: &dyn Describe) -> String {
+    let mut out = Vec::new();
+    let mut input: &[u8] = &[];
+    let mut ctx = Ctx::new(&mut out, &mut input);
+    value.describe(&mut ctx).expect("写入内存缓冲区不应失败");
+    String::from_utf8(out).expect("输出不是 UTF-8")
+}
+
+#[test]
+fn named_fields_are_listed_in_order() {
+    let config = Config { name: "demo", limits: HashMap::new(), secret: 42, r#type: 'a' };
+    assert_eq!(describe(&config), "Config：name = \"demo\"，limits = {}，type = 'a'（隐藏了 1 个字段）\n");
+}
+
+#[test]
+fn tuple_and_unit_structs() {
+    assert_eq!(describe(&Rgb(1, 2, 3)), "Rgb：0 = 1，2 = 3（隐藏了 1 个字段）\n");
+    assert_eq!(describe(&Nothing), "Nothing（没有字段）\n");
+}
+
+#[test]
+fn each_variant_gets_its_own_arm() {
+    let events = [Event::Click { x: 1, y: -2 }, Event::Key('q'), Event::Hidden(String::from("秘密")), Event::Quit];
+    let output: Vec<String> = events.iter().map(|event| describe(event)).collect();
+    assert_eq!(
+        output,
+        [
+            "Event::Click：x = 1，y = -2\n",
+            "Event::Key：0 = 'q'\n",
+            "Event::Hidden（隐藏了 1 个字段）\n",
+            "Event::Quit（没有字段）\n",
+        ]
+    );
+}
+
+#[test]
+fn derived_impls_work_as_trait_objects() {
+    let items: Vec<Box<dyn Describe>> = vec![Box::new(Nothing), Box::new(Event::Key('x'))];
+    let output: String = items.iter().map(|item| describe(item.as_ref())).collect();
+    assert_eq!(output, "Nothing（没有字段）\nEvent::Key：0 = 'x'\n");
+}
+
+#[test]
+fn hashmap_macro_works_outside_the_crate() {
+    let empty: HashMap<&str, i32> = hashmap! {};
+    assert!(empty.is_empty());
+
+    let map = hashmap! { "a" => 1, "b" => 2, };
+    assert_eq!(map.len(), 2);
+    assert_eq!(map["b"], 2);
+    assert_eq!(rust_study::count!(1, 2, 3), 3);
+}
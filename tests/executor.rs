@@ -0,0 +1,33 @@
+//! 最小执行器测试
+//!
+//! 执行器要在所有任务都结束或再也无法被唤醒时返回，不能一直阻塞。
+
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+use rust_study::async_basics::executor::{new_executor, oneshot};
+
+#[test]
+fn run_returns_when_a_oneshot_sender_is_dropped() {
+    let (done, finished) = mpsc::channel();
+    // 在单独的线程里运行，执行器卡住时测试失败而不是一直挂起
+    thread::spawn(move || {
+        let (executor, spawner) = new_executor();
+        let (sender, receiver) = oneshot::<u32>();
+        let (result, received) = mpsc::channel();
+        spawner.spawn(async move {
+            let _ = result.send(receiver.await);
+        });
+        spawner.spawn(async move {
+            drop(sender);
+        });
+        drop(spawner);
+        let polls = executor.run();
+        let _ = done.send((polls, received.recv().unwrap()));
+    });
+
+    let (polls, value) = finished.recv_timeout(Duration::from_secs(10)).expect("run 没有返回");
+    assert_eq!(value, None);
+    assert_eq!(polls, 3);
+}
@@ -0,0 +1,135 @@
+//! 导出网站测试
+//!
+//! 把网站导出到临时目录，检查目录结构、页面之间的链接和课程页的内容。
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use rust_study::context::Ctx;
+use rust_study::export::{self, Markup};
+use rust_study::registry::{TOPICS, LESSONS};
+
+// 导出到一个新的临时目录；调用方负责删除
+fn export_to(name: &str, markup: Markup) -> PathBuf {
+    let dir = env::temp_dir().join(format!("rust_study-export-{}-{}", std::process::id(), name));
+    let _ = fs::remove_dir_all(&dir);
+    let mut out = Vec::new();
+    let mut input: &[u8] = &[];
+    let ctx = Ctx::new(&mut out, &mut input);
+    let result = export::export(&ctx, &dir, markup).unwrap();
+    assert_eq!(result.lessons, LESSONS.len());
+    assert_eq!(result.pages, 1 + TOPICS.len() + LESSONS.len());
+    assert!(result.failed.is_empty(), "运行失败的课程: {:?}", result.failed);
+    dir
+}
+
+fn read(path: &Path) -> String {
+    fs::read_to_string(path).unwrap_or_else(|e| panic!("读取 {} 失败: {}", path.display(), e))
+}
+
+// 页面中所有链接的目标：Markdown 的 ](...) 和 HTML 的 href="..."
+fn links(page: &str) -> Vec<&str> {
+    let mut links = Vec::new();
+    for open in ["](", "href=\""] {
+        for (start, _) in page.match_indices(open) {
+            let rest = &page[start + open.len()..];
+            if let Some(end) = rest.find([')', '"']) {
+                links.push(&rest[..end]);
+            }
+        }
+    }
+    links
+}
+
+fn pages(dir: &Path) -> Vec<PathBuf> {
+    let mut pages = vec![dir.join("index.md"), dir.join("index.html")];
+    for topic in TOPICS {
+        for entry in fs::read_dir(dir.join(topic.id)).unwrap() {
+            pages.push(entry.unwrap().path());
+        }
+    }
+    pages.retain(|page| page.exists());
+    pages
+}
+
+// 每个相对链接都指向存在的页面，页内锚点都有对应的标题
+fn assert_links_resolve(dir: &Path) {
+    for page in pages(dir) {
+        let text = read(&page);
+        for link in links(&text) {
+            assert!(!link.contains("://"), "{} 引用了外部资源 {}", page.display(), link);
+            let (file, anchor) = link.split_once('#').unwrap_or((link, ""));
+            let target = if file.is_empty() { page.clone() } else { page.parent().unwrap().join(file) };
+            assert!(target.exists(), "{} 中的链接 {} 指向不存在的文件", page.display(), link);
+            if !anchor.is_empty() {
+                let target = read(&target);
+                assert!(
+                    target.contains(&format!("<a id=\"{}\">", anchor)) || target.contains(&format!(" id=\"{}\">", anchor)),
+                    "{} 中的链接 {} 没有对应的锚点",
+                    page.display(),
+                    link
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn markdown_site_mirrors_the_topic_tree() {
+    let dir = export_to("markdown", Markup::Markdown);
+
+    let index = read(&dir.join("index.md"));
+    for lesson in LESSONS {
+        assert!(index.contains(&format!("]({}/{}.md)", lesson.topic, lesson.id)), "目录中没有 {}", lesson.path());
+        assert!(dir.join(lesson.topic).join(format!("{}.md", lesson.id)).exists());
+    }
+    assert_links_resolve(&dir);
+
+    // 课程页：模块文档、小节标题、源码和输出依次出现
+    let page = read(&dir.join("basics").join("functions.md"));
+    let position = |needle: &str| page.find(needle).unwrap_or_else(|| panic!("页面中没有 {:?}", needle));
+    assert!(page.starts_with("[目录](../index.md) · [基础语法](index.md)\n\n# 函数\n"));
+    assert!(position("函数使用 `fn` 关键字定义") < position("## §1 无参数无返回值的函数"));
+    assert!(!page.contains("//! 函数"));
+    assert!(position("## §4 有返回值的函数") < position("    let result = add(10, 20);"));
+    assert!(position("    let result = add(10, 20);") < position("10 + 20 = 30"));
+    assert!(position("10 + 20 = 30") < position("## §5 使用表达式作为返回值"));
+    assert!(page.ends_with("[下一课：if-else](../control_flow/if_else.md)\n"));
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+// 标题中的 <T> 不转义会被 Markdown 渲染器当作 HTML 标签吞掉
+#[test]
+fn markdown_escapes_type_titles() {
+    let dir = export_to("markdown_escape", Markup::Markdown);
+
+    let page = read(&dir.join("smart_pointers").join("box_pointers.md"));
+    assert!(page.contains("\n# Box\\<T\\>\n"));
+    let index = read(&dir.join("index.md"));
+    assert!(index.contains("[Box\\<T\\>](smart_pointers/box_pointers.md)"));
+    assert!(index.contains("Vec\\<T\\>"));
+    assert!(!index.contains("<T>"));
+    assert_links_resolve(&dir);
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn html_site_is_self_contained_and_escaped() {
+    let dir = export_to("html", Markup::Html);
+    assert!(!dir.join("index.md").exists());
+    assert_links_resolve(&dir);
+
+    let page = read(&dir.join("advanced").join("traits.html"));
+    assert!(page.starts_with("<!DOCTYPE html>\n<html lang=\"zh-CN\">"));
+    assert!(page.contains("<h2 id=\"s1\">§1 基本 Trait 使用</h2>"));
+    assert!(page.contains("<ol>\n  <li>定义共享的行为</li>"));
+    // 源码中的尖括号和引号都要转义
+    assert!(page.contains("&lt;"));
+    assert!(!page.contains("Vec<"));
+    assert!(!page.contains(" src="));
+
+    let _ = fs::remove_dir_all(&dir);
+}
@@ -0,0 +1,230 @@
+//! 课程输出快照测试
+//!
+//! 逐个运行注册表中的课程，把输出与 tests/snapshots 下的快照文件比较。
+//! 课程行为有意改变时，用下面的命令重新生成快照：
+//!
+//! ```text
+//! UPDATE_SNAPSHOTS=1 cargo test --test golden
+//! ```
+
+use std::collections::HashSet;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use rust_study::registry::{Lesson, LESSONS};
+
+// 输出顺序依赖 HashMap 随机哈希的课程：比较时忽略行内和行间的顺序
+const UNORDERED: &[&str] = &["collections/hashmaps"];
+
+fn snapshot_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("snapshots")
+}
+
+fn snapshot_path(lesson: &Lesson) -> PathBuf {
+    snapshot_dir().join(lesson.topic).join(format!("{}.txt", lesson.id))
+}
+
+// 在独立的临时目录中运行一课，捕获标准输出
+//
+// 这个目录同时是当前目录和 TMPDIR，课程的临时工作目录也建在这里；
+// 输出中的目录路径和进程号替换为占位符。课程结束后目录里不应留下任何文件。
+fn capture(lesson: &Lesson) -> String {
+    let workdir = env::temp_dir().join(format!(
+        "rust_study-golden-{}-{}-{}",
+        std::process::id(),
+        lesson.topic,
+        lesson.id
+    ));
+    fs::create_dir_all(&workdir).expect("创建临时目录失败");
+
+    let child = Command::new(env!("CARGO_BIN_EXE_rust_study"))
+        .args(["--lang", "zh-CN", "run", &lesson.path()])
+        .current_dir(&workdir)
+        .env("TMPDIR", &workdir)
+        .env("RUST_STUDY_PROGRESS", "")
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("运行 rust_study 失败");
+    let pid = child.id();
+    let output = child.wait_with_output().expect("运行 rust_study 失败");
+
+    let leftovers: Vec<_> = fs::read_dir(&workdir)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name())
+        .collect();
+    let _ = fs::remove_dir_all(&workdir);
+
+    assert!(
+        output.status.success(),
+        "{} 运行失败: {}",
+        lesson.path(),
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(leftovers.is_empty(), "{} 留下了文件: {:?}", lesson.path(), leftovers);
+
+    String::from_utf8(output.stdout)
+        .expect("输出不是 UTF-8")
+        .replace(&workdir.display().to_string(), "<workdir>")
+        .split(&format!("rust_study-{}-", pid))
+        .enumerate()
+        .map(|(i, part)| if i == 0 { part.to_string() } else { mask_run_number(part, pid) })
+        .collect()
+}
+
+// 运行目录 "rust_study-<进程号>-<序号>" 中的序号随进程内创建的 Ctx 个数变化，
+// 与进程号一起替换为占位符；part 是 "rust_study-<进程号>-" 后面的内容
+fn mask_run_number(part: &str, pid: u32) -> String {
+    let rest = part.trim_start_matches(|c: char| c.is_ascii_digit());
+    if rest.len() < part.len() {
+        format!("rust_study-<pid>{}", rest)
+    } else {
+        format!("rust_study-{}-{}", pid, part)
+    }
+}
+
+// 把每行的字符和所有行都排序，用于比较顺序不稳定的输出
+fn unordered(text: &str) -> Vec<String> {
+    let mut lines: Vec<String> = text
+        .lines()
+        .map(|line| {
+            let mut chars: Vec<char> = line.chars().collect();
+            chars.sort_unstable();
+            chars.into_iter().collect()
+        })
+        .collect();
+    lines.sort();
+    lines
+}
+
+// 基于最长公共子序列的逐行对比，只显示改动附近的几行
+fn diff(expected: &str, actual: &str) -> String {
+    const CONTEXT: usize = 2;
+
+    let old: Vec<&str> = expected.lines().collect();
+    let new: Vec<&str> = actual.lines().collect();
+
+    let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut lines = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            lines.push((' ', old[i]));
+            i += 1;
+            j += 1;
+        } else if i < old.len() && (j == new.len() || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            lines.push(('-', old[i]));
+            i += 1;
+        } else {
+            lines.push(('+', new[j]));
+            j += 1;
+        }
+    }
+
+    let changed: Vec<usize> = (0..lines.len()).filter(|&k| lines[k].0 != ' ').collect();
+    let mut out = String::new();
+    let mut last_shown = None;
+    for (k, (mark, line)) in lines.iter().enumerate() {
+        let near = changed
+            .iter()
+            .any(|&c| k + CONTEXT >= c && k <= c + CONTEXT);
+        if !near {
+            continue;
+        }
+        if last_shown.is_some_and(|last| last + 1 != k) {
+            out.push_str("   ...\n");
+        }
+        out.push_str(&format!("{:>4} {} {}\n", k + 1, mark, line));
+        last_shown = Some(k);
+    }
+    out
+}
+
+#[test]
+fn lessons_match_snapshots() {
+    let update = env::var_os("UPDATE_SNAPSHOTS").is_some();
+    let mut failures = Vec::new();
+
+    for lesson in LESSONS {
+        let actual = capture(lesson);
+        let path = snapshot_path(lesson);
+
+        let expected = fs::read_to_string(&path).ok();
+        let matches = match &expected {
+            Some(expected) if UNORDERED.contains(&lesson.path().as_str()) => {
+                unordered(expected) == unordered(&actual)
+            }
+            Some(expected) => *expected == actual,
+            None => false,
+        };
+
+        // 只重写不一致的快照，顺序不稳定的课程不会因为重新生成而产生无关改动
+        if update {
+            if !matches {
+                fs::create_dir_all(path.parent().unwrap()).unwrap();
+                fs::write(&path, &actual).unwrap();
+            }
+            continue;
+        }
+
+        let expected = match expected {
+            Some(expected) => expected,
+            None => {
+                failures.push(format!("{}: 缺少快照 {}", lesson.path(), path.display()));
+                continue;
+            }
+        };
+
+        if !matches {
+            failures.push(format!(
+                "{}: 输出与快照不一致（- 快照，+ 实际）\n{}",
+                lesson.path(),
+                diff(&expected, &actual)
+            ));
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "{} 课的输出与快照不一致，确认改动后用 UPDATE_SNAPSHOTS=1 重新生成：\n\n{}",
+        failures.len(),
+        failures.join("\n")
+    );
+}
+
+#[test]
+fn every_snapshot_belongs_to_a_lesson() {
+    let known: HashSet<PathBuf> = LESSONS.iter().map(snapshot_path).collect();
+
+    for topic in fs::read_dir(snapshot_dir()).unwrap() {
+        for file in fs::read_dir(topic.unwrap().path()).unwrap() {
+            let path = file.unwrap().path();
+            assert!(known.contains(&path), "快照没有对应的课程: {}", path.display());
+        }
+    }
+}
+
+// 并发和异步课程的输出不能依赖线程调度：多运行几次，结果应该完全相同
+#[test]
+fn concurrency_lessons_are_deterministic() {
+    let threaded = ["concurrency", "async_basics"];
+    for lesson in LESSONS.iter().filter(|lesson| threaded.contains(&lesson.topic)) {
+        let first = capture(lesson);
+        for _ in 0..4 {
+            assert_eq!(capture(lesson), first, "{} 的输出每次运行都不同", lesson.path());
+        }
+    }
+}
@@ -0,0 +1,136 @@
+//! 消息目录测试
+//!
+//! 两种语言的目录必须有相同的键和相同个数的参数，
+//! 代码中用到的每个键都必须出现在目录里。
+
+use std::collections::BTreeSet;
+use std::env;
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+use rust_study::context::Ctx;
+use rust_study::i18n::Locale;
+use rust_study::registry::{self, Target};
+use rust_study::runner;
+
+#[test]
+fn catalogues_have_the_same_keys() {
+    let zh: BTreeSet<&str> = Locale::ZhCn.keys().collect();
+    let en: BTreeSet<&str> = Locale::EnUs.keys().collect();
+
+    let only_zh: Vec<_> = zh.difference(&en).collect();
+    let only_en: Vec<_> = en.difference(&zh).collect();
+    assert!(only_zh.is_empty(), "只在 zh-CN 中的键: {:?}", only_zh);
+    assert!(only_en.is_empty(), "只在 en-US 中的键: {:?}", only_en);
+
+    for locale in Locale::ALL {
+        assert_eq!(locale.keys().count(), zh.len(), "{} 中有重复的键", locale);
+    }
+}
+
+#[test]
+fn translations_take_the_same_arguments() {
+    for key in Locale::ZhCn.keys() {
+        let zh = Locale::ZhCn.text(key).matches("{}").count();
+        let en = Locale::EnUs.text(key).matches("{}").count();
+        assert_eq!(zh, en, "{} 的参数个数不一致", key);
+    }
+}
+
+// 收集源码中 .text("...") 和 .message("...") 用到的键
+fn keys_used_in(dir: &Path, keys: &mut BTreeSet<String>) {
+    for entry in fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            keys_used_in(&path, keys);
+            continue;
+        }
+        let source = fs::read_to_string(&path).unwrap();
+        for call in [".text(\"", ".message(\""] {
+            for (start, _) in source.match_indices(call) {
+                let rest = &source[start + call.len()..];
+                let key = &rest[..rest.find('"').unwrap()];
+                keys.insert(key.to_string());
+            }
+        }
+    }
+}
+
+#[test]
+fn every_key_used_in_the_code_is_in_the_catalogue() {
+    let mut used = BTreeSet::new();
+    keys_used_in(&Path::new(env!("CARGO_MANIFEST_DIR")).join("src"), &mut used);
+    assert!(!used.is_empty());
+
+    let known: BTreeSet<&str> = Locale::ZhCn.keys().collect();
+    let missing: Vec<_> = used.iter().filter(|key| !known.contains(key.as_str())).collect();
+    assert!(missing.is_empty(), "目录中缺少的键: {:?}", missing);
+}
+
+#[test]
+fn locale_tags_are_parsed_loosely() {
+    assert_eq!(Locale::parse("zh-CN"), Some(Locale::ZhCn));
+    assert_eq!(Locale::parse("zh_CN.UTF-8"), Some(Locale::ZhCn));
+    assert_eq!(Locale::parse("en"), Some(Locale::EnUs));
+    assert_eq!(Locale::parse("en_US.UTF-8"), Some(Locale::EnUs));
+    assert_eq!(Locale::parse("EN-us"), Some(Locale::EnUs));
+    assert_eq!(Locale::parse("C"), None);
+    assert_eq!(Locale::parse("fr_FR.UTF-8"), None);
+}
+
+#[test]
+fn english_headings_replace_chinese_ones() {
+    let lesson = match registry::resolve("control_flow/loops") {
+        Ok(Target::Lesson(lesson)) => lesson,
+        _ => panic!("找不到 control_flow/loops"),
+    };
+
+    let mut out = Vec::new();
+    let mut input: &[u8] = &[];
+    let mut ctx = Ctx::new(&mut out, &mut input).with_locale(Locale::EnUs);
+    runner::run_lesson(&mut ctx, lesson).unwrap();
+    let output = String::from_utf8(out).unwrap();
+
+    assert!(output.starts_with("\n--- Loops ---\n=== loop ===\n"));
+    assert!(output.contains("\n=== Iterator methods ===\n"));
+    assert!(!output.contains("循环 ==="));
+}
+
+// 英文界面的单步提示里不应出现中文：小节标题也要有英文版本
+#[test]
+fn english_step_prompts_have_no_chinese() {
+    let workdir = env::temp_dir().join(format!("rust_study-i18n-{}", std::process::id()));
+    fs::create_dir_all(&workdir).unwrap();
+
+    let mut child = Command::new(env!("CARGO_BIN_EXE_rust_study"))
+        .args(["--lang", "en", "--step", "all"])
+        .current_dir(&workdir)
+        .env("TMPDIR", &workdir)
+        .env("RUST_STUDY_PROGRESS", "")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .expect("运行 rust_study 失败");
+    // 每个提示都回车继续；写入失败说明程序已经读够输入退出了
+    let mut stdin = child.stdin.take().unwrap();
+    let _ = stdin.write_all("\n".repeat(5000).as_bytes());
+    drop(stdin);
+    let output = child.wait_with_output().expect("运行 rust_study 失败");
+    let _ = fs::remove_dir_all(&workdir);
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let prompts: Vec<&str> = stdout
+        .split("—— end of §")
+        .skip(1)
+        .map(|rest| rest.split_once(" ——").map_or(rest, |(prompt, _)| prompt))
+        .collect();
+    assert!(prompts.len() > 300, "只找到 {} 个单步提示", prompts.len());
+    let chinese: Vec<&&str> = prompts
+        .iter()
+        .filter(|prompt| prompt.chars().any(|c| ('\u{4e00}'..='\u{9fff}').contains(&c) || ('\u{ff00}'..='\u{ffef}').contains(&c)))
+        .collect();
+    assert!(chinese.is_empty(), "英文单步提示中有中文: {:?}", chinese);
+}
@@ -0,0 +1,47 @@
+//! 临时 panic 钩子测试
+//!
+//! `panics::with_hook` 登记的钩子只处理当前线程的 panic，结束后恢复外层的钩子。
+
+use std::cell::Cell;
+use std::panic;
+use std::rc::Rc;
+use std::thread;
+
+use rust_study::panics;
+
+// 记录钩子被调用的次数
+fn counting_hook(count: &Rc<Cell<u32>>) -> impl Fn(&panic::PanicHookInfo<'_>) + 'static {
+    let count = Rc::clone(count);
+    move |_| count.set(count.get() + 1)
+}
+
+#[test]
+fn hook_only_sees_panics_on_the_current_thread() {
+    let count = Rc::new(Cell::new(0));
+    let (here, other) = panics::with_hook(counting_hook(&count), || {
+        let here = panic::catch_unwind(|| panic!("当前线程"));
+        // 其他线程的 panic 交给原来的钩子，不会被这里的钩子吞掉
+        let other = thread::spawn(|| panics::quietly(|| panic!("其他线程"))).join();
+        (here, other)
+    });
+
+    assert!(here.is_err());
+    assert!(other.is_err());
+    assert_eq!(count.get(), 1);
+}
+
+#[test]
+fn nested_hooks_are_restored_in_order() {
+    let outer = Rc::new(Cell::new(0));
+    let inner = Rc::new(Cell::new(0));
+    panics::with_hook(counting_hook(&outer), || {
+        let _ = panics::with_hook(counting_hook(&inner), || panic::catch_unwind(|| panic!("内层")));
+        let _ = panic::catch_unwind(|| panic!("外层"));
+    });
+
+    assert_eq!((outer.get(), inner.get()), (1, 1));
+
+    // 离开之后不再调用任何临时钩子
+    panics::quietly(|| panic::catch_unwind(|| panic!("静默")).is_err());
+    assert_eq!((outer.get(), inner.get()), (1, 1));
+}
@@ -0,0 +1,156 @@
+//! 学习进度测试
+//!
+//! 进度文件放在每个测试自己的临时目录中，不碰用户的配置目录。
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use rust_study::context::Ctx;
+use rust_study::progress::{self, Completion, Kind, Progress};
+use rust_study::registry::{self, Target, TOPICS};
+use rust_study::runner;
+
+// 名为 name 的临时目录中的进度文件；目录先清空
+fn progress_file(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("rust_study-progress-{}-{}", std::process::id(), name));
+    let _ = fs::remove_dir_all(&dir);
+    dir.join("nested").join("progress.tsv")
+}
+
+fn cleanup(path: &Path) {
+    let _ = fs::remove_dir_all(path.parent().unwrap().parent().unwrap());
+}
+
+#[test]
+fn records_survive_a_reload_and_bad_lines_are_skipped() {
+    let path = progress_file("reload");
+    let mut progress = Progress::load(path.clone()).unwrap();
+    assert!(progress.records().is_empty());
+
+    progress.record(Kind::Lesson, "basics/functions", None).unwrap();
+    progress.record(Kind::Quiz, "basics", Some((2, 4))).unwrap();
+    progress.record(Kind::Quiz, "basics", Some((3, 4))).unwrap();
+    fs::write(&path, fs::read_to_string(&path).unwrap() + "# 注释\n不是记录\nquiz\tbasics\tsoon\n").unwrap();
+
+    let progress = Progress::load(path.clone()).unwrap();
+    assert_eq!(progress.records().len(), 3);
+    assert!(progress.is_done(Kind::Lesson, "basics/functions"));
+    assert!(!progress.is_done(Kind::Exercise, "basics/functions"));
+    assert_eq!(progress.best_score("basics"), Some((3, 4)));
+
+    cleanup(&path);
+}
+
+#[test]
+fn completion_counts_lessons_exercises_and_the_best_quiz() {
+    let path = progress_file("completion");
+    let mut progress = Progress::load(path.clone()).unwrap();
+    let basics = &TOPICS[0];
+
+    let empty = progress.completion(basics);
+    assert_eq!(empty.percent(), 0);
+    assert_eq!(empty.lessons, (0, registry::lessons_of("basics").count()));
+
+    progress.record(Kind::Lesson, "basics/functions", None).unwrap();
+    progress.record(Kind::Lesson, "basics/functions", None).unwrap();
+    progress.record(Kind::Exercise, "basics/fahrenheit", None).unwrap();
+    progress.record(Kind::Quiz, "basics", Some((1, 4))).unwrap();
+    // 其他主题的记录不算在内
+    progress.record(Kind::Lesson, "advanced/traits", None).unwrap();
+
+    let completion = progress.completion(basics);
+    assert_eq!(completion.lessons.0, 1);
+    assert_eq!(completion.exercises.0, 1);
+    assert_eq!(completion.quiz.0, 1);
+
+    let full = Completion {
+        lessons: (3, 3),
+        exercises: (3, 3),
+        quiz: (4, 4),
+    };
+    assert_eq!(full.percent(), 100);
+
+    cleanup(&path);
+}
+
+#[test]
+fn passing_lessons_are_recorded_by_the_runner() {
+    let path = progress_file("runner");
+    let Ok(Target::Lesson(lesson)) = registry::resolve("basics/functions") else {
+        panic!("找不到 basics/functions");
+    };
+
+    let mut out = Vec::new();
+    let mut input: &[u8] = &[];
+    let mut ctx = Ctx::new(&mut out, &mut input).with_progress(Progress::load(path.clone()).unwrap());
+    runner::run_lesson(&mut ctx, lesson).unwrap();
+    assert!(ctx.progress().unwrap().is_done(Kind::Lesson, "basics/functions"));
+    drop(ctx);
+
+    let saved = Progress::load(path.clone()).unwrap();
+    assert!(saved.is_done(Kind::Lesson, "basics/functions"));
+
+    cleanup(&path);
+}
+
+#[test]
+fn export_is_versioned_json() {
+    let path = progress_file("export");
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(&path, "lesson\tbasics/functions\t1700000000\nquiz\tbasics\t1700000060\t3/4\n").unwrap();
+
+    let progress = Progress::load(path.clone()).unwrap();
+    assert_eq!(
+        progress.to_json(),
+        concat!(
+            r#"{"schema":"rust_study.progress","version":1,"records":["#,
+            r#"{"kind":"lesson","id":"basics/functions","time":1700000000,"score":null},"#,
+            r#"{"kind":"quiz","id":"basics","time":1700000060,"score":{"correct":3,"total":4}}"#,
+            r#"]}"#
+        )
+    );
+
+    cleanup(&path);
+}
+
+#[test]
+fn times_are_formatted_in_utc() {
+    assert_eq!(progress::format_time(0), "1970-01-01 00:00 UTC");
+    assert_eq!(progress::format_time(1_700_000_000), "2023-11-14 22:13 UTC");
+    assert_eq!(progress::format_time(951_782_400), "2000-02-29 00:00 UTC");
+}
+
+// 用指定的进度文件运行二进制，返回标准输出
+fn run_binary(path: &Path, args: &[&str]) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_rust_study"))
+        .args(["--lang", "zh-CN"])
+        .args(args)
+        .env("RUST_STUDY_PROGRESS", path)
+        .stdin(Stdio::null())
+        .output()
+        .expect("运行 rust_study 失败");
+    assert!(output.status.success(), "{:?} 失败", args);
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn progress_command_shows_exports_and_resets() {
+    let path = progress_file("command");
+
+    run_binary(&path, &["run", "basics/functions"]);
+    let shown = run_binary(&path, &["progress"]);
+    assert!(shown.contains(&format!("学习进度（保存在 {}）：", path.display())));
+    assert!(shown.contains("课程 1/3"));
+    assert!(shown.contains("最近一次学习："));
+
+    let exported = run_binary(&path, &["progress", "export"]);
+    assert!(exported.starts_with(r#"{"schema":"rust_study.progress","version":1,"records":[{"kind":"lesson","id":"basics/functions","#));
+
+    run_binary(&path, &["progress", "reset"]);
+    assert!(!path.exists());
+    assert!(run_binary(&path, &["progress"]).contains("还没有完成任何内容"));
+
+    cleanup(&path);
+}
@@ -0,0 +1,146 @@
+//! 小测验测试
+//!
+//! 检查每道题都有唯一的正确答案，并用内存中的输入走一遍答题流程。
+
+use rust_study::context::Ctx;
+use rust_study::i18n::Locale;
+use rust_study::quiz::{self, Answer, Question, Score};
+use rust_study::registry::{self, Target};
+
+#[test]
+fn every_question_has_exactly_one_correct_option() {
+    for question in quiz::questions() {
+        match question.answer {
+            Answer::Choice(index) => assert!(
+                index < question.options.len(),
+                "{}：正确答案下标越界",
+                question.prompt
+            ),
+            Answer::Output(_) => {
+                let output = question.output().unwrap();
+                let matching = question.options.iter().filter(|option| **option == output).count();
+                assert_eq!(
+                    matching, 1,
+                    "{} / {:?}：实际输出 {:?} 应当恰好匹配一个选项",
+                    question.topic, question.code, output
+                );
+            }
+        }
+        assert!(question.correct().is_some());
+        assert!(question.options.len() >= 2);
+        assert!(!question.explanation.is_empty());
+
+        // 英文题目与中文一一对应，预测输出题的正确选项就是实际输出，两种语言相同
+        assert!(!question.prompt_en.is_empty() && !question.explanation_en.is_empty(), "{} 缺少英文", question.prompt);
+        assert_eq!(question.options_en.len(), question.options.len(), "{}：英文选项个数不同", question.prompt);
+        if let Some(output) = question.output() {
+            assert_eq!(question.options_en[question.correct().unwrap()], output, "{}：英文的正确选项不是实际输出", question.prompt);
+        }
+    }
+}
+
+#[test]
+fn every_question_points_to_a_lesson_and_every_topic_has_questions() {
+    for question in quiz::questions() {
+        let path = format!("{}/{}", question.topic, question.lesson);
+        assert!(
+            matches!(registry::resolve(&path), Ok(Target::Lesson(lesson)) if lesson.path() == path),
+            "题目指向不存在的课程 {}",
+            path
+        );
+    }
+
+    for topic in registry::TOPICS {
+        let questions = quiz::questions_for(Target::Topic(topic));
+        assert!(!questions.is_empty(), "{} 没有题目", topic.id);
+        assert!(
+            questions.iter().any(|question| matches!(question.answer, Answer::Output(_))),
+            "{} 没有预测输出题",
+            topic.id
+        );
+    }
+}
+
+// 用给定的输入答题，返回得分和输出
+fn take_quiz(questions: &[&Question], input: &str) -> (Score, String) {
+    take_quiz_in(questions, input, Locale::ZhCn)
+}
+
+fn take_quiz_in(questions: &[&Question], input: &str, locale: Locale) -> (Score, String) {
+    let mut out = Vec::new();
+    let mut input = input.as_bytes();
+    let mut ctx = Ctx::new(&mut out, &mut input).with_locale(locale);
+    let score = quiz::run(&mut ctx, questions).unwrap();
+    (score, String::from_utf8(out).unwrap())
+}
+
+// 按正确答案或错误答案作答
+fn answers(questions: &[&Question], right: &[bool]) -> String {
+    questions
+        .iter()
+        .zip(right)
+        .map(|(question, right)| {
+            let correct = question.correct().unwrap();
+            let index = if *right { correct } else { (correct + 1) % question.options.len() };
+            format!("{}\n", (b'a' + index as u8) as char)
+        })
+        .collect()
+}
+
+#[test]
+fn quiz_scores_answers_and_explains_them() {
+    let Ok(target) = registry::resolve("structs_enums") else {
+        panic!("找不到 structs_enums");
+    };
+    let questions = quiz::questions_for(target);
+    let right: Vec<bool> = (0..questions.len()).map(|i| i != 0).collect();
+
+    let (score, output) = take_quiz(&questions, &answers(&questions, &right));
+    assert_eq!(
+        score,
+        Score {
+            correct: questions.len() - 1,
+            answered: questions.len(),
+            total: questions.len()
+        }
+    );
+    assert!(output.contains("回答错误，正确答案是 b。"));
+    assert!(output.contains("实际输出：\n    来自 Alaska 州的硬币\n    25\n"));
+    assert!(output.contains("相关课程：rust_study run structs_enums/pattern_matching\n"));
+    assert!(output.ends_with(&format!("得分：{}/{}\n", questions.len() - 1, questions.len())));
+}
+
+#[test]
+fn invalid_answers_are_asked_again_and_q_finishes_early() {
+    let Ok(target) = registry::resolve("ownership") else {
+        panic!("找不到 ownership");
+    };
+    let questions = quiz::questions_for(target);
+    let first = answers(&questions[..1], &[true]);
+
+    let (score, output) = take_quiz(&questions, &format!("7\nzz\n{}q\n", first));
+    assert_eq!(score.answered, 1);
+    assert_eq!(score.correct, 1);
+    assert!(output.contains("无效答案: 7\n"));
+    assert!(output.contains("无效答案: zz\n"));
+    assert!(output.ends_with(&format!("得分：1/{}\n", questions.len())));
+
+    // 输入结束时同样交卷
+    let (score, _) = take_quiz(&questions, "");
+    assert_eq!(score.answered, 0);
+}
+
+// 英文界面下题目、选项和解释都是英文
+#[test]
+fn quiz_follows_the_locale() {
+    let Ok(target) = registry::resolve("basics/functions") else {
+        panic!("找不到 basics/functions");
+    };
+    let questions = quiz::questions_for(target);
+    let (_, output) = take_quiz_in(&questions, &answers(&questions, &vec![false; questions.len()]), Locale::EnUs);
+
+    assert!(output.contains("] What does this code print?\n"));
+    assert!(output.contains("  d) Compile error: a function can return only one value\n"));
+    assert!(output.contains(questions[0].explanation_en));
+    assert!(!output.chars().any(|c| ('\u{4e00}'..='\u{9fff}').contains(&c)), "英文测验中有中文: {}", output);
+}
@@ -0,0 +1,153 @@
+//! JSON 报告测试
+//!
+//! 报告格式是对外的约定：这里逐字节固定第 1 版的输出，修改格式时必须同时提升版本号。
+
+use std::env;
+use std::fs;
+use std::io;
+use std::process::{Command, Stdio};
+use std::time::Duration;
+
+use rust_study::context::Ctx;
+use rust_study::registry::Lesson;
+use rust_study::report::{self, Format, SCHEMA_VERSION};
+use rust_study::runner::{LessonRun, Status, Summary};
+
+fn no_op(_ctx: &mut Ctx) -> io::Result<()> {
+    Ok(())
+}
+
+static FIRST: Lesson = Lesson {
+    id: "first",
+    topic: "test",
+    title: "第一课",
+    title_en: "First",
+    description: "",
+    description_en: "",
+    run: no_op,
+    source: "",
+};
+
+static SECOND: Lesson = Lesson {
+    id: "second",
+    topic: "test",
+    title: "第二课",
+    title_en: "Second",
+    description: "",
+    description_en: "",
+    run: no_op,
+    source: "",
+};
+
+// 耗时是固定的，报告可以逐字节比较
+fn lesson_run(lesson: &'static Lesson, status: Status, micros: u64, output: &str) -> LessonRun {
+    LessonRun {
+        lesson,
+        status,
+        duration: Duration::from_micros(micros),
+        output: output.to_string(),
+    }
+}
+
+#[test]
+fn report_schema_is_pinned() {
+    assert_eq!(SCHEMA_VERSION, 1, "修改报告格式后请同时更新本文件中的期望输出");
+
+    let summary = Summary {
+        runs: vec![
+            lesson_run(&FIRST, Status::Passed, 1500, "你好\n"),
+            lesson_run(&SECOND, Status::Panicked("越界".to_string()), 42, ""),
+            lesson_run(&FIRST, Status::Failed("磁盘已满".to_string()), 7, "写到一半\n"),
+        ],
+    };
+
+    let expected = concat!(
+        r#"{"schema":"rust_study.report","version":1,"lessons":["#,
+        r#"{"id":"first","topic":"test","status":"passed","duration_us":1500,"output":"你好\n","panic":null,"error":null},"#,
+        r#"{"id":"second","topic":"test","status":"panicked","duration_us":42,"output":"","panic":"越界","error":null},"#,
+        r#"{"id":"first","topic":"test","status":"failed","duration_us":7,"output":"写到一半\n","panic":null,"error":"磁盘已满"}"#,
+        r#"],"summary":{"total":3,"passed":1,"failed":2}}"#,
+    );
+    assert_eq!(report::to_json(&summary), expected);
+}
+
+#[test]
+fn strings_are_escaped() {
+    let summary = Summary {
+        runs: vec![lesson_run(
+            &FIRST,
+            Status::Passed,
+            0,
+            "\"引号\" \\ \t\r\n\u{1b}[0m",
+        )],
+    };
+
+    let json = report::to_json(&summary);
+    assert!(
+        json.contains(r#""output":"\"引号\" \\ \t\r\n\u001b[0m""#),
+        "{}",
+        json
+    );
+}
+
+#[test]
+fn format_names() {
+    assert_eq!(Format::parse("text"), Some(Format::Text));
+    assert_eq!(Format::parse("json"), Some(Format::Json));
+    assert_eq!(Format::parse("yaml"), None);
+    assert_eq!(Format::default(), Format::Text);
+}
+
+// 在名为 name 的临时目录中运行二进制，返回退出码和标准输出
+fn run_binary(name: &str, args: &[&str]) -> (Option<i32>, String) {
+    let workdir = env::temp_dir().join(format!("rust_study-report-{}-{}", std::process::id(), name));
+    fs::create_dir_all(&workdir).unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_rust_study"))
+        .args(args)
+        .current_dir(&workdir)
+        .env("TMPDIR", &workdir)
+        .env("RUST_STUDY_PROGRESS", "")
+        .stdin(Stdio::null())
+        .output()
+        .expect("运行 rust_study 失败");
+    let _ = fs::remove_dir_all(&workdir);
+
+    (output.status.code(), String::from_utf8(output.stdout).unwrap())
+}
+
+#[test]
+fn json_mode_prints_only_the_report() {
+    let (code, stdout) = run_binary(
+        "json",
+        &[
+            "--lang",
+            "zh-CN",
+            "--format",
+            "json",
+            "run",
+            "basics/functions",
+        ],
+    );
+
+    assert_eq!(code, Some(0));
+    assert_eq!(
+        stdout.lines().count(),
+        1,
+        "报告之外不应有其他输出: {}",
+        stdout
+    );
+    assert!(stdout.starts_with(r#"{"schema":"rust_study.report","version":1,"lessons":[{"id":"functions","topic":"basics","status":"passed","duration_us":"#));
+    assert!(stdout.contains(r#""output":"=== 函数示例 ===\nHello, Rust!\n"#));
+    assert!(stdout.ends_with("\"summary\":{\"total\":1,\"passed\":1,\"failed\":0}}\n"));
+}
+
+#[test]
+fn json_mode_is_only_for_running_lessons() {
+    let (code, stdout) = run_binary("list", &["--format", "json", "list"]);
+    assert_eq!(code, Some(2));
+    assert!(stdout.is_empty());
+
+    let (code, _) = run_binary("xml", &["--format", "xml", "run", "basics"]);
+    assert_eq!(code, Some(2));
+}
@@ -0,0 +1,150 @@
+//! 运行器测试
+//!
+//! 用测试中定义的课程检查 panic 隔离、汇总结果和单步模式。
+
+use std::io::{self, Write};
+
+use rust_study::context::Ctx;
+use rust_study::registry::Lesson;
+use rust_study::runner::{self, Status};
+
+fn ok_lesson(ctx: &mut Ctx) -> io::Result<()> {
+    writeln!(ctx, "正常的一课")
+}
+
+fn panicking_lesson(ctx: &mut Ctx) -> io::Result<()> {
+    writeln!(ctx, "panic 之前的输出")?;
+    let numbers: Vec<i32> = Vec::new();
+    writeln!(ctx, "{}", numbers[0])
+}
+
+fn failing_lesson(_ctx: &mut Ctx) -> io::Result<()> {
+    Err(io::Error::other("磁盘已满"))
+}
+
+fn sectioned_lesson(ctx: &mut Ctx) -> io::Result<()> {
+    ctx.section(1, "第一节", "First")?;
+    writeln!(ctx, "一")?;
+    ctx.section(2, "第二节", "Second")?;
+    writeln!(ctx, "二")?;
+    ctx.section(3, "第三节", "Third")?;
+    writeln!(ctx, "三")
+}
+
+static OK: Lesson = Lesson {
+    id: "ok",
+    topic: "test",
+    title: "正常",
+    title_en: "Ok",
+    description: "",
+    description_en: "",
+    run: ok_lesson,
+    source: "",
+};
+
+static PANICKING: Lesson = Lesson {
+    id: "panicking",
+    topic: "test",
+    title: "会 panic",
+    title_en: "Panicking",
+    description: "",
+    description_en: "",
+    run: panicking_lesson,
+    source: "",
+};
+
+static FAILING: Lesson = Lesson {
+    id: "failing",
+    topic: "test",
+    title: "会出错",
+    title_en: "Failing",
+    description: "",
+    description_en: "",
+    run: failing_lesson,
+    source: "",
+};
+
+static SECTIONED: Lesson = Lesson {
+    id: "sectioned",
+    topic: "test",
+    title: "分节",
+    title_en: "Sectioned",
+    description: "",
+    description_en: "",
+    run: sectioned_lesson,
+    source: "",
+};
+
+#[test]
+fn a_panicking_lesson_does_not_stop_the_run() {
+    let mut out = Vec::new();
+    let mut input: &[u8] = &[];
+    let mut ctx = Ctx::new(&mut out, &mut input);
+
+    let summary = runner::run_lessons(&mut ctx, [&OK, &PANICKING, &FAILING, &OK]).unwrap();
+    let output = String::from_utf8(out).unwrap();
+
+    let statuses: Vec<&Status> = summary.runs.iter().map(|run| &run.status).collect();
+    assert_eq!(statuses[0], &Status::Passed);
+    assert!(matches!(statuses[1], Status::Panicked(message) if message.contains("index out of bounds")));
+    assert_eq!(statuses[2], &Status::Failed(String::from("磁盘已满")));
+    assert_eq!(statuses[3], &Status::Passed);
+
+    assert_eq!((summary.passed(), summary.failed()), (2, 2));
+    assert!(!summary.is_success());
+
+    assert!(output.contains("panic 之前的输出\n!!! 本课发生 panic: index out of bounds"));
+    assert!(output.contains("!!! 本课出错: 磁盘已满\n"));
+    assert_eq!(output.matches("正常的一课\n").count(), 2);
+    assert!(output.contains("\n=== 运行结果 ===\n"));
+    assert!(output.contains("test/failing"));
+    assert!(output.ends_with("共 4 课：2 通过，2 失败\n"));
+}
+
+#[test]
+fn a_run_without_failures_is_a_success() {
+    let mut out = Vec::new();
+    let mut input: &[u8] = &[];
+    let mut ctx = Ctx::new(&mut out, &mut input);
+
+    let summary = runner::run_lessons(&mut ctx, [&OK, &OK]).unwrap();
+    assert!(summary.is_success());
+    assert_eq!(summary.passed(), 2);
+}
+
+// 用给定的输入运行分节的课程，返回课程输出的副本和全部输出
+fn run_sectioned(step: bool, input: &str) -> (String, String) {
+    let mut out = Vec::new();
+    let mut input = input.as_bytes();
+    let mut ctx = Ctx::new(&mut out, &mut input).step_through(step);
+    let run = runner::run_lesson(&mut ctx, &SECTIONED).unwrap();
+    drop(ctx);
+    (run.output, String::from_utf8(out).unwrap())
+}
+
+#[test]
+fn step_mode_pauses_after_each_section() {
+    let (captured, output) = run_sectioned(true, "\n\n\n");
+    assert!(output.contains(
+        "一\n—— §1 第一节 结束。回车继续，q 退出单步模式 ——二\n—— §2 第二节 结束。回车继续，q 退出单步模式 ——三\n—— §3 第三节 结束。"
+    ));
+    // 暂停的提示不计入课程输出
+    assert_eq!(captured, "一\n二\n三\n");
+}
+
+#[test]
+fn q_or_end_of_input_stops_stepping() {
+    let (_, output) = run_sectioned(true, "q\n");
+    assert_eq!(output.matches("—— §").count(), 1);
+    assert!(output.ends_with("——二\n三\n"));
+
+    let (_, output) = run_sectioned(true, "");
+    assert_eq!(output.matches("—— §").count(), 1);
+}
+
+#[test]
+fn sections_are_silent_without_step_mode() {
+    let (captured, output) = run_sectioned(false, "");
+    assert_eq!(captured, "一\n二\n三\n");
+    assert!(!output.contains("——"));
+}
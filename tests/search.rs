@@ -0,0 +1,130 @@
+//! 搜索测试
+//!
+//! 用测试中定义的课程检查各类命中和排序，再在真实课程中搜几个词。
+
+use std::io;
+
+use rust_study::context::Ctx;
+use rust_study::registry::Lesson;
+use rust_study::search::{self, Kind};
+
+fn no_op(_ctx: &mut Ctx) -> io::Result<()> {
+    Ok(())
+}
+
+static LESSONS: &[Lesson] = &[Lesson {
+    id: "maps",
+    topic: "test",
+    title: "映射",
+    title_en: "Maps",
+    description: "插入和 entry API。",
+    description_en: "Inserting and the entry API.",
+    run: no_op,
+    source: r#"//! 映射
+
+fn count(map: &mut HashMap<String, i32>, word: String) {
+    *map.entry(word).or_insert(0) += 1; // 辅助函数中的 entry
+}
+
+pub fn main(ctx: &mut Ctx) -> io::Result<()> {
+    ctx.section(1, "插入", "Inserting")?;
+    map.insert(1, 2);
+
+    ctx.section(2, "entry", "entry")?;
+    let value = map.entry(key).or_insert(0);
+    writeln!(ctx, "使用 entry 之后: {}", value)?;
+    let entries = map.entry_count();
+    Ok(())
+}
+"#,
+}];
+
+#[test]
+fn hits_cover_titles_sections_code_strings_and_comments() {
+    let hits = search::search_in(LESSONS, "entry");
+    let found: Vec<(Kind, String)> = hits.iter().map(|hit| (hit.kind, hit.label())).collect();
+    assert_eq!(
+        found,
+        [
+            (Kind::Title, String::from("test/maps 映射 - 插入和 entry API。")),
+            (Kind::Section, String::from("test/maps §2 entry")),
+            (Kind::Code, String::from("test/maps entry().or_insert()")),
+            (Kind::Code, String::from("test/maps §2 entry().or_insert()")),
+            (Kind::Code, String::from("test/maps §2 entry_count()")),
+            (Kind::Narration, String::from("test/maps §2 \"使用 entry 之后: {}\"")),
+            (Kind::Comment, String::from("test/maps // 辅助函数中的 entry")),
+        ]
+    );
+}
+
+// 标题命中的片段显示匹配到的那种语言，英文搜索词不会配上不含它的中文标题
+#[test]
+fn title_snippets_show_the_language_that_matched() {
+    let title = |term: &str| {
+        let hits = search::search_in(LESSONS, term);
+        hits.iter().find(|hit| hit.kind == Kind::Title).map(|hit| hit.label())
+    };
+    assert_eq!(title("maps").as_deref(), Some("test/maps Maps - Inserting and the entry API."));
+    assert_eq!(title("inserting").as_deref(), Some("test/maps Maps - Inserting and the entry API."));
+    assert_eq!(title("映射").as_deref(), Some("test/maps 映射 - 插入和 entry API。"));
+
+    let hits = search::search("Hash maps");
+    assert_eq!(hits[0].kind, Kind::Title);
+    assert!(hits[0].snippet.contains("Hash maps"), "{}", hits[0].snippet);
+}
+
+#[test]
+fn search_is_case_insensitive_and_finds_chains_from_any_link() {
+    let hits = search::search_in(LESSONS, "OR_INSERT");
+    let labels: Vec<String> = hits.iter().map(|hit| hit.label()).collect();
+    assert_eq!(labels, ["test/maps entry().or_insert()", "test/maps §2 entry().or_insert()"]);
+
+    assert!(search::search_in(LESSONS, "  ").is_empty());
+    assert!(search::search_in(LESSONS, "不存在").is_empty());
+}
+
+#[test]
+fn real_lessons_show_where_entry_is_used() {
+    let hits = search::search("entry");
+    assert!(!hits.is_empty());
+    // 集合主题最先出现，迭代器主题的计数示例也用到了 entry
+    assert!(hits.iter().any(|hit| hit.lesson.topic == "iterators"));
+    let code = hits.iter().find(|hit| hit.kind == Kind::Code).unwrap();
+    assert!(code.label().starts_with("collections/hashmaps §"));
+    assert!(code.label().ends_with(" entry().or_insert()"));
+}
+
+#[test]
+fn a_better_match_never_lifts_a_hit_above_a_higher_kind() {
+    // 说明中只包含 entry（匹配程度 1），代码中有完整的标识符 entry（匹配程度 3），标题仍然排在前面
+    let hits = search::search_in(LESSONS, "entry");
+    let title = hits.iter().position(|hit| hit.kind == Kind::Title).unwrap();
+    let exact_code = hits.iter().position(|hit| hit.kind == Kind::Code && hit.quality == 3).unwrap();
+    assert_eq!(hits[title].quality, 1);
+    assert!(title < exact_code);
+    assert!(hits.windows(2).all(|pair| pair[0].kind <= pair[1].kind), "结果没有按种类排序");
+
+    let hits = search::search("entry");
+    assert_eq!(hits[0].kind, Kind::Title);
+    assert!(hits.windows(2).all(|pair| pair[0].kind <= pair[1].kind), "结果没有按种类排序");
+}
+
+fn run(term: &str) -> (bool, String) {
+    let mut out = Vec::new();
+    let mut input: &[u8] = &[];
+    let mut ctx = Ctx::new(&mut out, &mut input);
+    let found = search::run(&mut ctx, term).unwrap();
+    drop(ctx);
+    (found, String::from_utf8(out).unwrap())
+}
+
+#[test]
+fn run_prints_the_best_hits_first_and_reports_misses() {
+    let (found, output) = run("生命周期");
+    assert!(found);
+    assert_eq!(output.lines().next(), Some("advanced/lifetimes 生命周期 - 生命周期注解、省略规则和结构体中的引用。"));
+    assert_eq!(output.lines().count(), 21);
+    assert!(output.ends_with("处结果没有显示\n"));
+
+    assert_eq!(run("没有这个词"), (false, String::from("没有找到 没有这个词\n")));
+}
@@ -0,0 +1,168 @@
+//! 课程源码测试
+//!
+//! 检查嵌入的源码与分段，show 的输出，以及语法高亮。
+
+use std::io::Write;
+
+use rust_study::context::Ctx;
+use rust_study::i18n::Locale;
+use rust_study::registry::{self, Target, LESSONS};
+use rust_study::source::{self, Highlighter};
+
+#[test]
+fn every_lesson_embeds_its_source_with_consecutive_sections() {
+    for lesson in LESSONS {
+        assert!(
+            lesson.source.contains("pub fn main(ctx: &mut Ctx) -> io::Result<()>"),
+            "{} 的源码没有 main",
+            lesson.file()
+        );
+
+        let sections = source::sections(lesson.source);
+        let numbers: Vec<usize> = sections.iter().filter_map(|section| section.number).collect();
+        assert!(!numbers.is_empty(), "{} 没有编号小节", lesson.file());
+        assert_eq!(numbers, (1..=numbers.len()).collect::<Vec<_>>(), "{} 的小节编号不连续", lesson.file());
+
+        // 各段首尾相接，覆盖整个文件
+        let mut next = 0;
+        for section in &sections {
+            assert_eq!(section.lines.start, next, "{} 的分段有缺口", lesson.file());
+            next = section.lines.end;
+        }
+        assert_eq!(next, lesson.source.lines().count());
+    }
+}
+
+#[test]
+fn sections_are_split_where_main_declares_them() {
+    let source = "\
+use std::io;
+
+fn helper(ctx: &mut Ctx) -> io::Result<()> {
+    ctx.section(1, \"不在 main 中\", \"Not in main\")
+}
+
+pub fn main(ctx: &mut Ctx) -> io::Result<()> {
+    writeln!(ctx, \"标题\")?;
+
+    ctx.section(1, \"第一节\", \"First\")?;
+    helper();
+
+    ctx.section(2, \"第二节\", \"Second\")?;
+    Ok(())
+}
+
+fn after() {}
+";
+    let sections = source::sections(source);
+    let summary: Vec<_> = sections
+        .iter()
+        .map(|section| (section.number, section.title_in(Locale::ZhCn), section.title_in(Locale::EnUs), section.lines.clone()))
+        .collect();
+    assert_eq!(
+        summary,
+        [
+            (None, None, None, 0..9),
+            (Some(1), Some("第一节"), Some("First"), 9..12),
+            (Some(2), Some("第二节"), Some("Second"), 12..15),
+            (None, None, None, 15..17),
+        ]
+    );
+    // main 之前的辅助函数不属于任何小节
+    assert_eq!(sections[0].owns, 6..9);
+    assert!(sections[3].owns.is_empty());
+}
+
+fn show(query: &str, with_output: bool) -> String {
+    let Ok(target) = registry::resolve(query) else {
+        panic!("找不到 {}", query);
+    };
+    let mut out = Vec::new();
+    let mut input: &[u8] = &[];
+    let mut ctx = Ctx::new(&mut out, &mut input);
+    source::show_target(&mut ctx, target, with_output).unwrap();
+    drop(ctx);
+    String::from_utf8(out).unwrap()
+}
+
+#[test]
+fn show_prints_numbered_source_lines() {
+    let output = show("basics/functions", false);
+    assert!(output.starts_with("\n=== 函数（src/basics/functions.rs） ===\n"));
+    assert_eq!(output.lines().nth(2).map(str::trim_start), Some("1 │ //! 函数"));
+    assert!(output.contains("│ pub fn main(ctx: &mut Ctx) -> io::Result<()> {\n"));
+    assert!(!output.contains("输出："));
+    assert!(!output.contains('\x1b'));
+}
+
+#[test]
+fn show_with_output_puts_output_after_the_section_that_wrote_it() {
+    let output = show("basics/functions", true);
+    let position = |needle: &str| output.find(needle).unwrap_or_else(|| panic!("输出中没有 {:?}", needle));
+
+    // 直接在小节中写出
+    assert!(position("ctx.section(4, \"有返回值的函数\"") < position("┆ 10 + 20 = 30"));
+    assert!(position("┆ 10 + 20 = 30") < position("ctx.section(5, \"使用表达式作为返回值\""));
+    // 在小节调用的辅助函数中写出
+    assert!(position("ctx.section(1, \"无参数无返回值的函数\"") < position("┆ Hello, Rust!"));
+    assert!(position("┆ Hello, Rust!") < position("ctx.section(2, \"带参数的函数\""));
+    assert!(position("ctx.section(2, \"带参数的函数\"") < position("┆ 数字是: 42"));
+}
+
+#[test]
+fn show_of_a_topic_shows_every_lesson() {
+    let Ok(Target::Topic(topic)) = registry::resolve("control_flow") else {
+        panic!("找不到 control_flow");
+    };
+    let output = show(topic.id, false);
+    for lesson in registry::lessons_of(topic.id) {
+        assert!(output.contains(&format!("（{}） ===", lesson.file())));
+    }
+}
+
+#[test]
+fn trace_records_where_each_write_came_from() {
+    let mut out = Vec::new();
+    let mut input: &[u8] = &[];
+    let mut ctx = Ctx::new(&mut out, &mut input);
+    writeln!(ctx, "不记录").unwrap();
+    ctx.start_trace();
+    let line = line!() + 1;
+    writeln!(ctx, "第 {} 行", 1).unwrap();
+    let trace = ctx.finish_trace();
+    drop(ctx);
+
+    assert_eq!(trace.len(), 1);
+    assert_eq!(trace[0].0.file().replace('\\', "/"), "tests/source.rs");
+    assert_eq!(trace[0].0.line(), line);
+    assert_eq!(trace[0].1, "第 1 行\n");
+    assert_eq!(String::from_utf8(out).unwrap(), "不记录\n第 1 行\n");
+}
+
+#[test]
+fn highlighter_colours_keywords_strings_comments_and_macros() {
+    let mut highlighter = Highlighter::default();
+    assert_eq!(
+        highlighter.line("let s = \"// 不是注释\"; // 注释"),
+        "\x1b[35mlet\x1b[0m s = \x1b[32m\"// 不是注释\"\x1b[0m; \x1b[90m// 注释\x1b[0m"
+    );
+    assert_eq!(
+        highlighter.line("println!(\"{}\", 42);"),
+        "\x1b[34mprintln!\x1b[0m(\x1b[32m\"{}\"\x1b[0m, \x1b[33m42\x1b[0m);"
+    );
+    assert_eq!(
+        highlighter.line("fn f<'a>(c: char) -> Option<&'a str> { 'x' }"),
+        "\x1b[35mfn\x1b[0m f<\x1b[33m'a\x1b[0m>(c: char) -> \x1b[36mOption\x1b[0m<&\x1b[33m'a\x1b[0m str> { \x1b[32m'x'\x1b[0m }"
+    );
+    // 标识符中的 r 和数字不是原始字符串和数字
+    assert_eq!(highlighter.line("for2 r2"), "for2 r2");
+}
+
+#[test]
+fn highlighter_carries_strings_and_comments_across_lines() {
+    let mut highlighter = Highlighter::default();
+    assert_eq!(highlighter.line("let s = \"第一行"), "\x1b[35mlet\x1b[0m s = \x1b[32m\"第一行\x1b[0m");
+    assert_eq!(highlighter.line("第二行\"; /* 注释"), "\x1b[32m第二行\"\x1b[0m; \x1b[90m/* 注释\x1b[0m");
+    assert_eq!(highlighter.line("结束 */ x"), "\x1b[90m结束 */\x1b[0m x");
+    assert_eq!(highlighter.line("r#\"原始 \" 字符串\"#"), "\x1b[32mr#\"原始 \" 字符串\"#\x1b[0m");
+}